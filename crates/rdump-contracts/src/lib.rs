@@ -85,6 +85,19 @@ pub struct PathMetadata {
     pub permissions_display: String,
 }
 
+/// Per-file metadata attached to full results when `with_metadata` is requested.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ResultFileMetadata {
+    /// File size in bytes reported by the active backend.
+    pub size: u64,
+    /// Line count of the loaded content; zero when content was skipped.
+    pub lines: usize,
+    /// Last-modified timestamp when the backend can provide one.
+    pub modified_unix_millis: Option<i64>,
+    /// Canonical semantic language id (e.g. `rs`, `py`) when a profile matches the file.
+    pub language: Option<String>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum SemanticSkipReason {
@@ -170,6 +183,35 @@ impl Default for SemanticMatchMode {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Encoding {
+    Auto,
+    Utf8,
+    Latin1,
+    Utf16Le,
+    Utf16Be,
+}
+
+impl Default for Encoding {
+    fn default() -> Self {
+        Self::Auto
+    }
+}
+
+/// Flags applied when compiling regex-based content predicates such as `matches:`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema)]
+pub struct RegexFlags {
+    /// `.` also matches `\n`, so a pattern can span lines.
+    #[serde(default)]
+    pub dotall: bool,
+    /// `^` and `$` match at every line boundary of the file, so a pattern can span lines.
+    #[serde(default)]
+    pub multiline: bool,
+    #[serde(default)]
+    pub case_insensitive: bool,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, JsonSchema)]
 pub enum LimitValue {
     Unset,
@@ -232,6 +274,8 @@ pub enum PathDisplayMode {
     Relative,
     Absolute,
     RootRelative,
+    /// Relative to the process working directory, with `..` segments where needed.
+    RelativeToCwd,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
@@ -250,11 +294,17 @@ pub struct SearchRequest {
     pub presets: Vec<String>,
     #[serde(default)]
     pub no_ignore: bool,
+    #[serde(default = "default_true")]
+    pub respect_global_gitignore: bool,
+    #[serde(default)]
+    pub no_ignore_parent: bool,
     #[serde(default)]
     pub hidden: bool,
     #[serde(default)]
     pub max_depth: Option<usize>,
     #[serde(default)]
+    pub min_depth: Option<usize>,
+    #[serde(default)]
     pub sql_dialect: Option<SqlDialectOption>,
     #[serde(default)]
     pub sql_strict: bool,
@@ -297,11 +347,37 @@ pub struct SearchRequest {
     #[serde(default)]
     pub sql_trace: bool,
     #[serde(default)]
+    pub files_without_match: bool,
+    #[serde(default)]
+    pub secret_patterns: Option<Vec<String>>,
+    #[serde(default)]
+    pub line_range: Option<(usize, usize)>,
+    #[serde(default)]
+    pub encoding: Encoding,
+    #[serde(default)]
     pub path_display: Option<PathDisplayMode>,
     #[serde(default)]
     pub line_endings: Option<LineEndingMode>,
     #[serde(default = "default_true")]
     pub include_match_text: bool,
+    #[serde(default)]
+    pub deterministic: bool,
+    #[serde(default)]
+    pub same_file_system: bool,
+    #[serde(default)]
+    pub threads: Option<usize>,
+    #[serde(default)]
+    pub regex_flags: RegexFlags,
+    #[serde(default)]
+    pub ignore_case_paths: bool,
+    #[serde(default)]
+    pub whole_word: bool,
+    #[serde(default)]
+    pub ext_map: BTreeMap<String, String>,
+    #[serde(default)]
+    pub skip_content: bool,
+    #[serde(default)]
+    pub with_metadata: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
@@ -470,6 +546,8 @@ pub enum SearchItem {
         diagnostic_count: usize,
         semantic_skip_reasons: Vec<SemanticSkipReason>,
         item_truncated: bool,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        metadata: Option<ResultFileMetadata>,
     },
 }
 
@@ -28,7 +28,13 @@ pub fn build_rql_reference() -> RqlReference {
 
     RqlReference {
         schema_version: crate::types::SCHEMA_VERSION.to_string(),
-        operators: vec!["AND: &", "OR: |", "NOT: !", "Grouping: ( )"]
+        operators: vec![
+            "AND: &",
+            "OR: |",
+            "NOT: !",
+            "ENCLOSES: > (right-hand matches inside left-hand matches)",
+            "Grouping: ( )",
+        ]
             .into_iter()
             .map(String::from)
             .collect(),
@@ -350,6 +356,19 @@ pub fn build_sdk_reference() -> SdkReference {
                     description: "Disables ignore-file filtering when true.".to_string(),
                     default: "false".to_string(),
                 },
+                FieldDoc {
+                    name: "respect_global_gitignore".to_string(),
+                    description:
+                        "Applies the global gitignore and `.git/info/exclude` when true."
+                            .to_string(),
+                    default: "true".to_string(),
+                },
+                FieldDoc {
+                    name: "ignore_parents".to_string(),
+                    description: "Applies ignore files from directories above the root when true."
+                        .to_string(),
+                    default: "true".to_string(),
+                },
                 FieldDoc {
                     name: "hidden".to_string(),
                     description: "Includes hidden files when true.".to_string(),
@@ -485,7 +504,7 @@ pub fn build_sdk_reference() -> SdkReference {
         "Use search_path_iter/search_paths when you only need matching file paths.",
         "Implement SearchBackend in external adapters when you need rdump to search a virtual workspace.",
         "Use explain_query to inspect preset expansion and evaluation stages before running a search.",
-        "RQL supports logical operators &, |, ! and parentheses.",
+        "RQL supports logical operators &, |, !, the containment operator > and parentheses.",
     ]
     .into_iter()
     .map(String::from)
@@ -30,8 +30,11 @@ pub fn build_search_request(args: SearchArgs) -> McpResult<SearchRequest> {
         root: args.root,
         presets: args.presets.unwrap_or_default(),
         no_ignore: args.no_ignore.unwrap_or(false),
+        respect_global_gitignore: true,
+        no_ignore_parent: false,
         hidden: args.hidden.unwrap_or(false),
         max_depth: args.max_depth,
+        min_depth: None,
         sql_dialect: args.sql_dialect,
         sql_strict: args.sql_strict.unwrap_or(false),
         output: args.output,
@@ -50,12 +53,25 @@ pub fn build_search_request(args: SearchArgs) -> McpResult<SearchRequest> {
         ignore_debug: args.ignore_debug.unwrap_or(false),
         language_debug: args.language_debug.unwrap_or(false),
         sql_trace: args.sql_trace.unwrap_or(false),
+        files_without_match: false,
+        secret_patterns: None,
+        line_range: None,
+        encoding: rdump::contracts::Encoding::Auto,
         execution_profile: args.execution_profile,
         offset,
         continuation_token: args.continuation_token,
         path_display: args.path_display,
         line_endings: args.line_endings,
         include_match_text: args.include_match_text.unwrap_or(true),
+        deterministic: false,
+        same_file_system: false,
+        threads: None,
+        regex_flags: rdump::contracts::RegexFlags::default(),
+        ignore_case_paths: false,
+        whole_word: false,
+        ext_map: Default::default(),
+        skip_content: false,
+        with_metadata: false,
     })
 }
 
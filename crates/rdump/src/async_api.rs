@@ -215,6 +215,98 @@ where
     })
 }
 
+/// Stream search results with one-item backpressure. Requires the `async`
+/// feature.
+///
+/// Unlike [`search_async`], this does not need to be awaited: setup (query
+/// parsing, discovery, and waiting for a concurrency slot) runs on the blocking
+/// pool, and any setup error is yielded as the first stream item. The channel
+/// between the producer and the stream holds a single result, so the next file
+/// is only evaluated once the previous result has been taken. Dropping the
+/// stream cancels the search.
+///
+/// # Arguments
+/// - `query`: RQL query string (e.g., `ext:rs & func:main`)
+/// - `options`: Search configuration
+///
+/// # Returns
+/// A stream of `Result<SearchResult>` items.
+///
+/// # Panics
+/// Panics if called outside of a Tokio runtime.
+///
+/// # Examples
+/// ```rust
+/// # use tempfile::tempdir;
+/// use futures::StreamExt;
+/// use rdump::{search_stream, SearchOptions};
+///
+/// # #[tokio::main]
+/// # async fn main() -> anyhow::Result<()> {
+/// #   let dir = tempdir()?;
+/// #   std::fs::write(dir.path().join("main.rs"), "fn main() {}")?;
+/// #   let options = SearchOptions { root: dir.path().to_path_buf(), ..Default::default() };
+/// let mut stream = search_stream("func:main", options).take(2);
+/// while let Some(result) = stream.next().await {
+///     println!("{}", result?.path.display());
+/// }
+/// #   Ok(())
+/// # }
+/// ```
+pub fn search_stream(query: &str, options: SearchOptions) -> SearchAsyncStream {
+    search_stream_with_runtime(SearchRuntime::real_fs(), query, options)
+}
+
+pub fn search_stream_with_runtime(
+    runtime: SearchRuntime,
+    query: &str,
+    options: SearchOptions,
+) -> SearchAsyncStream {
+    let query = query.to_string();
+    let (tx, rx) = mpsc::channel(1);
+    let join_tx = tx.clone();
+    let cancellation = SearchCancellationToken::new();
+    let task_cancellation = cancellation.clone();
+
+    let handle = tokio::task::spawn_blocking(move || {
+        let Ok(_permit) = futures::executor::block_on(SEARCH_SEMAPHORE.clone().acquire_owned())
+        else {
+            let _ = tx.blocking_send(Err(anyhow::anyhow!("search limiter is closed")));
+            return;
+        };
+        if task_cancellation.is_cancelled() {
+            return;
+        }
+        let iter = match runtime.search_raw_iter(&query, &options, Some(task_cancellation.clone()))
+        {
+            Ok(iter) => SearchResultIterator::from_raw_iter(iter),
+            Err(e) => {
+                let _ = tx.blocking_send(Err(e));
+                return;
+            }
+        };
+        for result in iter {
+            if tx.blocking_send(result).is_err() {
+                task_cancellation.cancel();
+                break;
+            }
+        }
+    });
+
+    tokio::spawn(async move {
+        if let Err(err) = handle.await {
+            let _ = join_tx
+                .send(Err(anyhow::anyhow!("search task failed to join: {err}")))
+                .await;
+        }
+    });
+
+    SearchAsyncStream {
+        inner: ReceiverStream::new(rx),
+        _cancel_on_drop: CancelOnDrop::new(cancellation),
+    }
+}
+
 /// Search for files matching a query (async, convenience). Requires the `async`
 /// feature.
 ///
@@ -1,6 +1,6 @@
 use anyhow::{anyhow, Context, Result};
 use globset::{Glob, GlobSet, GlobSetBuilder};
-use ignore::WalkBuilder;
+use ignore::{WalkBuilder, WalkState};
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
@@ -8,11 +8,12 @@ use std::fmt;
 #[cfg(unix)]
 use std::os::unix::fs::{MetadataExt, PermissionsExt};
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::time::UNIX_EPOCH;
 
 use crate::engine;
 use crate::limits::{safe_canonicalize, DEFAULT_MAX_DEPTH};
+use crate::session::TreeCache;
 use crate::{
     FileIdentity, PathResolution, SearchCancellationToken, SearchDiagnostic, SearchOptions,
     SearchPathIterator, SearchReport, SearchResult, SearchResultIterator,
@@ -108,9 +109,17 @@ pub struct DiscoveryRequest {
     pub root: PathBuf,
     pub display_root: PathBuf,
     pub no_ignore: bool,
+    pub respect_global_gitignore: bool,
+    /// Apply ignore files found in directories above `root`.
+    pub ignore_parents: bool,
     pub hidden: bool,
     pub max_depth: Option<usize>,
+    /// Entries shallower than this walk depth are not candidates; root-level files are depth 1.
+    pub min_depth: Option<usize>,
     pub ignore_debug: bool,
+    pub same_file_system: bool,
+    /// Walker thread count; `None` walks serially and `Some(0)` lets the parallel walker choose.
+    pub threads: Option<usize>,
 }
 
 /// Candidate file identities and skip counters returned by backend discovery.
@@ -155,6 +164,7 @@ pub struct RealFsSearchBackend;
 #[derive(Debug, Clone)]
 pub struct SearchRuntime {
     backend: Arc<dyn SearchBackend>,
+    tree_cache: Option<Arc<TreeCache>>,
 }
 
 impl SearchRuntime {
@@ -165,7 +175,16 @@ impl SearchRuntime {
 
     /// Constructs a runtime from a caller-supplied backend implementation.
     pub fn with_backend(backend: Arc<dyn SearchBackend>) -> Self {
-        Self { backend }
+        Self {
+            backend,
+            tree_cache: None,
+        }
+    }
+
+    /// Reuses syntax trees from `tree_cache` across searches run by this runtime.
+    pub fn with_tree_cache(mut self, tree_cache: Arc<TreeCache>) -> Self {
+        self.tree_cache = Some(tree_cache);
+        self
     }
 
     /// Returns the backend bound to this runtime.
@@ -190,9 +209,11 @@ impl SearchRuntime {
         query: &str,
         options: &SearchOptions,
     ) -> Result<SearchPathIterator> {
-        Ok(SearchPathIterator::from_raw_iter(
-            self.search_raw_iter(query, options, None)?,
-        ))
+        Ok(SearchPathIterator::from_raw_iter(self.search_raw_iter(
+            query,
+            &options.for_path_output(),
+            None,
+        )?))
     }
 
     pub(crate) fn search_raw_iter(
@@ -201,7 +222,15 @@ impl SearchRuntime {
         options: &SearchOptions,
         cancellation: Option<SearchCancellationToken>,
     ) -> Result<engine::SearchRawIterator> {
-        engine::SearchRawIterator::new(Arc::clone(&self.backend), query, options, cancellation)
+        Ok(
+            engine::SearchRawIterator::new(
+                Arc::clone(&self.backend),
+                query,
+                options,
+                cancellation,
+            )?
+            .with_tree_cache(self.tree_cache.clone()),
+        )
     }
 
     /// Collects results plus engine statistics and diagnostics.
@@ -236,12 +265,22 @@ impl SearchRuntime {
 
 impl SearchBackend for RealFsSearchBackend {
     fn normalize_root(&self, root: &Path) -> Result<PathBuf> {
-        dunce::canonicalize(root).map_err(|_| {
+        if !root.exists() {
+            return Err(anyhow!("root directory does not exist: {}", root.display()));
+        }
+        let canonical = dunce::canonicalize(root).map_err(|_| {
             anyhow!(
                 "root path '{}' does not exist or is not accessible.",
                 root.display()
             )
-        })
+        })?;
+        if !canonical.is_dir() {
+            return Err(anyhow!(
+                "root must be a directory, got a file: {}",
+                root.display()
+            ));
+        }
+        Ok(canonical)
     }
 
     fn discover(&self, request: &DiscoveryRequest) -> Result<DiscoveryReport> {
@@ -259,7 +298,9 @@ impl SearchBackend for RealFsSearchBackend {
         walker_builder
             .hidden(!request.hidden)
             .max_depth(Some(effective_max_depth))
-            .follow_links(false);
+            .follow_links(false)
+            .same_file_system(request.same_file_system)
+            .threads(request.threads.unwrap_or(1));
 
         if request.no_ignore {
             walker_builder
@@ -268,6 +309,10 @@ impl SearchBackend for RealFsSearchBackend {
                 .git_global(false)
                 .git_exclude(false);
         } else {
+            walker_builder
+                .parents(request.ignore_parents)
+                .git_global(request.respect_global_gitignore)
+                .git_exclude(request.respect_global_gitignore);
             if let Some(global_ignore_path) = dirs::config_dir().map(|p| p.join("rdump/ignore")) {
                 if global_ignore_path.exists() {
                     if let Some(err) = walker_builder.add_ignore(global_ignore_path) {
@@ -281,10 +326,16 @@ impl SearchBackend for RealFsSearchBackend {
             walker_builder.add_custom_ignore_filename(".rdumpignore");
         }
 
-        for result in walker_builder.build() {
+        for result in walk_entries(&walker_builder, request.threads) {
             match result {
                 Ok(entry) => {
                     if entry.file_type().is_some_and(|ft| ft.is_file()) {
+                        if request
+                            .min_depth
+                            .is_some_and(|min_depth| entry.depth() < min_depth)
+                        {
+                            continue;
+                        }
                         let original_path = entry.into_path();
                         if !request.no_ignore {
                             let relative = original_path
@@ -378,6 +429,31 @@ impl SearchBackend for RealFsSearchBackend {
     }
 }
 
+/// Collects every walk entry. The serial walker is the default; the parallel one only runs
+/// when more than one thread, or an automatic count (`0`), was requested. Entry order is only
+/// stable for the serial walk; discovery sorts the candidates afterwards either way.
+fn walk_entries(
+    walker_builder: &WalkBuilder,
+    threads: Option<usize>,
+) -> Vec<Result<ignore::DirEntry, ignore::Error>> {
+    if threads.is_none_or(|threads| threads == 1) {
+        return walker_builder.build().collect();
+    }
+
+    let entries = Mutex::new(Vec::new());
+    walker_builder.build_parallel().run(|| {
+        let entries = &entries;
+        Box::new(move |result| {
+            entries
+                .lock()
+                .expect("walk entry lock poisoned")
+                .push(result);
+            WalkState::Continue
+        })
+    });
+    entries.into_inner().expect("walk entry lock poisoned")
+}
+
 pub fn backend_metadata_from_std(metadata: &std::fs::Metadata) -> BackendMetadata {
     let readonly = metadata.permissions().readonly();
     #[cfg(unix)]
@@ -529,6 +605,9 @@ pub(crate) fn collect_search_report(
         .count();
     stats.diagnostics = diagnostics.len();
     stats.materialize_millis = materialize_started.elapsed().as_millis() as u64;
+    if options.deterministic {
+        stats.clear_timings();
+    }
 
     Ok(SearchReport {
         results,
@@ -0,0 +1,35 @@
+use crate::{ContextLines, DumpArgs, SearchOptions};
+use anyhow::Result;
+use std::fs::File;
+use std::io::{self, Write};
+
+use crate::formatter;
+
+/// The main entry point for the `dump` command.
+pub fn run_dump(args: DumpArgs) -> Result<()> {
+    let query = args.query.as_deref().unwrap_or("");
+    let options = SearchOptions {
+        root: crate::expand_tilde(&args.root),
+        presets: args.preset,
+        hidden: args.hidden,
+        no_ignore: args.no_ignore,
+        // The bundle is meant to be pasted and diffed, so files always come in path order.
+        deterministic: true,
+        ..Default::default()
+    };
+    let results = crate::search(query, options)?;
+
+    let mut writer: Box<dyn Write> = match &args.output {
+        Some(output_path) => Box::new(File::create(crate::expand_tilde(output_path))?),
+        None => Box::new(io::stdout()),
+    };
+    formatter::print_dump_bundle(
+        &mut writer,
+        query,
+        &results,
+        ContextLines::symmetric(args.context),
+        args.max_bytes,
+    )?;
+    writer.flush()?;
+    Ok(())
+}
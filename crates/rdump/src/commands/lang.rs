@@ -1,11 +1,82 @@
+use crate::completion::predicate_description;
+use crate::parser::PredicateKey;
 use crate::planner::repo_language_inventory;
 use crate::predicates::code_aware::profiles::{
     find_canonical_language_profile, list_canonical_language_profiles, support_tier_for_id,
+    CanonicalLanguageProfile,
 };
+use crate::predicates::{content_predicate_keys, metadata_predicate_keys, react_predicate_keys};
 use crate::request::language_capability_matrix;
-use crate::LangAction;
 use crate::SearchOptions;
+use crate::{LangAction, LangDescribeFormat};
 use anyhow::{anyhow, Result};
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+struct LanguageDescription {
+    id: &'static str,
+    name: &'static str,
+    extensions: Vec<&'static str>,
+    aliases: Vec<&'static str>,
+    support_tier: String,
+    predicates: Vec<PredicateSummary>,
+}
+
+#[derive(Debug, Serialize)]
+struct PredicateSummary {
+    name: String,
+    category: &'static str,
+    description: &'static str,
+}
+
+/// Metadata and content predicates work for every language; semantic (and React) ones come
+/// from [`CanonicalLanguageProfile::semantic_predicates`], minus empty queries.
+fn describe_language(profile: &CanonicalLanguageProfile) -> LanguageDescription {
+    let summarize = |key: PredicateKey, category: &'static str| PredicateSummary {
+        name: key.as_ref().to_string(),
+        description: predicate_description(&key),
+        category,
+    };
+    let mut predicates: Vec<PredicateSummary> = metadata_predicate_keys()
+        .into_iter()
+        .map(|key| summarize(key, "metadata"))
+        .chain(
+            content_predicate_keys()
+                .into_iter()
+                .map(|key| summarize(key, "content")),
+        )
+        .collect();
+
+    let react_keys = react_predicate_keys();
+    let semantic = profile
+        .semantic_predicates()
+        .into_iter()
+        .filter(|key| {
+            profile
+                .profile
+                .queries
+                .get(*key)
+                .is_none_or(|query| !query.trim().is_empty())
+        })
+        .map(|key| {
+            let category = if react_keys.contains(key) {
+                "react"
+            } else {
+                "semantic"
+            };
+            summarize(key.clone(), category)
+        });
+    predicates.extend(semantic);
+
+    LanguageDescription {
+        id: profile.id,
+        name: profile.profile.name,
+        extensions: profile.profile.extensions.clone(),
+        aliases: profile.aliases.clone(),
+        support_tier: format!("{:?}", support_tier_for_id(profile.id)).to_lowercase(),
+        predicates,
+    }
+}
 
 pub fn run_lang(action: LangAction) -> Result<()> {
     match action {
@@ -23,12 +94,19 @@ pub fn run_lang(action: LangAction) -> Result<()> {
                 );
             }
         }
-        LangAction::Describe { language } => {
+        LangAction::Describe { language, format } => {
             let profile = find_canonical_language_profile(&language).ok_or_else(|| {
                 anyhow!(
                     "Language '{language}' not supported. Run `rdump lang list` to see available languages."
                 )
             })?;
+            if format == LangDescribeFormat::Json {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&describe_language(&profile))?
+                );
+                return Ok(());
+            }
 
             println!(
                 "Predicates for {} ({})",
@@ -50,9 +128,11 @@ pub fn run_lang(action: LangAction) -> Result<()> {
             println!("\nCONTENT");
             println!("  {}", content_preds.join(", "));
 
-            let mut semantic_preds: Vec<&str> =
-                profile.profile.queries.keys().map(|k| k.as_ref()).collect();
-            semantic_preds.sort_unstable();
+            let semantic_preds: Vec<&str> = profile
+                .semantic_predicates()
+                .into_iter()
+                .map(|k| k.as_ref())
+                .collect();
             if !semantic_preds.is_empty() {
                 println!("\nSEMANTIC");
                 println!("  {}", semantic_preds.join(", "));
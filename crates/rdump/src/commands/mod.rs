@@ -1,6 +1,7 @@
 // This makes the functions inside search.rs and preset.rs available
 // to other parts of the program that use the `commands` module.
 pub mod config;
+pub mod dump;
 pub mod lang;
 pub mod preset;
 pub mod query;
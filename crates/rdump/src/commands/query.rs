@@ -3,6 +3,7 @@ use serde::Serialize;
 use std::path::Path;
 
 use crate::evaluator::{Evaluator, FileContext, MatchResult};
+use crate::limits::SecretDetector;
 use crate::predicates::code_aware::CodeAwareSettings;
 use crate::predicates::code_aware::{
     detect_sql_dialect_for_debug, detect_sql_dialect_trace_for_debug,
@@ -302,6 +303,7 @@ fn explain_file(query: &str, path: &Path, options: &SearchOptions) -> Result<Fil
 
     let metadata_registry = create_metadata_predicate_registry();
     let mut metadata_context = FileContext::new(absolute_path.clone(), options.root.clone());
+    metadata_context.set_ignore_case_paths(options.ignore_case_paths);
     let metadata_result =
         Evaluator::new(ast.clone(), metadata_registry).evaluate(&mut metadata_context)?;
     let metadata_diagnostics = metadata_context.take_diagnostics();
@@ -310,6 +312,7 @@ fn explain_file(query: &str, path: &Path, options: &SearchOptions) -> Result<Fil
     code_settings.sql_dialect = options.sql_dialect;
     code_settings.sql_strict = options.sql_strict;
     code_settings.language_override = options.language_override.clone();
+    code_settings.ext_map = options.ext_map.clone();
     code_settings.semantic_budget_ms = options.semantic_budget_ms;
     code_settings.max_semantic_matches_per_file = options.max_semantic_matches_per_file;
     code_settings.semantic_match_mode = options.semantic_match_mode;
@@ -317,6 +320,13 @@ fn explain_file(query: &str, path: &Path, options: &SearchOptions) -> Result<Fil
     code_settings.language_debug = options.language_debug;
     code_settings.sql_trace = options.sql_trace;
     let mut full_context = FileContext::new(absolute_path.clone(), options.root.clone());
+    full_context.set_secret_detector(SecretDetector::from_patterns(
+        options.secret_patterns.as_deref(),
+    )?);
+    full_context.set_encoding(options.encoding);
+    full_context.set_regex_flags(options.regex_flags);
+    full_context.set_ignore_case_paths(options.ignore_case_paths);
+    full_context.set_whole_word(options.whole_word);
     let full_result = Evaluator::new(ast, create_predicate_registry_with_settings(code_settings))
         .evaluate(&mut full_context)?;
     let full_diagnostics = full_context.take_diagnostics();
@@ -1,15 +1,61 @@
-use crate::{ColorChoice, SearchArgs, SearchOptions, SearchReport, SearchRuntime, SearchStats};
+use crate::{
+    ColorChoice, Match, SearchArgs, SearchOptions, SearchReport, SearchResult, SearchRuntime,
+    SearchStats,
+};
 use anyhow::Result;
-use rdump_contracts::{ErrorMode, LimitValue, Limits, OutputMode, SearchRequest};
-use std::fs::File;
+use rdump_contracts::{ErrorMode, LimitValue, Limits, OutputMode, SearchRequest, SearchResponse};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs::{self, File};
 use std::io::{self, IsTerminal, Write};
-use std::path::PathBuf;
+use std::path::{Component, Path, PathBuf};
+use std::time::Instant;
 use tree_sitter::Range;
 
 use crate::formatter;
 
+/// Returned by `run_search` in `--quiet` mode when nothing matched, so the
+/// binary can exit with status 1 without printing an error.
+#[derive(Debug)]
+pub struct NoMatches;
+
+impl std::fmt::Display for NoMatches {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("no matches")
+    }
+}
+
+impl std::error::Error for NoMatches {}
+
 /// The main entry point for the `search` command.
 pub fn run_search(mut args: SearchArgs) -> Result<()> {
+    args.root = crate::expand_tilde(&args.root);
+    args.output = args.output.as_deref().map(crate::expand_tilde);
+    args.output_dir = args.output_dir.as_deref().map(crate::expand_tilde);
+    args.summary_json = args.summary_json.as_deref().map(crate::expand_tilde);
+    #[cfg(feature = "cli")]
+    {
+        if args.watch {
+            args.watch = false;
+            let root = args.root.clone();
+            // Everything a run writes would otherwise trigger the next run.
+            let ignored: Vec<PathBuf> = [&args.output, &args.output_dir, &args.summary_json]
+                .into_iter()
+                .flatten()
+                .cloned()
+                .collect();
+            return crate::watch::watch(&root, &ignored, || match run_search(args.clone()) {
+                // An empty result is a normal outcome between edits, not an error.
+                Err(err) if err.is::<NoMatches>() => Ok(()),
+                result => result,
+            });
+        }
+    }
+    apply_config_defaults(&mut args, &crate::config::load_config()?.defaults);
+    if args.fixed_string {
+        args.fixed_string = false;
+        args.query = args.query.as_deref().map(crate::parser::literal_query);
+    }
     if args.no_headers && args.find {
         eprintln!("Warning: --no-headers has no effect with --find.");
     }
@@ -25,19 +71,84 @@ pub fn run_search(mut args: SearchArgs) -> Result<()> {
         );
     }
 
+    if matches!(
+        args.format,
+        crate::Format::Paths | crate::Format::Find | crate::Format::Json
+    ) && args.sort_by == crate::SortByFlag::Relevance
+    {
+        eprintln!(
+            "Warning: --sort-by relevance only affects content-oriented formats and is ignored here."
+        );
+    }
+
+    // Folded paths are only named in file headings, so other output would drop them silently.
+    if args.dedup_content
+        && !(matches!(args.format, crate::Format::Hunks | crate::Format::Markdown)
+            && heading_style(&args) != crate::HeadingStyle::Inline)
+    {
+        eprintln!(
+            "Warning: --dedup-content needs file headings (hunks or markdown output without --no-heading) and is ignored here."
+        );
+        args.dedup_content = false;
+    }
+
+    if args.group_by != crate::GroupByFlag::None
+        && matches!(
+            args.format,
+            crate::Format::Paths
+                | crate::Format::Find
+                | crate::Format::Json
+                | crate::Format::LspJson
+                | crate::Format::Diff
+        )
+    {
+        eprintln!("Warning: --group-by only affects content-oriented formats and is ignored here.");
+        args.group_by = crate::GroupByFlag::None;
+    }
+
+    if args.null_separated && !matches!(args.format, crate::Format::Paths) {
+        eprintln!("Warning: --null only affects --format=paths and is ignored here.");
+    }
+
+    if args.max_columns.is_some()
+        && !matches!(args.format, crate::Format::Hunks | crate::Format::Cat)
+    {
+        eprintln!("Warning: --max-columns only affects hunks and cat output and is ignored here.");
+        args.max_columns = None;
+    }
+
+    if args.no_content {
+        // Every result is content-less, so show its placeholder instead of dropping it.
+        args.show_suppressed_placeholders = true;
+    }
+
     let request = search_request_from_args(&args);
-    let options = crate::request::search_options_from_request(&request);
+    let mut options = crate::request::search_options_from_request(&request);
+    options.max_results = args.max_matches;
+    options.show_enclosing = args.show_enclosing;
+    options.track_predicates = matches!(args.format, crate::Format::Aggregate);
     let query = args.query.as_deref().unwrap_or("");
+    let started = Instant::now();
 
-    let use_color = if args.output.is_some() {
-        args.color == ColorChoice::Always
-    } else {
-        match args.color {
-            ColorChoice::Always => true,
-            ColorChoice::Never => false,
-            ColorChoice::Auto => io::stdout().is_terminal(),
-        }
-    };
+    if args.quiet {
+        // Stop at the first matching file and skip reading it so `-q` stays cheap on large trees.
+        options.load_content = false;
+        let mut results = crate::search_iter(query, options)?;
+        return match results.next().transpose()? {
+            Some(_) => Ok(()),
+            None => Err(NoMatches.into()),
+        };
+    }
+
+    if let Some(output_dir) = &args.output_dir {
+        let report = apply_cli_output_preferences(crate::search_with_stats(query, options)?, &args);
+        write_output_dir(output_dir, &report, &args)?;
+        maybe_log_diagnostics(&args, &report.diagnostics);
+        return write_report_summary_json(&args, &report, started);
+    }
+
+    let use_color = should_use_color(&args.color, args.output.is_some());
+    let render_options = report_render_options(&args, use_color);
 
     let mut writer: Box<dyn Write> = if let Some(output_path) = &args.output {
         Box::new(File::create(output_path)?)
@@ -45,6 +156,24 @@ pub fn run_search(mut args: SearchArgs) -> Result<()> {
         Box::new(io::stdout())
     };
 
+    if let Some(replacement) = &args.replace {
+        let effective_query = crate::planner::resolve_effective_query(query, &options)?;
+        let target = formatter::replace_target_pattern(
+            &crate::parser::parse_query(&effective_query)?,
+            options.regex_flags,
+            options.whole_word,
+        )?;
+        // Byte ranges must line up with the original content, so only the path display
+        // preference applies here.
+        let mut report = crate::search_with_stats(query, options)?;
+        for result in &mut report.results {
+            result.path = apply_cli_path_display(result.file_identity(), path_display(&args));
+        }
+        formatter::print_replace_preview(&mut writer, &report, replacement, target.as_ref())?;
+        maybe_log_diagnostics(&args, &report.diagnostics);
+        return write_report_summary_json(&args, &report, started);
+    }
+
     if matches!(args.format, crate::Format::Json) {
         let mut request = search_request_from_args(&args);
         request.output = Some(if args.find {
@@ -53,9 +182,13 @@ pub fn run_search(mut args: SearchArgs) -> Result<()> {
             OutputMode::Full
         });
         let response = crate::request::execute_search_request(&request)?;
-        serde_json::to_writer_pretty(&mut writer, &response)?;
+        if args.json_compact {
+            serde_json::to_writer(&mut writer, &response)?;
+        } else {
+            serde_json::to_writer_pretty(&mut writer, &response)?;
+        }
         writer.write_all(b"\n")?;
-        return Ok(());
+        return write_response_summary_json(&args, &response, started);
     }
 
     match args.format {
@@ -66,56 +199,249 @@ pub fn run_search(mut args: SearchArgs) -> Result<()> {
                 &response.results,
                 &args.format,
                 args.time_format,
+                args.null_separated,
             )?;
-            maybe_log_contract_diagnostics(&response.diagnostics);
+            maybe_log_contract_diagnostics(&args, &response.diagnostics);
+            write_response_summary_json(&args, &response, started)?;
         }
         crate::Format::Summary => {
             let report =
                 apply_cli_output_preferences(crate::search_with_stats(query, options)?, &args);
-            for result in &report.results {
-                let single = SearchReport {
-                    results: vec![result.clone()],
-                    stats: SearchStats::default(),
-                    diagnostics: Vec::new(),
-                };
-                formatter::print_report_output(
-                    &mut writer,
-                    &single,
-                    &args.format,
-                    args.line_numbers,
-                    args.no_headers,
-                    use_color,
-                    args.context.unwrap_or(0),
-                    args.show_suppressed_placeholders,
-                    args.time_format,
-                )?;
+            let sections = formatter::group_results(&report.results, args.group_by);
+            for (index, (section, results)) in sections.into_iter().enumerate() {
+                if args.group_by != crate::GroupByFlag::None {
+                    print_section_header(&mut writer, index, &section)?;
+                }
+                for result in results {
+                    let single = SearchReport {
+                        results: vec![result.clone()],
+                        stats: SearchStats::default(),
+                        diagnostics: Vec::new(),
+                    };
+                    formatter::print_report_output(
+                        &mut writer,
+                        &single,
+                        &args.format,
+                        &render_options,
+                    )?;
+                }
             }
-            maybe_log_diagnostics(&report.diagnostics);
+            maybe_log_diagnostics(&args, &report.diagnostics);
+            write_report_summary_json(&args, &report, started)?;
         }
         _ => {
             let report =
                 apply_cli_output_preferences(crate::search_with_stats(query, options)?, &args);
-            formatter::print_report_output(
-                &mut writer,
-                &report,
-                &args.format,
-                args.line_numbers,
-                args.no_headers,
-                use_color,
-                args.context.unwrap_or(0),
-                args.show_suppressed_placeholders,
-                args.time_format,
-            )?;
-            maybe_log_diagnostics(&report.diagnostics);
+            let print_report = |writer: &mut Box<dyn Write>, report: &SearchReport| {
+                formatter::print_report_output(writer, report, &args.format, &render_options)
+            };
+            if args.group_by == crate::GroupByFlag::None {
+                print_report(&mut writer, &report)?;
+            } else {
+                let sections = formatter::group_results(&report.results, args.group_by);
+                for (index, (section, results)) in sections.into_iter().enumerate() {
+                    print_section_header(&mut writer, index, &section)?;
+                    let section_report = SearchReport {
+                        results: results.into_iter().cloned().collect(),
+                        stats: SearchStats::default(),
+                        diagnostics: Vec::new(),
+                    };
+                    print_report(&mut writer, &section_report)?;
+                }
+            }
+            maybe_log_diagnostics(&args, &report.diagnostics);
+            write_report_summary_json(&args, &report, started)?;
         }
     }
 
     Ok(())
 }
 
+/// Opens a `--group-by` section, separated from the previous one by a blank line.
+fn print_section_header(writer: &mut impl Write, index: usize, section: &str) -> Result<()> {
+    if index > 0 {
+        writeln!(writer)?;
+    }
+    writeln!(writer, "=== {section} ===")?;
+    Ok(())
+}
+
+/// The document `--summary-json` writes.
+#[derive(Debug, Serialize)]
+struct SummaryJson {
+    files_scanned: usize,
+    files_matched: usize,
+    total_matches: usize,
+    elapsed_ms: u64,
+    /// Matched files per language profile name; files without a profile count as `other`.
+    languages: BTreeMap<String, usize>,
+}
+
+impl SummaryJson {
+    fn new<'a>(
+        files_scanned: usize,
+        total_matches: usize,
+        matched_paths: impl IntoIterator<Item = &'a Path>,
+        ext_map: &HashMap<String, String>,
+        started: Instant,
+    ) -> Self {
+        let mut files_matched = 0;
+        let mut languages = BTreeMap::new();
+        for path in matched_paths {
+            files_matched += 1;
+            let language = crate::language_for_path(path, ext_map).unwrap_or("other");
+            *languages.entry(language.to_string()).or_insert(0) += 1;
+        }
+        Self {
+            files_scanned,
+            files_matched,
+            total_matches,
+            elapsed_ms: started.elapsed().as_millis() as u64,
+            languages,
+        }
+    }
+}
+
+fn write_report_summary_json(
+    args: &SearchArgs,
+    report: &SearchReport,
+    started: Instant,
+) -> Result<()> {
+    let Some(path) = &args.summary_json else {
+        return Ok(());
+    };
+    let summary = SummaryJson::new(
+        report.stats.candidate_files,
+        report.stats.matched_ranges,
+        report.results.iter().map(|result| result.path.as_path()),
+        &ext_map(args),
+        started,
+    );
+    write_summary_json(path, &summary)
+}
+
+fn write_response_summary_json(
+    args: &SearchArgs,
+    response: &SearchResponse,
+    started: Instant,
+) -> Result<()> {
+    let Some(path) = &args.summary_json else {
+        return Ok(());
+    };
+    let summary = SummaryJson::new(
+        response.stats.candidate_files,
+        response.stats.matched_ranges,
+        response
+            .results
+            .iter()
+            .map(|item| Path::new(crate::request::item_path(item))),
+        &ext_map(args),
+        started,
+    );
+    write_summary_json(path, &summary)
+}
+
+/// `--ext-map` remappings, for the CLI-side steps that pick a language profile by path.
+fn ext_map(args: &SearchArgs) -> HashMap<String, String> {
+    args.ext_map.iter().cloned().collect()
+}
+
+fn write_summary_json(path: &Path, summary: &SummaryJson) -> Result<()> {
+    let mut json = serde_json::to_string_pretty(summary)?;
+    json.push('\n');
+    fs::write(path, json)?;
+    Ok(())
+}
+
+/// Fills in options the command line left unset from the config's `[defaults]` table.
+fn apply_config_defaults(args: &mut SearchArgs, defaults: &crate::config::SearchDefaults) {
+    if !args.hidden && !args.no_hidden {
+        args.hidden = defaults.hidden.unwrap_or(false);
+    }
+    if !args.no_ignore && !args.ignore {
+        args.no_ignore = defaults.no_ignore.unwrap_or(false);
+    }
+    if args.max_depth.is_none() {
+        args.max_depth = defaults.max_depth;
+    }
+    if !args.format_explicit && args.format == crate::Format::default() {
+        if let Some(format) = &defaults.format {
+            args.format = format.clone();
+        }
+    }
+    for (extension, language) in &defaults.ext_map {
+        let extension = extension
+            .trim()
+            .trim_start_matches('.')
+            .to_ascii_lowercase();
+        if !args.ext_map.iter().any(|(mapped, _)| *mapped == extension) {
+            args.ext_map
+                .push((extension, language.trim().to_ascii_lowercase()));
+        }
+    }
+}
+
+/// `--context-before` and `--context-after` each override `--context` on their side.
+fn context_lines(args: &SearchArgs) -> crate::ContextLines {
+    let lines = args.context.unwrap_or(0);
+    crate::ContextLines {
+        before: args.context_before.unwrap_or(lines),
+        after: args.context_after.unwrap_or(lines),
+    }
+}
+
+/// Resolves `--absolute` and `--relative` into the path display mode they stand for.
+fn path_display(args: &SearchArgs) -> crate::PathDisplayModeFlag {
+    if args.absolute {
+        crate::PathDisplayModeFlag::Absolute
+    } else if args.relative {
+        crate::PathDisplayModeFlag::RelativeToCwd
+    } else {
+        args.path_display
+    }
+}
+
+fn heading_style(args: &SearchArgs) -> crate::HeadingStyle {
+    if args.heading {
+        crate::HeadingStyle::Grouped
+    } else if args.no_heading {
+        crate::HeadingStyle::Inline
+    } else {
+        crate::HeadingStyle::PerFile
+    }
+}
+
+fn report_render_options(args: &SearchArgs, use_color: bool) -> formatter::ReportRenderOptions {
+    formatter::ReportRenderOptions {
+        json_compact: args.json_compact,
+        with_line_numbers: args.line_numbers,
+        no_headers: args.no_headers,
+        heading: heading_style(args),
+        use_color,
+        context: context_lines(args),
+        max_matches_per_file: args.max_matches_per_file,
+        show_suppressed_placeholders: args.show_suppressed_placeholders,
+        time_format: args.time_format,
+    }
+}
+
 fn apply_cli_output_preferences(mut report: SearchReport, args: &SearchArgs) -> SearchReport {
+    if args.sort_by == crate::SortByFlag::Relevance {
+        let mut scored: Vec<(f64, SearchResult)> = report
+            .results
+            .drain(..)
+            .map(|result| (result.score(), result))
+            .collect();
+        // `sort_by` is stable, so equally scored files stay in path order.
+        scored.sort_by(|(left, _), (right, _)| right.total_cmp(left));
+        report.results = scored.into_iter().map(|(_, result)| result).collect();
+    }
+    let ext_map = ext_map(args);
     for result in &mut report.results {
-        result.path = apply_cli_path_display(result.file_identity(), args.path_display);
+        if args.expand_to_scope {
+            expand_matches_to_scope(result, &ext_map);
+        }
+        result.path = apply_cli_path_display(result.file_identity(), path_display(args));
         if matches!(args.line_endings, crate::LineEndingModeFlag::Normalize) {
             result.content = normalize_line_endings(&result.content);
             for matched in &mut result.matches {
@@ -123,9 +449,170 @@ fn apply_cli_output_preferences(mut report: SearchReport, args: &SearchArgs) ->
             }
         }
     }
+    if args.dedup_content {
+        report.results = dedup_identical_content(report.results);
+    }
+    // After dedup, so files that only differ past the cut are still told apart.
+    if let Some(max_columns) = args.max_columns {
+        for result in &mut report.results {
+            result.content = truncate_long_lines(&result.content, max_columns);
+        }
+    }
     report
 }
 
+/// Marks the end of a line cut short by `--max-columns`.
+const TRUNCATION_MARKER: &str = "…";
+
+/// Cuts every line longer than `max_columns` characters down to that many characters followed
+/// by [`TRUNCATION_MARKER`], keeping line endings. Line numbers are unchanged, so matches still
+/// point at the right lines.
+fn truncate_long_lines(content: &str, max_columns: usize) -> String {
+    let mut truncated = String::with_capacity(content.len());
+    for line in content.split_inclusive('\n') {
+        let text = line.trim_end_matches(['\r', '\n']);
+        match text.char_indices().nth(max_columns) {
+            Some((cut, _)) => {
+                truncated.push_str(&text[..cut]);
+                truncated.push_str(TRUNCATION_MARKER);
+                truncated.push_str(&line[text.len()..]);
+            }
+            None => truncated.push_str(line),
+        }
+    }
+    truncated
+}
+
+/// Keeps the first result for each distinct file content and records the paths of later
+/// copies in its `content_aliases`. Results without loaded content are kept as they are.
+fn dedup_identical_content(results: Vec<SearchResult>) -> Vec<SearchResult> {
+    let mut first_by_hash: HashMap<u64, Vec<usize>> = HashMap::new();
+    let mut kept: Vec<SearchResult> = Vec::with_capacity(results.len());
+    for result in results {
+        if !result.content_available() {
+            kept.push(result);
+            continue;
+        }
+        let hash = xxhash_rust::xxh3::xxh3_64(result.content.as_bytes());
+        let candidates = first_by_hash.entry(hash).or_default();
+        // Compare the content too, so a hash collision never hides a file.
+        match candidates
+            .iter()
+            .copied()
+            .find(|&index| kept[index].content == result.content)
+        {
+            Some(index) => kept[index].metadata.content_aliases.push(result.path),
+            None => {
+                candidates.push(kept.len());
+                kept.push(result);
+            }
+        }
+    }
+    kept
+}
+
+/// Widens each match to its enclosing function, method, or class. Files without a language
+/// profile and matches outside any such scope are left unchanged.
+fn expand_matches_to_scope(result: &mut SearchResult, ext_map: &HashMap<String, String>) {
+    if result.matches.is_empty() || !result.content_available() {
+        return;
+    }
+    let Some(tree) = crate::predicates::code_aware::parse_with_path_profile(
+        &result.path,
+        ext_map,
+        &result.content,
+    ) else {
+        return;
+    };
+    for matched in &mut result.matches {
+        let Some(range) = crate::predicates::code_aware::enclosing_scope_range(
+            &tree,
+            matched.byte_range.start,
+            matched.byte_range.end,
+        ) else {
+            continue;
+        };
+        *matched = Match {
+            start_line: range.start_point.row + 1,
+            end_line: range.end_point.row + 1,
+            start_column: range.start_point.column,
+            end_column: range.end_point.column,
+            text: result.content[range.start_byte..range.end_byte].to_string(),
+            byte_range: range.start_byte..range.end_byte,
+            context_before: Vec::new(),
+            context_after: Vec::new(),
+            predicate: matched.predicate.clone(),
+            enclosing: matched.enclosing.clone(),
+        };
+    }
+    result.matches.dedup_by(|a, b| a.byte_range == b.byte_range);
+}
+
+/// Writes each result's formatted output to its own file under `output_dir`, mirroring the
+/// result's path relative to the search root.
+fn write_output_dir(output_dir: &Path, report: &SearchReport, args: &SearchArgs) -> Result<()> {
+    let mut written = HashSet::new();
+    for result in &report.results {
+        let target = output_dir_target(output_dir, result.file_identity())?;
+        if !written.insert(target.clone()) {
+            anyhow::bail!(
+                "Refusing to overwrite {}: more than one result maps to it",
+                target.display()
+            );
+        }
+
+        let single = SearchReport {
+            results: vec![result.clone()],
+            stats: SearchStats::default(),
+            diagnostics: Vec::new(),
+        };
+        let mut buffer = Vec::new();
+        formatter::print_report_body(
+            &mut buffer,
+            &single,
+            &args.format,
+            &report_render_options(args, false),
+        )?;
+
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&target, buffer)?;
+    }
+    Ok(())
+}
+
+/// Maps a result to its file under `output_dir`. Only plain path components are kept, so a
+/// result can never be written outside the output directory.
+fn output_dir_target(output_dir: &Path, identity: &crate::FileIdentity) -> Result<PathBuf> {
+    let relative = identity
+        .root_relative_path
+        .as_ref()
+        .unwrap_or(&identity.display_path);
+    let mut target = output_dir.to_path_buf();
+    let mut has_file_name = false;
+    for component in relative.components() {
+        match component {
+            Component::CurDir => {}
+            Component::Normal(part) => {
+                target.push(part);
+                has_file_name = true;
+            }
+            _ => anyhow::bail!(
+                "Refusing to write {} outside the output directory",
+                relative.display()
+            ),
+        }
+    }
+    if !has_file_name {
+        anyhow::bail!(
+            "Cannot derive an output file name for {}",
+            relative.display()
+        );
+    }
+    Ok(target)
+}
+
 fn apply_cli_path_display(
     identity: &crate::FileIdentity,
     mode: crate::PathDisplayModeFlag,
@@ -137,6 +624,7 @@ fn apply_cli_path_display(
             .root_relative_path
             .clone()
             .unwrap_or_else(|| identity.display_path.clone()),
+        crate::PathDisplayModeFlag::RelativeToCwd => identity.cwd_relative_path(),
     }
 }
 
@@ -144,53 +632,87 @@ fn normalize_line_endings(text: &str) -> String {
     text.replace("\r\n", "\n").replace('\r', "\n")
 }
 
-fn maybe_log_diagnostics(diagnostics: &[crate::SearchDiagnostic]) {
-    let enabled = std::env::var("RDUMP_LOG_DIAGNOSTICS")
+/// True when `RDUMP_LOG_DIAGNOSTICS` asks for every diagnostic on stderr, not just the
+/// per-file warnings printed by default.
+fn log_all_diagnostics() -> bool {
+    std::env::var("RDUMP_LOG_DIAGNOSTICS")
         .ok()
-        .is_some_and(|value| value == "1" || value.eq_ignore_ascii_case("true"));
-    if !enabled {
-        return;
+        .is_some_and(|value| value == "1" || value.eq_ignore_ascii_case("true"))
+}
+
+/// Diagnostics worth a warning on every run: files skipped because they are too large or look
+/// like they hold secrets, and files whose syntax tree could not be built. Binary files are
+/// skipped silently, as other search tools do.
+fn is_per_file_warning(diagnostic: &crate::SearchDiagnostic) -> bool {
+    match diagnostic.kind {
+        crate::content::DiagnosticKind::ContentSkipped => {
+            diagnostic.message.ends_with("(too_large)")
+                || diagnostic.message.ends_with("(secret_like)")
+        }
+        crate::content::DiagnosticKind::SemanticSkip => diagnostic
+            .message
+            .to_ascii_lowercase()
+            .contains("failed to parse"),
+        _ => false,
     }
+}
 
+/// Prints per-file warnings, or every diagnostic under `RDUMP_LOG_DIAGNOSTICS`, to stderr.
+/// `--no-messages` silences both.
+fn maybe_log_diagnostics(args: &SearchArgs, diagnostics: &[crate::SearchDiagnostic]) {
+    let messages = crate::Messages::new(args.no_messages);
+    let log_all = log_all_diagnostics();
     for diagnostic in diagnostics {
-        if let Some(path) = &diagnostic.path {
-            eprintln!(
-                "[{}:{}] {} ({})",
-                format!("{:?}", diagnostic.level).to_lowercase(),
-                format!("{:?}", diagnostic.kind).to_lowercase(),
-                diagnostic.message,
-                path.display()
-            );
-        } else {
-            eprintln!(
-                "[{}:{}] {}",
-                format!("{:?}", diagnostic.level).to_lowercase(),
-                format!("{:?}", diagnostic.kind).to_lowercase(),
-                diagnostic.message
-            );
+        if !log_all && !is_per_file_warning(diagnostic) {
+            continue;
+        }
+        let level = format!("{:?}", diagnostic.level).to_lowercase();
+        let kind = format!("{:?}", diagnostic.kind).to_lowercase();
+        let label = format!("[{level}:{kind}] {}", diagnostic.message);
+        match &diagnostic.path {
+            Some(path) => messages.warn(format_args!("{label} ({})", path.display())),
+            None => messages.warn(label),
         }
     }
 }
 
-fn maybe_log_contract_diagnostics(diagnostics: &[rdump_contracts::SearchDiagnostic]) {
-    let enabled = std::env::var("RDUMP_LOG_DIAGNOSTICS")
-        .ok()
-        .is_some_and(|value| value == "1" || value.eq_ignore_ascii_case("true"));
-    if !enabled {
+/// Path-only output never loads content, so only `RDUMP_LOG_DIAGNOSTICS` prints anything.
+fn maybe_log_contract_diagnostics(
+    args: &SearchArgs,
+    diagnostics: &[rdump_contracts::SearchDiagnostic],
+) {
+    if !log_all_diagnostics() {
         return;
     }
 
+    let messages = crate::Messages::new(args.no_messages);
     for diagnostic in diagnostics {
-        if let Some(path) = &diagnostic.path {
-            eprintln!(
-                "[{}:{}] {} ({})",
-                diagnostic.level, diagnostic.kind, diagnostic.message, path
-            );
-        } else {
-            eprintln!(
-                "[{}:{}] {}",
-                diagnostic.level, diagnostic.kind, diagnostic.message
-            );
+        let label = format!(
+            "[{}:{}] {}",
+            diagnostic.level, diagnostic.kind, diagnostic.message
+        );
+        match &diagnostic.path {
+            Some(path) => messages.warn(format_args!("{label} ({path})")),
+            None => messages.warn(label),
+        }
+    }
+}
+
+/// Decides whether output gets ANSI colors. Files written with `--output` never do, and a
+/// non-empty `NO_COLOR` disables color even with `--color=always`. With `--color=auto`, color
+/// follows whether stdout is a terminal unless `CLICOLOR_FORCE` is set to something other
+/// than `0`.
+fn should_use_color(color: &ColorChoice, writing_to_file: bool) -> bool {
+    if writing_to_file || std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty()) {
+        return false;
+    }
+    match color {
+        ColorChoice::Always => true,
+        ColorChoice::Never => false,
+        ColorChoice::Auto => {
+            std::env::var_os("CLICOLOR_FORCE")
+                .is_some_and(|value| !value.is_empty() && value != "0")
+                || io::stdout().is_terminal()
         }
     }
 }
@@ -231,9 +753,14 @@ pub fn search_request_from_args(args: &SearchArgs) -> SearchRequest {
             crate::Format::Matches => Some(OutputMode::Matches),
             crate::Format::Snippets => Some(OutputMode::Snippets),
             crate::Format::Json
+            | crate::Format::LspJson
+            | crate::Format::Github
+            | crate::Format::Diff
             | crate::Format::Cat
             | crate::Format::Markdown
-            | crate::Format::Hunks => Some(OutputMode::Full),
+            | crate::Format::Hunks
+            | crate::Format::Aggregate
+            | crate::Format::DirSummary => Some(OutputMode::Full),
             crate::Format::Paths | crate::Format::Find => Some(OutputMode::Paths),
         }
     };
@@ -243,12 +770,15 @@ pub fn search_request_from_args(args: &SearchArgs) -> SearchRequest {
         root: Some(args.root.display().to_string()),
         presets: args.preset.clone(),
         no_ignore: args.no_ignore,
+        respect_global_gitignore: !args.no_global_ignore,
+        no_ignore_parent: args.no_ignore_parent,
         hidden: args.hidden,
         max_depth: args.max_depth,
+        min_depth: args.min_depth,
         sql_dialect: args.dialect.map(Into::into),
         sql_strict: args.sql_strict,
         output,
-        limits: Some(cli_unbounded_limits()),
+        limits: Some(cli_limits(args)),
         context_lines: args.context,
         error_mode: if args.fail_fast {
             ErrorMode::FailFast
@@ -267,19 +797,37 @@ pub fn search_request_from_args(args: &SearchArgs) -> SearchRequest {
         ignore_debug: args.ignore_debug,
         language_debug: args.language_debug,
         sql_trace: args.sql_trace,
+        files_without_match: args.files_without_match,
+        secret_patterns: None,
+        line_range: args.line_range,
+        encoding: args.encoding.into(),
         execution_profile: args.execution_profile.map(Into::into),
         offset: 0,
         continuation_token: None,
-        path_display: Some(args.path_display.into()),
+        path_display: Some(path_display(args).into()),
         line_endings: Some(args.line_endings.into()),
         include_match_text: !args.no_match_text,
+        deterministic: args.deterministic,
+        same_file_system: args.one_file_system,
+        threads: args.threads,
+        regex_flags: rdump_contracts::RegexFlags {
+            dotall: args.regex_dotall,
+            multiline: args.regex_multiline,
+            case_insensitive: args.regex_case_insensitive,
+        },
+        ignore_case_paths: args.ignore_case_paths,
+        whole_word: args.whole_word,
+        ext_map: args.ext_map.iter().cloned().collect(),
+        skip_content: args.no_content,
+        with_metadata: args.with_metadata,
     }
 }
 
-fn cli_unbounded_limits() -> Limits {
+fn cli_limits(args: &SearchArgs) -> Limits {
+    let cap = |value: Option<usize>| value.map_or(LimitValue::Unlimited, LimitValue::Value);
     Limits {
-        max_results: LimitValue::Unlimited,
-        max_matches_per_file: LimitValue::Unlimited,
+        max_results: cap(args.max_matches),
+        max_matches_per_file: cap(args.max_matches_per_file),
         max_bytes_per_file: LimitValue::Unlimited,
         max_total_bytes: LimitValue::Unlimited,
         max_match_bytes: LimitValue::Unlimited,
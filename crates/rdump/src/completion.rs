@@ -0,0 +1,218 @@
+//! Offline completion for partially typed RQL queries, for shell completion and editor plugins.
+
+use crate::parser::PredicateKey;
+use crate::predicates::{
+    content_predicate_keys, metadata_predicate_keys, react_predicate_keys, semantic_predicate_keys,
+};
+use crate::request::predicate_catalog;
+
+/// A suggested predicate for the token under the cursor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Completion {
+    /// Text that replaces the partial token, including the trailing `:`.
+    pub text: String,
+    /// Canonical predicate name; differs from `text` when an alias was completed.
+    pub predicate: String,
+    /// `metadata`, `content`, `semantic`, or `react` (JSX/TSX files only).
+    pub category: &'static str,
+    pub description: &'static str,
+}
+
+/// Suggests predicate keys for the last token of `partial`.
+///
+/// A token that already contains `:` is a predicate value, which is open-ended, so nothing is
+/// suggested for it; the same goes for a cursor inside a quoted value. An empty token (an
+/// empty query, or one ending in an operator or whitespace) suggests every predicate.
+/// Suggestions are sorted by their completed text.
+pub fn rql_completions(partial: &str) -> Vec<Completion> {
+    let Some(token) = trailing_token(partial) else {
+        return Vec::new();
+    };
+    if token.contains(':') {
+        return Vec::new();
+    }
+    let token = token.to_ascii_lowercase();
+
+    let aliases: Vec<(String, String)> = predicate_catalog()
+        .predicates
+        .into_iter()
+        .flat_map(|descriptor| {
+            let name = descriptor.name;
+            descriptor
+                .aliases
+                .into_iter()
+                .map(move |alias| (alias, name.clone()))
+        })
+        .collect();
+
+    let mut completions = Vec::new();
+    for (category, keys) in [
+        ("metadata", metadata_predicate_keys()),
+        ("content", content_predicate_keys()),
+        ("semantic", semantic_predicate_keys()),
+        ("react", react_predicate_keys()),
+    ] {
+        for key in keys {
+            let name = key.as_ref();
+            let candidates = std::iter::once(name).chain(
+                aliases
+                    .iter()
+                    .filter(|(_, target)| target == name)
+                    .map(|(alias, _)| alias.as_str()),
+            );
+            for candidate in candidates {
+                if candidate.starts_with(&token) {
+                    completions.push(Completion {
+                        text: format!("{candidate}:"),
+                        predicate: name.to_string(),
+                        category,
+                        description: predicate_description(&key),
+                    });
+                }
+            }
+        }
+    }
+    completions.sort_by(|left, right| left.text.cmp(&right.text));
+    completions
+}
+
+/// One-line summary of what a predicate matches.
+pub fn predicate_description(key: &PredicateKey) -> &'static str {
+    match key {
+        PredicateKey::Ext => "File extension, case-insensitive. Accepts a comma-separated list.",
+        PredicateKey::Name => "File name (basename) glob, case-insensitive.",
+        PredicateKey::Path => "Substring or glob anywhere in the path.",
+        PredicateKey::PathExact => "Exact path match.",
+        PredicateKey::In => "Files directly in a directory, or under it with a glob.",
+        PredicateKey::Size => "File size comparison or range, e.g. `>=10kb` or `10kb..1mb`.",
+        PredicateKey::Modified => "Modification time comparison or range, e.g. `<2d` or `7d..1d`.",
+        PredicateKey::Contains => "Case-insensitive literal substring in file content.",
+        PredicateKey::Matches => "Regex search on file content.",
+        PredicateKey::Def => "Any definition (class, struct, trait, etc.).",
+        PredicateKey::Func => "Function or method definition.",
+        PredicateKey::Import => "Import, use, or require statement.",
+        PredicateKey::Class => "Class definition.",
+        PredicateKey::Struct => "Struct definition.",
+        PredicateKey::Field => "Struct or class field declaration.",
+        PredicateKey::Enum => "Enum definition.",
+        PredicateKey::EnumVariant => "Enum variant or constant.",
+        PredicateKey::Interface => "Interface definition.",
+        PredicateKey::InterfaceMethod => "Method signature declared inside an interface body.",
+        PredicateKey::Trait => "Trait definition.",
+        PredicateKey::TraitMethod => "Method signature declared inside a trait body.",
+        PredicateKey::Type => "Type alias.",
+        PredicateKey::Impl => "Impl block.",
+        PredicateKey::Implements => "Types that implement a trait or interface.",
+        PredicateKey::Macro => "Macro definition.",
+        PredicateKey::Module => "Module definition.",
+        PredicateKey::Namespace => "Namespace block.",
+        PredicateKey::Object => "Object or singleton definition.",
+        PredicateKey::Protocol => "Protocol definition.",
+        PredicateKey::Template => "Template declaration.",
+        PredicateKey::Service => "Protobuf/gRPC service definition.",
+        PredicateKey::Comment => "Text within a comment.",
+        PredicateKey::Doc => "Text within a doc comment or docstring.",
+        PredicateKey::Str => {
+            "Text within a string literal. Prefix raw:, template: or doc: to pick a kind."
+        }
+        PredicateKey::StrExact => "String literal whose contents equal the value.",
+        PredicateKey::Call => "Function or method call site.",
+        PredicateKey::DefnBody => "Function definitions whose body calls the value.",
+        PredicateKey::Target => "Makefile rule target.",
+        PredicateKey::Stage => "Dockerfile build stage alias.",
+        PredicateKey::From => "Dockerfile base image.",
+        PredicateKey::Block => "HCL block by type.",
+        PredicateKey::Route => "Web route registration path.",
+        PredicateKey::Property => "Property declaration.",
+        PredicateKey::Extends => "Classes that extend the named base class.",
+        PredicateKey::Test => "Test declaration by name.",
+        PredicateKey::Decorator => "Decorator or attribute applied to a declaration.",
+        PredicateKey::Heading => "Markdown heading text.",
+        PredicateKey::Link => "Markdown link target.",
+        PredicateKey::Component => "React component definition.",
+        PredicateKey::Element => "JSX element tag.",
+        PredicateKey::Hook => "React hook call.",
+        PredicateKey::CustomHook => "Custom React hook definition.",
+        PredicateKey::Prop => "Prop passed to a JSX element.",
+        PredicateKey::Other(_) => "",
+    }
+}
+
+/// Returns the token being typed at the end of `partial`, or `None` inside a quoted value.
+fn trailing_token(partial: &str) -> Option<&str> {
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+    let mut start = 0;
+    for (index, ch) in partial.char_indices() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+        match (quote, ch) {
+            (Some(_), '\\') => escaped = true,
+            (Some(open), ch) if ch == open => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(ch),
+            (None, ch) if ch.is_whitespace() || "&|!()>".contains(ch) => {
+                start = index + ch.len_utf8();
+            }
+            (None, _) => {}
+        }
+    }
+    match quote {
+        Some(_) => None,
+        None => Some(&partial[start..]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(partial: &str) -> Vec<String> {
+        rql_completions(partial)
+            .into_iter()
+            .map(|completion| completion.text)
+            .collect()
+    }
+
+    #[test]
+    fn test_prefix_suggests_predicate_keys() {
+        assert_eq!(texts("fu"), vec!["func:", "func_containing:"]);
+        let func = &rql_completions("FUNC")[0];
+        assert_eq!(func.predicate, "func");
+        assert_eq!(func.category, "semantic");
+        assert_eq!(func.description, "Function or method definition.");
+        let alias = &rql_completions("func_c")[0];
+        assert_eq!(alias.predicate, "defn_body");
+    }
+
+    #[test]
+    fn test_values_are_not_completed() {
+        assert!(rql_completions("func:").is_empty());
+        assert!(rql_completions("func:ma").is_empty());
+        assert!(rql_completions("contains:'a & fu").is_empty());
+    }
+
+    #[test]
+    fn test_completes_the_token_after_operators() {
+        assert_eq!(
+            texts("ext:rs & (!im"),
+            vec!["impl:", "implements:", "import:"]
+        );
+        assert_eq!(texts("contains:'x y' | co"), texts("co"));
+        assert!(texts("co").contains(&"component:".to_string()));
+    }
+
+    #[test]
+    fn test_empty_token_lists_every_predicate() {
+        let all = rql_completions("ext:rs & ");
+        assert_eq!(all.len(), rql_completions("").len());
+        assert!(all
+            .iter()
+            .all(|completion| !completion.description.is_empty()));
+        assert!(all
+            .iter()
+            .any(|completion| completion.text == "hook:" && completion.category == "react"));
+    }
+}
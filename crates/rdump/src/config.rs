@@ -100,12 +100,51 @@ impl PresetDefinition {
     }
 }
 
+/// Search options from a config's `[defaults]` table. `rdump search` applies them below
+/// explicit command-line flags.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchDefaults {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hidden: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub no_ignore: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_depth: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<crate::Format>,
+    /// Extension to language remappings, as with `--ext-map`. Keys may span several dots
+    /// (`"rs.in" = "rust"`).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub ext_map: BTreeMap<String, String>,
+}
+
+impl SearchDefaults {
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// Layers `other` on top of `self`; keys set in `other` win.
+    fn overlay(self, other: SearchDefaults) -> Self {
+        let mut ext_map = self.ext_map;
+        ext_map.extend(other.ext_map);
+        Self {
+            hidden: other.hidden.or(self.hidden),
+            no_ignore: other.no_ignore.or(self.no_ignore),
+            max_depth: other.max_depth.or(self.max_depth),
+            format: other.format.or(self.format),
+            ext_map,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Config {
     #[serde(default = "default_config_schema_version")]
     pub schema_version: u32,
     #[serde(default)]
     pub presets: BTreeMap<String, PresetDefinition>,
+    #[serde(default, skip_serializing_if = "SearchDefaults::is_empty")]
+    pub defaults: SearchDefaults,
 }
 
 impl Default for Config {
@@ -113,6 +152,7 @@ impl Default for Config {
         Self {
             schema_version: CONFIG_SCHEMA_VERSION,
             presets: BTreeMap::new(),
+            defaults: SearchDefaults::default(),
         }
     }
 }
@@ -214,6 +254,7 @@ pub fn load_config_report_for_dir(start_dir: &Path) -> Result<ConfigReport> {
             ));
         }
 
+        merged.defaults = merged.defaults.overlay(config.defaults);
         for (name, definition) in config.presets {
             layered_presets.insert(
                 name,
@@ -512,6 +553,48 @@ mod tests {
         env::remove_var("RDUMP_TEST_CONFIG_DIR");
     }
 
+    #[test]
+    fn test_load_config_layers_search_defaults() {
+        let _lock = ENV_MUTEX
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let test_dir = tempdir().unwrap();
+
+        let fake_home_dir = test_dir.path().join("home");
+        fs::create_dir_all(fake_home_dir.join("rdump")).unwrap();
+        fs::write(
+            fake_home_dir.join("rdump/config.toml"),
+            "[defaults]\nhidden = true\nmax_depth = 3\nformat = \"lsp-json\"\n\n[defaults.ext_map]\nmjs = \"js\"\n\"rs.in\" = \"rs\"\n",
+        )
+        .unwrap();
+
+        let project_dir = test_dir.path().join("project");
+        fs::create_dir(&project_dir).unwrap();
+        fs::write(
+            project_dir.join(".rdump.toml"),
+            "[defaults]\nhidden = false\nno_ignore = true\n\n[defaults.ext_map]\n\"rs.in\" = \"rust\"\n",
+        )
+        .unwrap();
+
+        env::set_var("RDUMP_TEST_CONFIG_DIR", fake_home_dir.to_str().unwrap());
+        let defaults = load_config_for_dir(&project_dir).unwrap().defaults;
+        env::remove_var("RDUMP_TEST_CONFIG_DIR");
+
+        assert_eq!(
+            defaults,
+            SearchDefaults {
+                hidden: Some(false),
+                no_ignore: Some(true),
+                max_depth: Some(3),
+                format: Some(crate::Format::LspJson),
+                ext_map: BTreeMap::from([
+                    ("mjs".to_string(), "js".to_string()),
+                    ("rs.in".to_string(), "rust".to_string()),
+                ]),
+            }
+        );
+    }
+
     #[test]
     fn test_save_config_prefers_repo_local_dir() {
         let _lock = ENV_MUTEX
@@ -1,10 +1,11 @@
 use anyhow::{Context, Result};
+use rdump_contracts::Encoding;
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use crate::backend::{RealFsSearchBackend, SearchBackend};
-use crate::limits::{is_probably_binary, maybe_contains_secret, MAX_FILE_SIZE};
+use crate::limits::{is_probably_binary, SecretDetector, MAX_FILE_SIZE};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -182,6 +183,8 @@ pub enum ContentSkipReason {
     TooLarge,
     Binary,
     SecretLike,
+    /// The caller asked for results without content.
+    NotRequested,
 }
 
 impl ContentSkipReason {
@@ -190,16 +193,20 @@ impl ContentSkipReason {
             ContentSkipReason::TooLarge => "too_large",
             ContentSkipReason::Binary => "binary",
             ContentSkipReason::SecretLike => "secret_like",
+            ContentSkipReason::NotRequested => "not_requested",
         }
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "kind", rename_all = "snake_case")]
 pub enum ContentState {
+    #[default]
     Loaded,
     LoadedLossy,
-    Skipped { reason: ContentSkipReason },
+    Skipped {
+        reason: ContentSkipReason,
+    },
 }
 
 impl ContentState {
@@ -223,6 +230,36 @@ pub fn load_search_content_with_backend(
     backend: &dyn SearchBackend,
     resolved_path: &Path,
     display_path: &Path,
+) -> Result<LoadedContent> {
+    load_search_content_with_detector(
+        backend,
+        resolved_path,
+        display_path,
+        &SecretDetector::Builtin,
+    )
+}
+
+pub fn load_search_content_with_detector(
+    backend: &dyn SearchBackend,
+    resolved_path: &Path,
+    display_path: &Path,
+    secret_detector: &SecretDetector,
+) -> Result<LoadedContent> {
+    load_search_content_with_encoding(
+        backend,
+        resolved_path,
+        display_path,
+        secret_detector,
+        Encoding::Auto,
+    )
+}
+
+pub fn load_search_content_with_encoding(
+    backend: &dyn SearchBackend,
+    resolved_path: &Path,
+    display_path: &Path,
+    secret_detector: &SecretDetector,
+    encoding: Encoding,
 ) -> Result<LoadedContent> {
     let metadata = backend
         .stat(resolved_path)
@@ -249,80 +286,100 @@ pub fn load_search_content_with_backend(
     let bytes = backend
         .read_bytes(resolved_path)
         .with_context(|| format!("Failed to read file {}", display_path.display()))?;
-    let check_len = bytes.len().min(8192);
+    let transcoder = transcoder_for(encoding, &bytes);
+    let is_utf16 = transcoder.is_some_and(|decoder| {
+        decoder == encoding_rs::UTF_16LE || decoder == encoding_rs::UTF_16BE
+    });
+
+    // UTF-16 text is full of NUL bytes, so sniff it for binary content after decoding instead.
+    if !is_utf16 && is_probably_binary(&bytes[..bytes.len().min(8192)]) {
+        return Ok(binary_content(display_path));
+    }
+
+    let (content, lossy_from) = match transcoder {
+        Some(decoder) => {
+            let (text, had_errors) = decoder.decode_with_bom_removal(&bytes);
+            (text.into_owned(), had_errors.then_some(decoder.name()))
+        }
+        None => match String::from_utf8(bytes) {
+            Ok(content) => (content, None),
+            Err(err) => (
+                String::from_utf8_lossy(err.as_bytes()).into_owned(),
+                Some("UTF-8"),
+            ),
+        },
+    };
 
-    if is_probably_binary(&bytes[..check_len]) {
+    if is_utf16 && is_probably_binary(&content.as_bytes()[..content.len().min(8192)]) {
+        return Ok(binary_content(display_path));
+    }
+
+    if secret_detector.is_match(&content) {
         return Ok(LoadedContent {
             content: Arc::<str>::from(""),
             state: ContentState::Skipped {
-                reason: ContentSkipReason::Binary,
+                reason: ContentSkipReason::SecretLike,
             },
             diagnostics: vec![SearchDiagnostic::content_skipped(
                 display_path.to_path_buf(),
-                ContentSkipReason::Binary,
-                format!("Skipping binary file {}", display_path.display()),
+                ContentSkipReason::SecretLike,
+                format!(
+                    "Skipping possible secret-containing file {}",
+                    display_path.display()
+                ),
             )],
         });
     }
 
-    match String::from_utf8(bytes) {
-        Ok(content) => {
-            if maybe_contains_secret(&content) {
-                return Ok(LoadedContent {
-                    content: Arc::<str>::from(""),
-                    state: ContentState::Skipped {
-                        reason: ContentSkipReason::SecretLike,
-                    },
-                    diagnostics: vec![SearchDiagnostic::content_skipped(
-                        display_path.to_path_buf(),
-                        ContentSkipReason::SecretLike,
-                        format!(
-                            "Skipping possible secret-containing file {}",
-                            display_path.display()
-                        ),
-                    )],
-                });
-            }
+    match lossy_from {
+        None => Ok(LoadedContent {
+            content: Arc::from(content.into_boxed_str()),
+            state: ContentState::Loaded,
+            diagnostics: Vec::new(),
+        }),
+        Some(encoding_name) => Ok(LoadedContent {
+            content: Arc::from(content.into_boxed_str()),
+            state: ContentState::LoadedLossy,
+            diagnostics: vec![SearchDiagnostic::new(
+                DiagnosticLevel::Warn,
+                DiagnosticKind::ContentDecodedLossy,
+                format!(
+                    "Decoded {} with lossy {} replacement",
+                    display_path.display(),
+                    encoding_name
+                ),
+                Some(display_path.to_path_buf()),
+            )],
+        }),
+    }
+}
 
-            Ok(LoadedContent {
-                content: Arc::from(content.into_boxed_str()),
-                state: ContentState::Loaded,
-                diagnostics: Vec::new(),
-            })
-        }
-        Err(err) => {
-            let content = String::from_utf8_lossy(err.as_bytes()).into_owned();
-            if maybe_contains_secret(&content) {
-                return Ok(LoadedContent {
-                    content: Arc::<str>::from(""),
-                    state: ContentState::Skipped {
-                        reason: ContentSkipReason::SecretLike,
-                    },
-                    diagnostics: vec![SearchDiagnostic::content_skipped(
-                        display_path.to_path_buf(),
-                        ContentSkipReason::SecretLike,
-                        format!(
-                            "Skipping possible secret-containing file {}",
-                            display_path.display()
-                        ),
-                    )],
-                });
-            }
+/// Picks the decoder used to transcode `bytes`, or `None` to read them as UTF-8 directly.
+fn transcoder_for(encoding: Encoding, bytes: &[u8]) -> Option<&'static encoding_rs::Encoding> {
+    match encoding {
+        Encoding::Auto => match encoding_rs::Encoding::for_bom(bytes) {
+            Some((detected, _)) if detected != encoding_rs::UTF_8 => Some(detected),
+            _ => None,
+        },
+        Encoding::Utf8 => None,
+        // encoding_rs follows the WHATWG mapping of the Latin-1 labels onto windows-1252.
+        Encoding::Latin1 => Some(encoding_rs::WINDOWS_1252),
+        Encoding::Utf16Le => Some(encoding_rs::UTF_16LE),
+        Encoding::Utf16Be => Some(encoding_rs::UTF_16BE),
+    }
+}
 
-            Ok(LoadedContent {
-                content: Arc::from(content.into_boxed_str()),
-                state: ContentState::LoadedLossy,
-                diagnostics: vec![SearchDiagnostic::new(
-                    DiagnosticLevel::Warn,
-                    DiagnosticKind::ContentDecodedLossy,
-                    format!(
-                        "Decoded {} with lossy UTF-8 replacement",
-                        display_path.display()
-                    ),
-                    Some(display_path.to_path_buf()),
-                )],
-            })
-        }
+fn binary_content(display_path: &Path) -> LoadedContent {
+    LoadedContent {
+        content: Arc::<str>::from(""),
+        state: ContentState::Skipped {
+            reason: ContentSkipReason::Binary,
+        },
+        diagnostics: vec![SearchDiagnostic::content_skipped(
+            display_path.to_path_buf(),
+            ContentSkipReason::Binary,
+            format!("Skipping binary file {}", display_path.display()),
+        )],
     }
 }
 
@@ -355,6 +412,57 @@ mod tests {
         assert!(!loaded.diagnostics.is_empty());
     }
 
+    #[test]
+    fn transcodes_utf16_with_bom_in_auto_mode() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("utf16.txt");
+        let mut bytes = vec![0xFF, 0xFE];
+        bytes.extend("héllo wörld".encode_utf16().flat_map(u16::to_le_bytes));
+        std::fs::write(&path, bytes).unwrap();
+
+        let loaded = load_search_content(&path).unwrap();
+        assert_eq!(loaded.state, ContentState::Loaded);
+        assert_eq!(loaded.content.as_ref(), "héllo wörld");
+    }
+
+    #[test]
+    fn transcodes_explicit_encodings() {
+        let dir = tempdir().unwrap();
+        let latin1 = dir.path().join("latin1.txt");
+        std::fs::write(&latin1, [b'c', b'a', b'f', 0xE9]).unwrap();
+        let utf16be = dir.path().join("utf16be.txt");
+        std::fs::write(
+            &utf16be,
+            "café"
+                .encode_utf16()
+                .flat_map(u16::to_be_bytes)
+                .collect::<Vec<_>>(),
+        )
+        .unwrap();
+
+        for (path, encoding) in [(&latin1, Encoding::Latin1), (&utf16be, Encoding::Utf16Be)] {
+            let loaded = load_search_content_with_encoding(
+                &RealFsSearchBackend,
+                path,
+                path,
+                &SecretDetector::Builtin,
+                encoding,
+            )
+            .unwrap();
+            assert_eq!(loaded.state, ContentState::Loaded);
+            assert_eq!(loaded.content.as_ref(), "café");
+        }
+
+        // Without a BOM, auto mode keeps reading UTF-8 and skips the NUL-laden bytes.
+        let loaded = load_search_content(&utf16be).unwrap();
+        assert_eq!(
+            loaded.state,
+            ContentState::Skipped {
+                reason: ContentSkipReason::Binary
+            }
+        );
+    }
+
     #[test]
     fn skips_binary_files() {
         let dir = tempdir().unwrap();
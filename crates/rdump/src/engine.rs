@@ -1,17 +1,19 @@
 use crate::backend::{build_directory_hotspots, DiscoveryRequest, SearchBackend};
 use crate::evaluator::{Evaluator, FileContext, MatchResult};
+use crate::limits::SecretDetector;
 use crate::parser::{self, AstNode, PredicateKey};
 use crate::planner::resolve_effective_query;
 use crate::predicates::code_aware::CodeAwareSettings;
 use crate::predicates::{self, PredicateEvaluator};
+use crate::session::TreeCache;
 use crate::{
-    FileSnapshot, PathResolution, RawSearchItem, SearchCancellationToken, SearchDiagnostic,
-    SearchOptions, SearchStats,
+    FileSnapshot, PathResolution, Progress, RawSearchItem, SearchCancellationToken,
+    SearchDiagnostic, SearchOptions, SearchStats, PROGRESS_INTERVAL,
 };
 use anyhow::{anyhow, Result};
 use std::collections::HashMap;
 use std::fmt;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
@@ -46,6 +48,9 @@ pub(crate) struct SearchRawIterator {
     cancellation: Option<SearchCancellationToken>,
     cancelled: bool,
     remaining_candidate_bytes: usize,
+    secret_detector: SecretDetector,
+    tree_cache: Option<Arc<TreeCache>>,
+    progress_finished: bool,
 }
 
 impl fmt::Debug for SearchRawIterator {
@@ -66,8 +71,17 @@ impl SearchRawIterator {
         options: &SearchOptions,
         cancellation: Option<SearchCancellationToken>,
     ) -> Result<Self> {
+        let mut options = options.clone();
+        options.root = crate::expand_tilde(&options.root);
+        let options = &options;
+        if (options.offset > 0 || options.limit.is_some()) && !options.deterministic {
+            return Err(anyhow!(
+                "offset and limit need a stable result order; set deterministic: true to paginate"
+            ));
+        }
         let canonical_root = backend.normalize_root(&options.root)?;
         let query_to_parse = resolve_effective_query(query, options)?;
+        let secret_detector = SecretDetector::from_patterns(options.secret_patterns.as_deref())?;
         let ast = crate::planner::optimize_ast(parser::parse_query(&query_to_parse)?);
         validate_ast_predicates(&ast, &predicates::create_predicate_registry())?;
 
@@ -76,12 +90,17 @@ impl SearchRawIterator {
             root: canonical_root.clone(),
             display_root: options.root.clone(),
             no_ignore: options.no_ignore,
+            respect_global_gitignore: options.respect_global_gitignore,
+            ignore_parents: options.ignore_parents,
             hidden: options.hidden,
             max_depth: options.max_depth,
+            min_depth: options.min_depth,
             ignore_debug: options.ignore_debug,
+            same_file_system: options.same_file_system,
+            threads: options.threads,
         })?;
 
-        let candidates: Vec<CandidateEntry> = discovery
+        let mut candidates: Vec<CandidateEntry> = discovery
             .candidates
             .into_iter()
             .map(|identity| {
@@ -99,6 +118,18 @@ impl SearchRawIterator {
                 }
             })
             .collect();
+        if options.deterministic {
+            candidates.sort_by(|left, right| {
+                left.identity
+                    .display_path
+                    .cmp(&right.identity.display_path)
+                    .then_with(|| {
+                        left.identity
+                            .resolved_path
+                            .cmp(&right.identity.resolved_path)
+                    })
+            });
+        }
 
         let mut stats = SearchStats {
             candidate_files: candidates.len(),
@@ -133,6 +164,7 @@ impl SearchRawIterator {
         code_settings.semantic_budget_ms = options.semantic_budget_ms;
         code_settings.max_semantic_matches_per_file = options.max_semantic_matches_per_file;
         code_settings.language_override = options.language_override.clone();
+        code_settings.ext_map = options.ext_map.clone();
         code_settings.semantic_match_mode = options.semantic_match_mode;
         code_settings.semantic_strict = options.semantic_strict;
         code_settings.language_debug = options.language_debug;
@@ -168,9 +200,17 @@ impl SearchRawIterator {
             cancellation,
             cancelled: false,
             remaining_candidate_bytes,
+            secret_detector,
+            tree_cache: None,
+            progress_finished: false,
         })
     }
 
+    pub(crate) fn with_tree_cache(mut self, tree_cache: Option<Arc<TreeCache>>) -> Self {
+        self.tree_cache = tree_cache;
+        self
+    }
+
     pub(crate) fn stats(&self) -> &SearchStats {
         &self.stats
     }
@@ -180,7 +220,34 @@ impl SearchRawIterator {
     }
 
     pub(crate) fn remaining_hint(&self) -> usize {
-        self.candidates.len().saturating_sub(self.next_candidate)
+        let remaining = self.candidates.len().saturating_sub(self.next_candidate);
+        match self.matched_files_cap() {
+            Some(max) => remaining.min(max.saturating_sub(self.stats.matched_files)),
+            None => remaining,
+        }
+    }
+
+    /// Matching files to find before stopping: `max_results` or the end of the requested page,
+    /// whichever comes first. Files skipped by `offset` count toward both.
+    fn matched_files_cap(&self) -> Option<usize> {
+        let page_end = self
+            .options
+            .limit
+            .map(|limit| self.options.offset.saturating_add(limit));
+        match (self.options.max_results, page_end) {
+            (Some(max), Some(end)) => Some(max.min(end)),
+            (max, end) => max.or(end),
+        }
+    }
+
+    fn reached_max_results(&self) -> bool {
+        self.matched_files_cap()
+            .is_some_and(|max| self.stats.matched_files >= max)
+    }
+
+    /// True while the most recent match still falls before the requested page.
+    fn before_offset(&self) -> bool {
+        self.stats.matched_files <= self.options.offset
     }
 
     pub(crate) fn was_cancelled(&self) -> bool {
@@ -217,6 +284,9 @@ impl SearchRawIterator {
         self.stats.query_cache_misses =
             cache_misses_after.saturating_sub(self.query_cache_misses_before);
         self.stats.diagnostics = self.diagnostics.len();
+        if self.options.deterministic {
+            self.stats.clear_timings();
+        }
     }
 
     fn next_candidate(&mut self) -> Option<CandidateEntry> {
@@ -228,6 +298,31 @@ impl SearchRawIterator {
         Some(candidate)
     }
 
+    fn report_progress(&self, current_path: &Path) {
+        if let Some(progress) = &self.options.progress {
+            progress.report(Progress {
+                files_scanned: self.next_candidate,
+                files_matched: self.stats.matched_files,
+                current_path: current_path.to_path_buf(),
+            });
+        }
+    }
+
+    /// Sends the final progress report, once, when the iterator runs out or stops early.
+    fn finish_progress(&mut self) {
+        if self.progress_finished || self.options.progress.is_none() {
+            return;
+        }
+        self.progress_finished = true;
+        let current_path = self
+            .next_candidate
+            .checked_sub(1)
+            .and_then(|index| self.candidates.get(index))
+            .map(|candidate| candidate.identity.display_path.clone())
+            .unwrap_or_else(|| self.options.root.clone());
+        self.report_progress(&current_path);
+    }
+
     fn should_stop(&self) -> bool {
         self.cancellation
             .as_ref()
@@ -243,6 +338,12 @@ impl Iterator for SearchRawIterator {
             if self.should_stop() {
                 self.cancelled = true;
                 self.refresh_runtime_stats();
+                self.finish_progress();
+                return None;
+            }
+            if self.reached_max_results() {
+                self.refresh_runtime_stats();
+                self.finish_progress();
                 return None;
             }
 
@@ -250,9 +351,13 @@ impl Iterator for SearchRawIterator {
                 Some(candidate) => candidate,
                 None => {
                     self.refresh_runtime_stats();
+                    self.finish_progress();
                     return None;
                 }
             };
+            if self.next_candidate % PROGRESS_INTERVAL == 0 {
+                self.report_progress(&candidate.identity.display_path);
+            }
 
             if let Some(err) = budget_error(self.started, self.time_budget) {
                 self.refresh_runtime_stats();
@@ -265,6 +370,14 @@ impl Iterator for SearchRawIterator {
                 Arc::clone(&self.backend),
             );
             context.display_path = candidate.identity.display_path.clone();
+            context.set_secret_detector(self.secret_detector.clone());
+            context.set_line_range(self.options.line_range);
+            context.set_encoding(self.options.encoding);
+            context.set_regex_flags(self.options.regex_flags);
+            context.set_ignore_case_paths(self.options.ignore_case_paths);
+            context.set_track_hunk_sources(self.options.track_predicates);
+            context.set_whole_word(self.options.whole_word);
+            context.set_tree_cache(self.tree_cache.clone());
 
             let prefilter_started = Instant::now();
             let prefilter = self.metadata_evaluator.evaluate(&mut context);
@@ -292,7 +405,12 @@ impl Iterator for SearchRawIterator {
             self.stats.prefiltered_files += 1;
 
             let evaluate_started = Instant::now();
-            let evaluation = self.full_evaluator.evaluate(&mut context);
+            let mut evaluation = self.full_evaluator.evaluate(&mut context);
+            if self.options.files_without_match {
+                // The metadata prefilter defines the scope; report whole files that fall
+                // inside it but fail the full query.
+                evaluation = evaluation.map(|result| MatchResult::Boolean(!result.is_match()));
+            }
             self.stats.evaluate_millis += evaluate_started.elapsed().as_millis() as u64;
             self.stats.evaluated_files += 1;
 
@@ -328,6 +446,11 @@ impl Iterator for SearchRawIterator {
                     }
                     self.stats.matched_files += 1;
                     self.stats.whole_file_results += 1;
+                    if self.before_offset() {
+                        self.diagnostics.extend(path_diagnostics);
+                        self.refresh_runtime_stats();
+                        continue;
+                    }
                     let diagnostics = attach_resolution_diagnostics(
                         candidate.identity.display_path.clone(),
                         candidate.identity.resolution,
@@ -341,9 +464,15 @@ impl Iterator for SearchRawIterator {
                         root_relative_path: candidate.identity.root_relative_path,
                         resolution: candidate.identity.resolution,
                         ranges: Vec::new(),
+                        hunk_predicates: Vec::new(),
+                        hunk_scopes: Vec::new(),
                         diagnostics,
                         semantic_skip_reasons,
                         snapshot,
+                        secret_detector: self.secret_detector.clone(),
+                        encoding: self.options.encoding,
+                        context_lines: self.options.context_lines,
+                        load_content: self.options.load_content,
                     }));
                 }
                 Ok(MatchResult::Hunks(hunks)) => {
@@ -366,6 +495,11 @@ impl Iterator for SearchRawIterator {
                     self.stats.matched_files += 1;
                     self.stats.ranged_results += 1;
                     self.stats.matched_ranges += hunks.len();
+                    if self.before_offset() {
+                        self.diagnostics.extend(path_diagnostics);
+                        self.refresh_runtime_stats();
+                        continue;
+                    }
                     let diagnostics = attach_resolution_diagnostics(
                         candidate.identity.display_path.clone(),
                         candidate.identity.resolution,
@@ -378,10 +512,35 @@ impl Iterator for SearchRawIterator {
                         resolved_path: candidate.identity.resolved_path,
                         root_relative_path: candidate.identity.root_relative_path,
                         resolution: candidate.identity.resolution,
+                        hunk_predicates: if self.options.track_predicates {
+                            hunks
+                                .iter()
+                                .map(|range| {
+                                    context
+                                        .hunk_source(range)
+                                        .map(|key| key.as_ref().to_string())
+                                })
+                                .collect()
+                        } else {
+                            Vec::new()
+                        },
+                        hunk_scopes: if self.options.show_enclosing {
+                            crate::predicates::code_aware::enclosing_scope_labels(
+                                &mut context,
+                                &self.options.ext_map,
+                                &hunks,
+                            )
+                        } else {
+                            Vec::new()
+                        },
                         ranges: hunks,
                         diagnostics,
                         semantic_skip_reasons,
                         snapshot,
+                        secret_detector: self.secret_detector.clone(),
+                        encoding: self.options.encoding,
+                        context_lines: self.options.context_lines,
+                        load_content: self.options.load_content,
                     }));
                 }
                 Err(err) => {
@@ -494,6 +653,28 @@ pub(crate) fn validate_ast_predicates(
         AstNode::Not(child) => {
             validate_ast_predicates(child, registry)?;
         }
+        AstNode::Near(left, right, _) => {
+            validate_ast_predicates(left, registry)?;
+            validate_ast_predicates(right, registry)?;
+            for operand in [left, right] {
+                if !can_produce_hunks(operand) {
+                    return Err(anyhow!(
+                        "near() needs operands that match specific lines, such as `contains:`, `matches:`, or code-aware predicates like `func:`; `{}` only matches whole files",
+                        operand.to_canonical_string()
+                    ));
+                }
+            }
+        }
     }
     Ok(())
 }
+
+/// Whether `node` can yield line-level hunks rather than only whole-file matches.
+fn can_produce_hunks(node: &AstNode) -> bool {
+    match node {
+        AstNode::Predicate(key, _) => !predicates::metadata_predicate_keys().contains(key),
+        AstNode::LogicalOp(_, left, right) => can_produce_hunks(left) || can_produce_hunks(right),
+        AstNode::Not(_) => false,
+        AstNode::Near(_, _, _) => true,
+    }
+}
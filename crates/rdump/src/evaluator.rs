@@ -7,11 +7,16 @@ use tree_sitter::{Parser, Range, Tree};
 
 use crate::backend::{BackendMetadata, RealFsSearchBackend, SearchBackend};
 use crate::content::{
-    load_search_content_with_backend, ContentState, LoadedContent, SearchDiagnostic,
+    load_search_content_with_encoding, ContentState, LoadedContent, SearchDiagnostic,
 };
+use crate::limits::SecretDetector;
 use crate::parser::{AstNode, LogicalOperator, PredicateKey};
-use crate::predicates::PredicateEvaluator;
+use crate::predicates::code_aware::enclosing_definition_range;
+use crate::predicates::{semantic_predicate_keys, PredicateEvaluator};
+use crate::session::TreeCache;
 use crate::SemanticSkipReason;
+use rdump_contracts::{Encoding, RegexFlags};
+use regex::RegexBuilder;
 
 /// The result of an evaluation for a single file.
 #[derive(Debug, Clone)]
@@ -31,14 +36,26 @@ pub struct FileContext {
     backend: Arc<dyn SearchBackend>,
     metadata: Option<BackendMetadata>,
     content: Option<LoadedContent>,
+    secret_detector: SecretDetector,
+    line_range: Option<(usize, usize)>,
+    encoding: Encoding,
+    regex_flags: RegexFlags,
+    ignore_case_paths: bool,
+    whole_word: bool,
     // Cache for the parsed tree-sitter AST
     tree: Option<Tree>,
+    /// Cross-search tree cache, consulted before parsing.
+    tree_cache: Option<Arc<TreeCache>>,
     /// Language key for the cached tree (used to reparse when dialect changes).
     tree_language_key: Option<String>,
     /// Cached SQL dialect/profile key chosen for this file.
     sql_profile_key: Option<String>,
     diagnostics: Vec<SearchDiagnostic>,
     semantic_skip_reasons: Vec<SemanticSkipReason>,
+    /// Leaf predicate that first returned each hunk, keyed by byte range, so a combined result
+    /// can be traced back. Only filled while `track_hunk_sources` is set.
+    hunk_sources: HashMap<std::ops::Range<usize>, PredicateKey>,
+    track_hunk_sources: bool,
 }
 
 impl FileContext {
@@ -63,14 +80,103 @@ impl FileContext {
             backend,
             metadata: None,
             content: None,
+            secret_detector: SecretDetector::Builtin,
+            line_range: None,
+            encoding: Encoding::Auto,
+            regex_flags: RegexFlags::default(),
+            ignore_case_paths: false,
+            whole_word: false,
             tree: None,
+            tree_cache: None,
             tree_language_key: None,
             sql_profile_key: None,
             diagnostics: Vec::new(),
             semantic_skip_reasons: Vec::new(),
+            hunk_sources: HashMap::new(),
+            track_hunk_sources: false,
         }
     }
 
+    /// Replaces the secret-detection rules used when content is first loaded.
+    pub fn set_secret_detector(&mut self, secret_detector: SecretDetector) {
+        self.secret_detector = secret_detector;
+    }
+
+    /// Sets the encoding used to transcode file bytes when content is first loaded.
+    pub fn set_encoding(&mut self, encoding: Encoding) {
+        self.encoding = encoding;
+    }
+
+    pub fn set_regex_flags(&mut self, regex_flags: RegexFlags) {
+        self.regex_flags = regex_flags;
+    }
+
+    pub fn regex_flags(&self) -> RegexFlags {
+        self.regex_flags
+    }
+
+    /// Makes path predicates match case-insensitively.
+    pub fn set_ignore_case_paths(&mut self, ignore_case_paths: bool) {
+        self.ignore_case_paths = ignore_case_paths;
+    }
+
+    pub fn ignore_case_paths(&self) -> bool {
+        self.ignore_case_paths
+    }
+
+    /// Makes `contains:` and `matches:` require word boundaries around the term.
+    pub fn set_whole_word(&mut self, whole_word: bool) {
+        self.whole_word = whole_word;
+    }
+
+    pub fn whole_word(&self) -> bool {
+        self.whole_word
+    }
+
+    /// Returns a builder for `pattern` with the search's regex flags and size cap applied.
+    /// Regex-based predicates compile through this so the flags apply to all of them.
+    pub fn regex_builder(&self, pattern: &str) -> RegexBuilder {
+        let mut builder = RegexBuilder::new(pattern);
+        builder
+            .size_limit(10 * 1024 * 1024) // Cap regex state to avoid pathological patterns
+            .dot_matches_new_line(self.regex_flags.dotall)
+            .multi_line(self.regex_flags.multiline)
+            .case_insensitive(self.regex_flags.case_insensitive);
+        builder
+    }
+
+    /// Records which predicate returned each hunk, for [`FileContext::hunk_source`].
+    pub fn set_track_hunk_sources(&mut self, track_hunk_sources: bool) {
+        self.track_hunk_sources = track_hunk_sources;
+    }
+
+    /// Shares parsed trees with other searches through `tree_cache`.
+    pub fn set_tree_cache(&mut self, tree_cache: Option<Arc<TreeCache>>) {
+        self.tree_cache = tree_cache;
+    }
+
+    /// Restricts content and semantic matching to a 1-based, inclusive line window.
+    pub fn set_line_range(&mut self, line_range: Option<(usize, usize)>) {
+        self.line_range = line_range;
+    }
+
+    /// Returns how many leading lines to skip and how many to scan for the line window.
+    pub fn line_window(&self) -> (usize, usize) {
+        match self.line_range {
+            Some((start, end)) => {
+                let skip = start.saturating_sub(1);
+                (skip, end.saturating_sub(skip))
+            }
+            None => (0, usize::MAX),
+        }
+    }
+
+    /// Returns true when the zero-based `row` falls inside the line window.
+    pub fn row_in_line_window(&self, row: usize) -> bool {
+        let (skip, take) = self.line_window();
+        row >= skip && row - skip < take
+    }
+
     pub fn backend(&self) -> &Arc<dyn SearchBackend> {
         &self.backend
     }
@@ -100,10 +206,12 @@ impl FileContext {
 
     pub fn get_content(&mut self) -> Result<&str> {
         if self.content.is_none() {
-            self.content = Some(load_search_content_with_backend(
+            self.content = Some(load_search_content_with_encoding(
                 self.backend.as_ref(),
                 &self.path,
                 &self.display_path,
+                &self.secret_detector,
+                self.encoding,
             )?);
         }
         Ok(self.content.as_ref().unwrap().content.as_ref())
@@ -111,10 +219,12 @@ impl FileContext {
 
     pub fn get_content_arc(&mut self) -> Result<Arc<str>> {
         if self.content.is_none() {
-            self.content = Some(load_search_content_with_backend(
+            self.content = Some(load_search_content_with_encoding(
                 self.backend.as_ref(),
                 &self.path,
                 &self.display_path,
+                &self.secret_detector,
+                self.encoding,
             )?);
         }
         Ok(self.content.as_ref().unwrap().content.clone())
@@ -122,10 +232,12 @@ impl FileContext {
 
     pub fn content_state(&mut self) -> Result<ContentState> {
         if self.content.is_none() {
-            self.content = Some(load_search_content_with_backend(
+            self.content = Some(load_search_content_with_encoding(
                 self.backend.as_ref(),
                 &self.path,
                 &self.display_path,
+                &self.secret_detector,
+                self.encoding,
             )?);
         }
         Ok(self.content.as_ref().unwrap().state.clone())
@@ -133,10 +245,12 @@ impl FileContext {
 
     pub fn content_diagnostics(&mut self) -> Result<Vec<SearchDiagnostic>> {
         if self.content.is_none() {
-            self.content = Some(load_search_content_with_backend(
+            self.content = Some(load_search_content_with_encoding(
                 self.backend.as_ref(),
                 &self.path,
                 &self.display_path,
+                &self.secret_detector,
+                self.encoding,
             )?);
         }
         Ok(self.content.as_ref().unwrap().diagnostics.clone())
@@ -147,23 +261,82 @@ impl FileContext {
         &mut self,
         language_key: &str,
         language: tree_sitter::Language,
+    ) -> Result<&Tree> {
+        self.get_tree_in_ranges(language_key, language, &[])
+    }
+
+    /// Like [`get_tree`](Self::get_tree), but only parses `included_ranges` of the content
+    /// (the whole file when empty), so embedded code keeps its positions in the file.
+    pub fn get_tree_in_ranges(
+        &mut self,
+        language_key: &str,
+        language: tree_sitter::Language,
+        included_ranges: &[tree_sitter::Range],
     ) -> Result<&Tree> {
         if self.tree.is_none() || self.tree_language_key.as_deref() != Some(language_key) {
-            let path_display = self.path.display().to_string();
-            let content = self.get_content()?;
-            let mut parser = Parser::new();
-            parser.set_language(&language).with_context(|| {
-                format!("Failed to set language for tree-sitter parser on {path_display}")
-            })?;
-            let tree = parser
-                .parse(content, None)
-                .ok_or_else(|| anyhow!("Tree-sitter failed to parse {path_display}"))?;
+            let cache_stamp = if self.tree_cache.is_some() {
+                self.metadata().ok().and_then(|metadata| {
+                    Some((metadata.modified_unix_millis?, metadata.size_bytes))
+                })
+            } else {
+                None
+            };
+            let cached = match (&self.tree_cache, cache_stamp) {
+                (Some(cache), Some((modified, size))) => {
+                    cache.get(&self.path, language_key, self.encoding, modified, size)
+                }
+                _ => None,
+            };
+            let tree = match cached {
+                Some(tree) => tree,
+                None => {
+                    let path_display = self.path.display().to_string();
+                    let content = self.get_content()?;
+                    let mut parser = Parser::new();
+                    parser.set_language(&language).with_context(|| {
+                        format!("Failed to set language for tree-sitter parser on {path_display}")
+                    })?;
+                    parser
+                        .set_included_ranges(included_ranges)
+                        .with_context(|| {
+                            format!("Invalid embedded code ranges in {path_display}")
+                        })?;
+                    let tree = parser
+                        .parse(content, None)
+                        .ok_or_else(|| anyhow!("Tree-sitter failed to parse {path_display}"))?;
+                    if let (Some(cache), Some((modified, size))) = (&self.tree_cache, cache_stamp) {
+                        cache.insert(
+                            &self.path,
+                            language_key,
+                            self.encoding,
+                            modified,
+                            size,
+                            tree.clone(),
+                        );
+                    }
+                    tree
+                }
+            };
             self.tree = Some(tree);
             self.tree_language_key = Some(language_key.to_string());
         }
         Ok(self.tree.as_ref().unwrap())
     }
 
+    /// Widens hunks to the definitions that enclose them, using the cached syntax tree.
+    /// Results are returned unchanged when no tree has been parsed for this file.
+    pub fn widen_to_definitions(&self, result: MatchResult) -> MatchResult {
+        match (result, &self.tree) {
+            (MatchResult::Hunks(ranges), Some(tree)) => MatchResult::Hunks(
+                ranges
+                    .into_iter()
+                    .map(|range| enclosing_definition_range(tree, range))
+                    .collect(),
+            ),
+            (result, _) => result,
+        }
+    }
+
     pub fn has_tree_for(&self, language_key: &str) -> bool {
         self.tree.is_some() && self.tree_language_key.as_deref() == Some(language_key)
     }
@@ -197,6 +370,13 @@ impl FileContext {
     pub fn take_semantic_skip_reasons(&mut self) -> Vec<SemanticSkipReason> {
         std::mem::take(&mut self.semantic_skip_reasons)
     }
+
+    /// Returns the predicate whose evaluation produced `range`. Combining results keeps hunks
+    /// intact, so every hunk of a final result was returned by some leaf predicate.
+    /// Always `None` unless hunk sources are tracked.
+    pub fn hunk_source(&self, range: &Range) -> Option<&PredicateKey> {
+        self.hunk_sources.get(&(range.start_byte..range.end_byte))
+    }
 }
 
 /// The main evaluator struct. It holds the AST and the predicate registry.
@@ -222,6 +402,21 @@ impl Evaluator {
     fn evaluate_node(&self, node: &AstNode, context: &mut FileContext) -> Result<MatchResult> {
         match node {
             AstNode::Predicate(key, value) => self.evaluate_predicate(key, value, context),
+            AstNode::LogicalOp(LogicalOperator::Encloses, outer, inner) => {
+                let mut outer_res = self.evaluate_node(outer, context)?;
+                if !outer_res.is_match() {
+                    return Ok(MatchResult::Boolean(false));
+                }
+                // Semantic captures usually cover only a definition's name; widen them to the
+                // whole definition so `func:main > call:unwrap` looks inside the body.
+                if let AstNode::Predicate(key, _) = &**outer {
+                    if semantic_predicate_keys().contains(key) {
+                        outer_res = context.widen_to_definitions(outer_res);
+                    }
+                }
+                let inner_res = self.evaluate_node(inner, context)?;
+                Ok(outer_res.combine_with(inner_res, &LogicalOperator::Encloses))
+            }
             AstNode::LogicalOp(op, left, right) => {
                 let left_res = self.evaluate_node(left, context)?;
 
@@ -252,6 +447,14 @@ impl Evaluator {
                 let result = self.evaluate_node(inner_node, context)?;
                 Ok(MatchResult::Boolean(!result.is_match()))
             }
+            AstNode::Near(left, right, distance) => {
+                let left_res = self.evaluate_node(left, context)?;
+                if !left_res.is_match() {
+                    return Ok(MatchResult::Boolean(false));
+                }
+                let right_res = self.evaluate_node(right, context)?;
+                Ok(left_res.combine_near(right_res, *distance))
+            }
         }
     }
 
@@ -263,7 +466,18 @@ impl Evaluator {
         context: &mut FileContext,
     ) -> Result<MatchResult> {
         if let Some(evaluator) = self.registry.get(key) {
-            evaluator.evaluate(context, key, value)
+            let result = evaluator.evaluate(context, key, value)?;
+            if let MatchResult::Hunks(ranges) = &result {
+                if context.track_hunk_sources {
+                    for range in ranges {
+                        context
+                            .hunk_sources
+                            .entry(range.start_byte..range.end_byte)
+                            .or_insert_with(|| key.clone());
+                    }
+                }
+            }
+            Ok(result)
         } else {
             // If a predicate is not in the current registry (e.g., a content predicate
             // during the metadata-only pass), it's considered a "pass" for this stage.
@@ -286,6 +500,64 @@ impl MatchResult {
         match op {
             LogicalOperator::And => self.combine_and(other),
             LogicalOperator::Or => self.combine_or(other),
+            LogicalOperator::Encloses => self.combine_encloses(other),
+        }
+    }
+
+    /// Keeps the hunks on either side that start within `distance` lines of a hunk on the
+    /// other side. A whole-file match is near everything, so it keeps the other side as is.
+    pub fn combine_near(self, other: MatchResult, distance: usize) -> Self {
+        if !self.is_match() || !other.is_match() {
+            return MatchResult::Boolean(false);
+        }
+        match (self, other) {
+            (MatchResult::Hunks(left), MatchResult::Hunks(right)) => {
+                let is_near = |range: &Range, others: &[Range]| {
+                    others.iter().any(|other| {
+                        range.start_point.row.abs_diff(other.start_point.row) <= distance
+                    })
+                };
+                let mut kept: Vec<Range> = left
+                    .iter()
+                    .filter(|range| is_near(range, &right))
+                    .chain(right.iter().filter(|range| is_near(range, &left)))
+                    .cloned()
+                    .collect();
+                kept.sort_by_key(|range| (range.start_byte, range.end_byte));
+                kept.dedup();
+                MatchResult::Hunks(kept)
+            }
+            (MatchResult::Boolean(true), other) => other,
+            (this, MatchResult::Boolean(true)) => this,
+            // Should be unreachable due to the initial `is_match` check.
+            _ => MatchResult::Boolean(false),
+        }
+    }
+
+    // Helper for containment: `self` is the outer result, `other` the inner one.
+    fn combine_encloses(self, other: MatchResult) -> Self {
+        if !self.is_match() || !other.is_match() {
+            return MatchResult::Boolean(false);
+        }
+        match (self, other) {
+            // Keep only the inner hunks that lie within some outer hunk.
+            (MatchResult::Hunks(outer), MatchResult::Hunks(inner)) => MatchResult::Hunks(
+                inner
+                    .into_iter()
+                    .filter(|range| {
+                        outer.iter().any(|enclosing| {
+                            enclosing.start_byte <= range.start_byte
+                                && range.end_byte <= enclosing.end_byte
+                        })
+                    })
+                    .collect(),
+            ),
+            // A full-file outer match encloses everything.
+            (MatchResult::Boolean(true), inner) => inner,
+            // A full-file inner match places no constraint on the outer hunks.
+            (outer, MatchResult::Boolean(true)) => outer,
+            // Should be unreachable due to the initial `is_match` check.
+            _ => MatchResult::Boolean(false),
         }
     }
 
@@ -622,6 +894,31 @@ mod tests {
         assert_eq!(tree1_sexp, tree2_sexp);
     }
 
+    #[test]
+    fn test_combine_encloses_keeps_contained_hunks() {
+        let range = |start_byte, end_byte| Range {
+            start_byte,
+            end_byte,
+            start_point: Point { row: 0, column: 0 },
+            end_point: Point { row: 0, column: 0 },
+        };
+        let outer = MatchResult::Hunks(vec![range(0, 20)]);
+        let inner = MatchResult::Hunks(vec![range(5, 10), range(15, 25), range(30, 35)]);
+        match outer.combine_with(inner, &LogicalOperator::Encloses) {
+            MatchResult::Hunks(hunks) => assert_eq!(hunks, vec![range(5, 10)]),
+            other => panic!("expected hunks, got {other:?}"),
+        }
+
+        let whole_file = MatchResult::Boolean(true);
+        let inner = MatchResult::Hunks(vec![range(30, 35)]);
+        assert!(whole_file
+            .combine_with(inner, &LogicalOperator::Encloses)
+            .is_match());
+        assert!(!MatchResult::Hunks(vec![range(0, 20)])
+            .combine_with(MatchResult::Hunks(vec![]), &LogicalOperator::Encloses)
+            .is_match());
+    }
+
     #[test]
     fn test_file_context_binary_file() {
         let dir = tempdir().unwrap();
@@ -1,14 +1,21 @@
+#[path = "formatter/dump.rs"]
+mod dump;
 #[path = "formatter/raw.rs"]
 mod raw;
+#[path = "formatter/replace.rs"]
+mod replace;
 #[path = "formatter/report.rs"]
 mod report;
 #[path = "formatter/shared.rs"]
 mod shared;
 
+pub use dump::print_dump_bundle;
 pub use raw::{print_output, print_output_with_backend};
+pub use replace::{print_replace_preview, replace_target_pattern};
+pub(crate) use report::group_results;
 pub use report::{
     print_contract_path_items, print_path_output, print_path_output_with_backend,
-    print_report_output,
+    print_report_body, print_report_output, ReportRenderOptions,
 };
 pub(crate) use shared::format_mode;
 
@@ -27,6 +34,7 @@ mod tests {
         ContentState, Match, SearchReport, SearchResult, SearchResultMetadata, SearchStats,
     };
     use anyhow::{anyhow, Result};
+    use rdump_contracts::RegexFlags;
     use std::collections::BTreeMap;
     use std::io::Write;
     use std::ops::Range;
@@ -171,6 +179,7 @@ mod tests {
             false,
             0,
             crate::TimeFormat::Local,
+            crate::Messages::default(),
         )
         .unwrap();
         let output = String::from_utf8(writer).unwrap();
@@ -195,6 +204,7 @@ mod tests {
             false,
             0,
             crate::TimeFormat::Local,
+            crate::Messages::default(),
         )
         .unwrap();
         let output = String::from_utf8(writer).unwrap();
@@ -216,6 +226,7 @@ mod tests {
             false,
             0,
             crate::TimeFormat::Local,
+            crate::Messages::default(),
         )
         .unwrap();
 
@@ -242,6 +253,7 @@ mod tests {
             true,
             0,
             crate::TimeFormat::Local,
+            crate::Messages::default(),
         )
         .unwrap();
         let output = String::from_utf8(writer).unwrap();
@@ -267,6 +279,7 @@ mod tests {
             true,
             0,
             crate::TimeFormat::Local,
+            crate::Messages::default(),
         )
         .unwrap();
 
@@ -310,6 +323,7 @@ mod tests {
             false,
             0,
             crate::TimeFormat::Local,
+            crate::Messages::default(),
         )
         .unwrap();
         let output = String::from_utf8(writer).unwrap();
@@ -346,6 +360,7 @@ mod tests {
             false,
             0,
             crate::TimeFormat::Local,
+            crate::Messages::default(),
         )
         .unwrap();
         let output = String::from_utf8(writer).unwrap();
@@ -372,6 +387,7 @@ mod tests {
             true,
             0,
             crate::TimeFormat::Local,
+            crate::Messages::default(),
         )
         .unwrap();
         let output = String::from_utf8(writer).unwrap();
@@ -384,7 +400,7 @@ mod tests {
     fn test_get_contextual_line_ranges_empty_hunks() {
         let lines: Vec<&str> = vec!["line 1", "line 2", "line 3"];
         let hunks: Vec<tree_sitter::Range> = vec![];
-        let result = get_contextual_line_ranges(&hunks, &lines, 1);
+        let result = get_contextual_line_ranges(&hunks, &lines, crate::ContextLines::symmetric(1));
         assert!(result.is_empty());
     }
 
@@ -397,7 +413,7 @@ mod tests {
             start_point: tree_sitter::Point { row: 0, column: 0 },
             end_point: tree_sitter::Point { row: 0, column: 10 },
         }];
-        let result = get_contextual_line_ranges(&hunks, &lines, 1);
+        let result = get_contextual_line_ranges(&hunks, &lines, crate::ContextLines::symmetric(1));
         assert!(result.is_empty());
     }
 
@@ -409,12 +425,10 @@ mod tests {
             &mut writer,
             &report,
             &crate::Format::Summary,
-            false,
-            false,
-            false,
-            0,
-            true,
-            crate::TimeFormat::Local,
+            &ReportRenderOptions {
+                show_suppressed_placeholders: true,
+                ..Default::default()
+            },
         )
         .unwrap();
         let output = String::from_utf8(writer).unwrap();
@@ -434,6 +448,10 @@ mod tests {
                 end_column: 7,
                 byte_range: Range { start: 3, end: 7 },
                 text: "main".to_string(),
+                context_before: Vec::new(),
+                context_after: Vec::new(),
+                predicate: None,
+                enclosing: None,
             }],
         );
         let mut writer = Vec::new();
@@ -441,12 +459,10 @@ mod tests {
             &mut writer,
             &report,
             &crate::Format::Matches,
-            false,
-            false,
-            false,
-            0,
-            true,
-            crate::TimeFormat::Local,
+            &ReportRenderOptions {
+                show_suppressed_placeholders: true,
+                ..Default::default()
+            },
         )
         .unwrap();
         let output = String::from_utf8(writer).unwrap();
@@ -454,6 +470,144 @@ mod tests {
         assert!(output.contains("1:4-1:8 main"));
     }
 
+    #[test]
+    fn test_lsp_json_report_output_uses_utf16_columns() {
+        // `😀` is 4 bytes but 2 UTF-16 code units; `你好` is 6 bytes but 2 code units.
+        let content = "fn main() {}\nlet s = \"😀你好\"; call();";
+        let start = content.find("call").unwrap();
+        let report = sample_report(
+            "src/main.rs",
+            content,
+            vec![Match {
+                start_line: 2,
+                end_line: 2,
+                start_column: start - 13,
+                end_column: start - 13 + 4,
+                byte_range: Range {
+                    start,
+                    end: start + 4,
+                },
+                text: "call".to_string(),
+                context_before: Vec::new(),
+                context_after: Vec::new(),
+                predicate: None,
+                enclosing: None,
+            }],
+        );
+        let mut writer = Vec::new();
+        print_report_output(
+            &mut writer,
+            &report,
+            &crate::Format::LspJson,
+            &ReportRenderOptions {
+                show_suppressed_placeholders: true,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let output: serde_json::Value = serde_json::from_slice(&writer).unwrap();
+        let matched = &output["results"][0]["matches"][0];
+        assert_eq!(matched["start_column"], 22);
+        assert_eq!(matched["byte_range"][0], start);
+        assert_eq!(matched["range"]["start"]["line"], 1);
+        assert_eq!(matched["range"]["start"]["character"], 16);
+        assert_eq!(matched["range"]["end"]["character"], 20);
+    }
+
+    fn replace_match(content: &str, range: Range<usize>) -> Match {
+        Match {
+            start_line: content[..range.start].matches('\n').count() + 1,
+            end_line: content[..range.end].matches('\n').count() + 1,
+            start_column: 0,
+            end_column: 0,
+            text: content[range.clone()].to_string(),
+            byte_range: range,
+            context_before: Vec::new(),
+            context_after: Vec::new(),
+            predicate: None,
+            enclosing: None,
+        }
+    }
+
+    #[test]
+    fn test_replace_preview_applies_overlapping_matches_left_to_right() {
+        let content = "foofoo bar\nkeep\nfoo\n";
+        // `ofo` (2..5) overlaps the first `foo` and is skipped regardless of input order.
+        let report = sample_report(
+            "src/main.rs",
+            content,
+            vec![
+                replace_match(content, 16..19),
+                replace_match(content, 2..5),
+                replace_match(content, 3..6),
+                replace_match(content, 0..3),
+            ],
+        );
+        let mut writer = Vec::new();
+        print_replace_preview(&mut writer, &report, "X", None).unwrap();
+        let output = String::from_utf8(writer).unwrap();
+        assert_eq!(
+            output,
+            "--- a/src/main.rs\n+++ b/src/main.rs\n@@ -1,1 +1,1 @@\n-foofoo bar\n+XX bar\n@@ -3,1 +3,1 @@\n-foo\n+X\n"
+        );
+        assert_eq!(report.results[0].content, content);
+    }
+
+    #[test]
+    fn test_replace_preview_prefers_longer_match_and_tracks_line_shift() {
+        let content = "foobar\nfoo\n";
+        let report = sample_report(
+            "lib.rs",
+            content,
+            vec![
+                replace_match(content, 0..3),
+                replace_match(content, 0..6),
+                replace_match(content, 7..10),
+            ],
+        );
+        let mut writer = Vec::new();
+        print_replace_preview(&mut writer, &report, "a\nb", None).unwrap();
+        let output = String::from_utf8(writer).unwrap();
+        assert!(output.contains("@@ -1,1 +1,2 @@\n-foobar\n+a\n+b\n"));
+        assert!(output.contains("@@ -2,1 +3,2 @@\n-foo\n+a\n+b\n"));
+    }
+
+    #[test]
+    fn test_replace_preview_targets_content_predicate_inside_line_matches() {
+        let content = "let foo = Foo::new();\nlet other = 1;\n";
+        let report = sample_report("src/main.rs", content, vec![replace_match(content, 0..21)]);
+        let ast = crate::parser::parse_query("contains:foo & !contains:bar").unwrap();
+        let target = replace_target_pattern(&ast, RegexFlags::default(), false).unwrap();
+        let mut writer = Vec::new();
+        print_replace_preview(&mut writer, &report, "bar", target.as_ref()).unwrap();
+        let output = String::from_utf8(writer).unwrap();
+        assert!(output.contains("-let foo = Foo::new();\n+let bar = bar::new();\n"));
+
+        let ast = crate::parser::parse_query("func:main").unwrap();
+        assert!(replace_target_pattern(&ast, RegexFlags::default(), false)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_replace_target_pattern_honors_regex_flags_and_whole_word() {
+        let ast = crate::parser::parse_query("matches:'foo' | contains:cat").unwrap();
+        let target = replace_target_pattern(&ast, RegexFlags::default(), false)
+            .unwrap()
+            .unwrap();
+        assert!(!target.is_match("FOO"));
+        assert!(target.is_match("concat"));
+
+        let flags = RegexFlags {
+            case_insensitive: true,
+            ..RegexFlags::default()
+        };
+        let target = replace_target_pattern(&ast, flags, true).unwrap().unwrap();
+        assert!(target.is_match("FOO"));
+        assert!(!target.is_match("concat"));
+        assert!(target.is_match("a cat"));
+    }
+
     #[test]
     fn test_snippets_report_output() {
         let report = sample_report(
@@ -466,6 +620,10 @@ mod tests {
                 end_column: 16,
                 byte_range: Range { start: 25, end: 29 },
                 text: "main".to_string(),
+                context_before: Vec::new(),
+                context_after: Vec::new(),
+                predicate: None,
+                enclosing: None,
             }],
         );
         let mut writer = Vec::new();
@@ -473,16 +631,161 @@ mod tests {
             &mut writer,
             &report,
             &crate::Format::Snippets,
-            true,
-            false,
-            false,
-            0,
-            true,
-            crate::TimeFormat::Local,
+            &ReportRenderOptions {
+                with_line_numbers: true,
+                show_suppressed_placeholders: true,
+                ..Default::default()
+            },
         )
         .unwrap();
         let output = String::from_utf8(writer).unwrap();
         assert!(output.contains("@@ 2-2 @@"));
         assert!(output.contains("2 | let value = main();"));
     }
+
+    #[test]
+    fn test_github_annotations_output() {
+        let report = sample_report(
+            "./src/main.rs",
+            "fn main() {}\nlet value = main(); // 100%, done",
+            vec![Match {
+                start_line: 2,
+                end_line: 2,
+                start_column: 4,
+                end_column: 33,
+                byte_range: Range { start: 17, end: 46 },
+                text: "value = main(); // 100%, done".to_string(),
+                context_before: Vec::new(),
+                context_after: Vec::new(),
+                predicate: None,
+                enclosing: None,
+            }],
+        );
+        let mut writer = Vec::new();
+        print_report_output(
+            &mut writer,
+            &report,
+            &crate::Format::Github,
+            &ReportRenderOptions {
+                show_suppressed_placeholders: true,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(writer).unwrap(),
+            "::warning file=src/main.rs,line=2,col=5::value = main(); // 100%25, done\n"
+        );
+
+        let report = sample_report("a,b.txt", "hello", vec![]);
+        let mut writer = Vec::new();
+        print_report_body(
+            &mut writer,
+            &report,
+            &crate::Format::Github,
+            &ReportRenderOptions {
+                show_suppressed_placeholders: true,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(writer).unwrap(),
+            "::warning file=a%2Cb.txt,line=1,col=1::whole-file match\n"
+        );
+    }
+
+    #[test]
+    fn test_hunks_heading_styles() {
+        let content = "alpha\nbeta\ngamma\ndelta\n";
+        let report = sample_report(
+            "src/lib.rs",
+            content,
+            vec![replace_match(content, 0..5), replace_match(content, 18..23)],
+        );
+        let render = |heading| {
+            let mut writer = Vec::new();
+            print_report_body(
+                &mut writer,
+                &report,
+                &crate::Format::Hunks,
+                &ReportRenderOptions {
+                    heading,
+                    show_suppressed_placeholders: true,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+            String::from_utf8(writer).unwrap()
+        };
+
+        assert_eq!(
+            render(crate::HeadingStyle::PerFile),
+            "File: src/lib.rs\n---\nalpha\n...\ndelta\n"
+        );
+        assert_eq!(
+            render(crate::HeadingStyle::Grouped),
+            "src/lib.rs\nalpha\n\ndelta\n"
+        );
+        assert_eq!(
+            render(crate::HeadingStyle::Inline),
+            "src/lib.rs:alpha\nsrc/lib.rs:delta\n"
+        );
+    }
+
+    #[test]
+    fn test_hunks_max_matches_per_file_notes_omitted_matches() {
+        let content = "alpha\nbeta\ngamma\ndelta\n";
+        let report = sample_report(
+            "src/lib.rs",
+            content,
+            vec![replace_match(content, 18..23), replace_match(content, 0..5)],
+        );
+        let mut writer = Vec::new();
+        print_report_body(
+            &mut writer,
+            &report,
+            &crate::Format::Hunks,
+            &ReportRenderOptions {
+                max_matches_per_file: Some(1),
+                show_suppressed_placeholders: true,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(writer).unwrap(),
+            "File: src/lib.rs\n---\nalpha\n(+1 more)\n"
+        );
+    }
+
+    #[test]
+    fn test_lsp_json_compact_keeps_the_pretty_field_set() {
+        let content = "fn main() {}\n";
+        let report = sample_report("src/main.rs", content, vec![replace_match(content, 3..7)]);
+        let render = |compact| {
+            let mut writer = Vec::new();
+            print_report_body(
+                &mut writer,
+                &report,
+                &crate::Format::LspJson,
+                &ReportRenderOptions {
+                    json_compact: compact,
+                    show_suppressed_placeholders: true,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+            String::from_utf8(writer).unwrap()
+        };
+
+        let compact = render(true);
+        let pretty = render(false);
+        assert_eq!(compact.lines().count(), 1, "{compact}");
+        assert!(pretty.lines().count() > 1);
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&compact).unwrap(),
+            serde_json::from_str::<serde_json::Value>(&pretty).unwrap()
+        );
+    }
 }
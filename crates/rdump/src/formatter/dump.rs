@@ -0,0 +1,143 @@
+use anyhow::Result;
+use std::io::Write;
+use std::ops::Range;
+use syntect::util::LinesWithEndings;
+
+use crate::formatter::shared::{
+    display_path_text, get_contextual_line_ranges_from_matches, print_plain_content,
+    root_relative_path,
+};
+use crate::{ContextLines, SearchResult};
+
+/// Prints results as one self-contained document for pasting into an LLM prompt: a manifest
+/// listing every matched file with its line count, then each file's hunks between
+/// `=== path ===` and `=== end path ===` delimiters. Paths are relative to the search root,
+/// and whole-file matches are dumped in full.
+///
+/// With `max_bytes`, a manifest entry or hunk that would push the document past the budget is
+/// left out along with everything after it, and a closing note says how many files made it in
+/// full. The note counts against the budget too; only the short header is always written.
+pub fn print_dump_bundle(
+    writer: &mut impl Write,
+    query: &str,
+    results: &[SearchResult],
+    context: ContextLines,
+    max_bytes: Option<usize>,
+) -> Result<()> {
+    let dumpable = results
+        .iter()
+        .filter(|result| result.content_available())
+        .count();
+    let mut bundle = render_dump_bundle(query, results, context, max_bytes)?;
+    if let (true, Some(max)) = (bundle.truncated, max_bytes) {
+        // Make room for the note; its longest form counts every file as complete.
+        let note_len = truncation_note(max, dumpable, dumpable).len();
+        bundle = render_dump_bundle(query, results, context, Some(max.saturating_sub(note_len)))?;
+    }
+
+    writer.write_all(&bundle.document)?;
+    if bundle.truncated {
+        let note = truncation_note(
+            max_bytes.unwrap_or_default(),
+            bundle.complete_files,
+            dumpable,
+        );
+        writer.write_all(note.as_bytes())?;
+    }
+    Ok(())
+}
+
+struct DumpBundle {
+    document: Vec<u8>,
+    complete_files: usize,
+    truncated: bool,
+}
+
+fn truncation_note(max_bytes: usize, complete_files: usize, dumpable: usize) -> String {
+    format!(
+        "\n[truncated: the {max_bytes}-byte budget was reached; {complete_files} of {dumpable} files included in full]\n"
+    )
+}
+
+fn render_dump_bundle(
+    query: &str,
+    results: &[SearchResult],
+    context: ContextLines,
+    max_bytes: Option<usize>,
+) -> Result<DumpBundle> {
+    let fits = |used: usize, extra: usize| max_bytes.is_none_or(|max| used + extra <= max);
+    let mut bundle = DumpBundle {
+        document: Vec::new(),
+        complete_files: 0,
+        truncated: false,
+    };
+    let document = &mut bundle.document;
+    writeln!(document, "# rdump dump")?;
+    writeln!(document, "# query: {query}")?;
+    writeln!(document, "# files: {}", results.len())?;
+    writeln!(document)?;
+    writeln!(document, "## Manifest")?;
+    for result in results {
+        let path = display_path_text(root_relative_path(result));
+        let entry = if !result.content_available() {
+            format!("- {path} (content not loaded)\n")
+        } else {
+            let lines = match result.content.lines().count() {
+                1 => "1 line".to_string(),
+                count => format!("{count} lines"),
+            };
+            match result.match_count() {
+                0 => format!("- {path} ({lines}, whole file)\n"),
+                1 => format!("- {path} ({lines}, 1 match)\n"),
+                count => format!("- {path} ({lines}, {count} matches)\n"),
+            }
+        };
+        if !fits(document.len(), entry.len()) {
+            bundle.truncated = true;
+            return Ok(bundle);
+        }
+        document.extend_from_slice(entry.as_bytes());
+    }
+
+    'files: for result in results.iter().filter(|result| result.content_available()) {
+        let path = display_path_text(root_relative_path(result));
+        let opening = format!("\n=== {path} ===\n");
+        let closing = format!("=== end {path} ===\n");
+        let lines: Vec<&str> = LinesWithEndings::from(&result.content).collect();
+        let ranges: Vec<Range<usize>> = if lines.is_empty() {
+            Vec::new()
+        } else if result.matches.is_empty() {
+            vec![0..lines.len()]
+        } else {
+            get_contextual_line_ranges_from_matches(&result.matches, &lines, context)
+        };
+
+        let mut opened = false;
+        for range in ranges {
+            let mut hunk = Vec::new();
+            if !opened {
+                hunk.extend_from_slice(opening.as_bytes());
+            }
+            writeln!(hunk, "--- lines {}-{} ---", range.start + 1, range.end)?;
+            print_plain_content(&mut hunk, &lines[range.clone()].join(""), true, range.start)?;
+            if !hunk.ends_with(b"\n") {
+                hunk.push(b'\n');
+            }
+
+            if !fits(document.len(), hunk.len() + closing.len()) {
+                bundle.truncated = true;
+                if opened {
+                    document.extend_from_slice(closing.as_bytes());
+                }
+                break 'files;
+            }
+            document.extend_from_slice(&hunk);
+            opened = true;
+        }
+        if opened {
+            document.extend_from_slice(closing.as_bytes());
+        }
+        bundle.complete_files += 1;
+    }
+    Ok(bundle)
+}
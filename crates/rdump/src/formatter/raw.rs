@@ -11,7 +11,7 @@ use crate::formatter::shared::{
     print_content_with_style, print_markdown_fenced_content, print_plain_content,
 };
 use crate::limits::{is_probably_binary, maybe_contains_secret, MAX_FILE_SIZE};
-use crate::{Format, TimeFormat};
+use crate::{Format, Messages, TimeFormat};
 
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
 struct FileOutput {
@@ -28,6 +28,7 @@ pub fn print_output(
     use_color: bool,
     context_lines: usize,
     time_format: TimeFormat,
+    messages: Messages,
 ) -> Result<()> {
     print_output_with_backend(
         &RealFsSearchBackend,
@@ -39,6 +40,7 @@ pub fn print_output(
         use_color,
         context_lines,
         time_format,
+        messages,
     )
 }
 
@@ -52,17 +54,19 @@ pub fn print_output_with_backend(
     use_color: bool,
     context_lines: usize,
     time_format: TimeFormat,
+    messages: Messages,
 ) -> Result<()> {
     match format {
         Format::Find => print_find_format(backend, writer, matching_files, time_format)?,
         Format::Paths => print_paths_format(writer, matching_files)?,
-        Format::Json => print_json_format(backend, writer, matching_files)?,
+        Format::Json => print_json_format(backend, writer, matching_files, messages)?,
         Format::Cat => print_cat_format(
             backend,
             writer,
             matching_files,
             with_line_numbers,
             use_color,
+            messages,
         )?,
         Format::Markdown => print_markdown_format(
             backend,
@@ -70,6 +74,7 @@ pub fn print_output_with_backend(
             matching_files,
             with_line_numbers,
             !no_headers,
+            messages,
         )?,
         Format::Hunks => print_hunks_format(
             backend,
@@ -79,23 +84,30 @@ pub fn print_output_with_backend(
             !no_headers,
             use_color,
             context_lines,
+            messages,
         )?,
         other => anyhow::bail!("raw formatter does not support format {:?}", other),
     }
     Ok(())
 }
 
-fn read_file_content(backend: &dyn SearchBackend, path: &Path) -> Result<Option<String>> {
+/// Reads `path` for output, or returns `None` with a warning (unless `messages` is quiet) when
+/// the file is too large, binary, or looks like it holds a secret.
+fn read_file_content(
+    backend: &dyn SearchBackend,
+    path: &Path,
+    messages: Messages,
+) -> Result<Option<String>> {
     let metadata = backend
         .stat(path)
         .with_context(|| format!("Failed to read metadata for {}", path.display()))?;
 
     if metadata.size_bytes > MAX_FILE_SIZE {
-        eprintln!(
+        messages.warn(format_args!(
             "Skipping {} (exceeds max file size of {} bytes)",
             path.display(),
             MAX_FILE_SIZE
-        );
+        ));
         return Ok(None);
     }
 
@@ -104,17 +116,17 @@ fn read_file_content(backend: &dyn SearchBackend, path: &Path) -> Result<Option<
         .with_context(|| format!("Failed to read file {}", path.display()))?;
 
     if is_probably_binary(&bytes) {
-        eprintln!("Skipping binary file {}", path.display());
+        messages.warn(format_args!("Skipping binary file {}", path.display()));
         return Ok(None);
     }
 
     let content = String::from_utf8_lossy(&bytes).into_owned();
 
     if maybe_contains_secret(&content) {
-        eprintln!(
+        messages.warn(format_args!(
             "Skipping possible secret-containing file {}",
             path.display()
-        );
+        ));
         return Ok(None);
     }
 
@@ -127,6 +139,7 @@ fn print_markdown_format(
     matching_files: &[(PathBuf, Vec<Range>)],
     with_line_numbers: bool,
     with_headers: bool,
+    messages: Messages,
 ) -> Result<()> {
     for (index, (path, _)) in matching_files.iter().enumerate() {
         if with_headers {
@@ -136,7 +149,7 @@ fn print_markdown_format(
             writeln!(writer, "File: {}", path.display())?;
             writeln!(writer, "---")?;
         }
-        let Some(content) = read_file_content(backend, path)? else {
+        let Some(content) = read_file_content(backend, path, messages)? else {
             continue;
         };
         let extension = path.extension().and_then(|s| s.to_str()).unwrap_or("");
@@ -151,9 +164,10 @@ fn print_cat_format(
     matching_files: &[(PathBuf, Vec<Range>)],
     with_line_numbers: bool,
     use_color: bool,
+    messages: Messages,
 ) -> Result<()> {
     for (path, _) in matching_files {
-        let Some(content) = read_file_content(backend, path)? else {
+        let Some(content) = read_file_content(backend, path, messages)? else {
             continue;
         };
         if use_color {
@@ -176,10 +190,11 @@ fn print_json_format(
     backend: &dyn SearchBackend,
     writer: &mut impl Write,
     matching_files: &[(PathBuf, Vec<Range>)],
+    messages: Messages,
 ) -> Result<()> {
     let mut outputs = Vec::new();
     for (path, _) in matching_files {
-        let Some(content) = read_file_content(backend, path)
+        let Some(content) = read_file_content(backend, path, messages)
             .with_context(|| format!("Failed to read file for final output: {}", path.display()))?
         else {
             continue;
@@ -240,6 +255,7 @@ fn print_hunks_format(
     with_headers: bool,
     use_color: bool,
     context_lines: usize,
+    messages: Messages,
 ) -> Result<()> {
     for (index, (path, hunks)) in matching_files.iter().enumerate() {
         if with_headers {
@@ -249,7 +265,7 @@ fn print_hunks_format(
             writeln!(writer, "File: {}", path.display())?;
             writeln!(writer, "---")?;
         }
-        let Some(content) = read_file_content(backend, path)? else {
+        let Some(content) = read_file_content(backend, path, messages)? else {
             continue;
         };
         let extension = path.extension().and_then(|s| s.to_str()).unwrap_or("");
@@ -258,7 +274,11 @@ fn print_hunks_format(
             print_content_with_style(writer, &content, extension, with_line_numbers, use_color, 0)?;
         } else {
             let lines: Vec<&str> = syntect::util::LinesWithEndings::from(&content).collect();
-            let line_ranges = get_contextual_line_ranges(hunks, &lines, context_lines);
+            let line_ranges = get_contextual_line_ranges(
+                hunks,
+                &lines,
+                crate::ContextLines::symmetric(context_lines),
+            );
 
             for (range_index, range) in line_ranges.iter().enumerate() {
                 if range_index > 0 {
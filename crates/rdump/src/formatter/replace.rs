@@ -0,0 +1,193 @@
+use anyhow::Result;
+use rdump_contracts::RegexFlags;
+use regex::{Regex, RegexBuilder};
+use std::io::Write;
+use std::ops::Range;
+
+use crate::formatter::shared::display_path_text;
+use crate::parser::{AstNode, PredicateKey};
+use crate::{Match, SearchReport};
+
+/// A contiguous run of whole lines touched by one or more replacements.
+struct ReplaceHunk {
+    old_start_line: usize,
+    old_lines: Vec<String>,
+    new_lines: Vec<String>,
+}
+
+/// Builds the pattern that locates replacement targets inside line-level content matches:
+/// `matches:` regexes as written and `contains:` values as case-insensitive literals, compiled
+/// with the same `regex_flags` and `whole_word` bounds the predicates used. Negated predicates
+/// are ignored. Returns `None` when the query has neither.
+pub fn replace_target_pattern(
+    ast: &AstNode,
+    regex_flags: RegexFlags,
+    whole_word: bool,
+) -> Result<Option<Regex>> {
+    fn collect(node: &AstNode, alternatives: &mut Vec<String>) {
+        match node {
+            AstNode::Predicate(PredicateKey::Matches, value) => {
+                alternatives.push(format!("(?:{value})"));
+            }
+            AstNode::Predicate(PredicateKey::Contains, value) => {
+                alternatives.push(format!("(?i:{})", regex::escape(value)));
+            }
+            AstNode::Predicate(_, _) | AstNode::Not(_) => {}
+            AstNode::LogicalOp(_, left, right) | AstNode::Near(left, right, _) => {
+                collect(left, alternatives);
+                collect(right, alternatives);
+            }
+        }
+    }
+
+    let mut alternatives = Vec::new();
+    collect(ast, &mut alternatives);
+    if alternatives.is_empty() {
+        return Ok(None);
+    }
+    let mut pattern = alternatives.join("|");
+    if whole_word {
+        pattern = format!(r"\b(?:{pattern})\b");
+    }
+    Ok(Some(
+        RegexBuilder::new(&pattern)
+            .size_limit(10 * 1024 * 1024)
+            .dot_matches_new_line(regex_flags.dotall)
+            .multi_line(regex_flags.multiline)
+            .case_insensitive(regex_flags.case_insensitive)
+            .build()?,
+    ))
+}
+
+/// Prints a unified diff (without context lines) of what replacing every match with the
+/// literal `replacement` would change. When `target` is given, only its occurrences inside
+/// each match are replaced; matches it does not occur in (such as semantic captures) are
+/// replaced whole. Nothing is written to disk.
+pub fn print_replace_preview(
+    writer: &mut impl Write,
+    report: &SearchReport,
+    replacement: &str,
+    target: Option<&Regex>,
+) -> Result<()> {
+    for result in &report.results {
+        if !result.content_available() {
+            continue;
+        }
+        let hunks = replacement_hunks(&result.content, &result.matches, replacement, target);
+        if hunks.is_empty() {
+            continue;
+        }
+
+        let path = display_path_text(&result.path);
+        let path = path.strip_prefix("./").unwrap_or(&path);
+        writeln!(writer, "--- a/{path}")?;
+        writeln!(writer, "+++ b/{path}")?;
+        let mut line_delta: isize = 0;
+        for hunk in hunks {
+            let new_start_line = hunk.old_start_line as isize + line_delta;
+            writeln!(
+                writer,
+                "@@ -{},{} +{},{} @@",
+                hunk.old_start_line,
+                hunk.old_lines.len(),
+                new_start_line,
+                hunk.new_lines.len()
+            )?;
+            for line in &hunk.old_lines {
+                writeln!(writer, "-{line}")?;
+            }
+            for line in &hunk.new_lines {
+                writeln!(writer, "+{line}")?;
+            }
+            line_delta += hunk.new_lines.len() as isize - hunk.old_lines.len() as isize;
+        }
+    }
+    Ok(())
+}
+
+/// Picks the edits to apply: candidates are taken left to right (longest first on ties), and
+/// one overlapping an already chosen edit is skipped so the outcome never depends on the
+/// order predicates reported their matches in.
+fn select_edits(content: &str, matches: &[Match], target: Option<&Regex>) -> Vec<Range<usize>> {
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    for matched in matches {
+        let span = matched.byte_range.clone();
+        let Some(text) = content.get(span.clone()) else {
+            continue;
+        };
+        let before = ranges.len();
+        if let Some(target) = target {
+            ranges.extend(
+                target
+                    .find_iter(text)
+                    .map(|found| span.start + found.start()..span.start + found.end()),
+            );
+        }
+        if ranges.len() == before {
+            ranges.push(span);
+        }
+    }
+    ranges.retain(|range| range.start < range.end);
+    ranges.sort_by(|a, b| a.start.cmp(&b.start).then(b.end.cmp(&a.end)));
+
+    let mut edits: Vec<Range<usize>> = Vec::new();
+    for range in ranges {
+        if edits.last().is_some_and(|last| range.start < last.end) {
+            continue;
+        }
+        edits.push(range);
+    }
+    edits
+}
+
+fn replacement_hunks(
+    content: &str,
+    matches: &[Match],
+    replacement: &str,
+    target: Option<&Regex>,
+) -> Vec<ReplaceHunk> {
+    let edits = select_edits(content, matches, target);
+
+    // Widen each edit to the full lines it touches, merging edits that share a line.
+    let mut groups: Vec<(Range<usize>, Vec<Range<usize>>)> = Vec::new();
+    for edit in edits {
+        let line_start = content[..edit.start]
+            .rfind('\n')
+            .map_or(0, |index| index + 1);
+        let line_end = content[edit.end..]
+            .find('\n')
+            .map_or(content.len(), |index| edit.end + index);
+        match groups.last_mut() {
+            Some((span, members)) if line_start <= span.end => {
+                span.end = span.end.max(line_end);
+                members.push(edit);
+            }
+            _ => groups.push((line_start..line_end, vec![edit])),
+        }
+    }
+
+    groups
+        .into_iter()
+        .map(|(span, members)| {
+            let mut replaced = String::with_capacity(span.len());
+            let mut cursor = span.start;
+            for edit in members {
+                replaced.push_str(&content[cursor..edit.start]);
+                replaced.push_str(replacement);
+                cursor = edit.end;
+            }
+            replaced.push_str(&content[cursor..span.end]);
+
+            // Replacing a whole line with nothing still leaves that (now empty) line behind.
+            let mut new_lines: Vec<String> = replaced.lines().map(str::to_string).collect();
+            if new_lines.is_empty() && !span.is_empty() {
+                new_lines.push(String::new());
+            }
+            ReplaceHunk {
+                old_start_line: content[..span.start].matches('\n').count() + 1,
+                old_lines: content[span].lines().map(str::to_string).collect(),
+                new_lines,
+            }
+        })
+        .collect()
+}
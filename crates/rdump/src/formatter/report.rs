@@ -2,17 +2,22 @@ use anyhow::{Context, Result};
 use chrono::{DateTime, Local};
 use rdump_contracts::SearchItem;
 use serde::Serialize;
+use std::collections::BTreeMap;
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use syntect::util::LinesWithEndings;
 
 use crate::backend::{RealFsSearchBackend, SearchBackend};
 use crate::formatter::shared::{
     content_notice, content_state_label, display_path_text, escape_human_text, format_size,
     format_timestamp, get_contextual_line_ranges_from_matches, print_content_with_style,
-    print_markdown_fenced_content, snippet_range_for_match,
+    print_markdown_fenced_content, root_relative_path, snippet_range_for_match, utf16_column,
+    write_prefixed_lines,
+};
+use crate::{
+    ContextLines, Format, GroupByFlag, HeadingStyle, Match, SearchDiagnostic, SearchReport,
+    SearchResult, SearchStats, TimeFormat,
 };
-use crate::{Format, SearchDiagnostic, SearchReport, SearchResult, SearchStats, TimeFormat};
 
 #[derive(Serialize)]
 struct JsonSearchOutput<'a> {
@@ -25,6 +30,46 @@ struct JsonSearchOutput<'a> {
     diagnostics: &'a [SearchDiagnostic],
 }
 
+#[derive(Serialize)]
+struct LspJsonOutput<'a> {
+    schema_version: &'static str,
+    status: rdump_contracts::SearchStatus,
+    results: Vec<LspJsonResult<'a>>,
+    stats: &'a SearchStats,
+    diagnostics: &'a [SearchDiagnostic],
+}
+
+#[derive(Serialize)]
+struct LspJsonResult<'a> {
+    path: String,
+    content_state: String,
+    matches: Vec<LspJsonMatch<'a>>,
+}
+
+#[derive(Serialize)]
+struct LspJsonMatch<'a> {
+    start_line: usize,
+    end_line: usize,
+    start_column: usize,
+    end_column: usize,
+    byte_range: [usize; 2],
+    range: LspRange,
+    text: &'a str,
+}
+
+/// An LSP `Range`: zero-based lines and UTF-16 code-unit characters.
+#[derive(Serialize)]
+struct LspRange {
+    start: LspPosition,
+    end: LspPosition,
+}
+
+#[derive(Serialize)]
+struct LspPosition {
+    line: usize,
+    character: usize,
+}
+
 pub fn print_path_output(
     writer: &mut impl Write,
     paths: &[PathBuf],
@@ -54,6 +99,7 @@ pub fn print_contract_path_items(
     items: &[SearchItem],
     format: &Format,
     time_format: TimeFormat,
+    null_separated: bool,
 ) -> Result<()> {
     for item in items {
         let SearchItem::Path { path, metadata, .. } = item else {
@@ -61,6 +107,9 @@ pub fn print_contract_path_items(
         };
 
         match format {
+            Format::Paths if null_separated => {
+                write!(writer, "{path}\0")?;
+            }
             Format::Paths => {
                 writeln!(writer, "{path}")?;
             }
@@ -86,17 +135,96 @@ pub fn print_contract_path_items(
     Ok(())
 }
 
+/// Splits results into `--group-by` sections named by language or parent directory. Sections
+/// are sorted by name, except that files without a language profile come last as `Other`;
+/// results keep their order within a section. [`GroupByFlag::None`] yields one unnamed section.
+pub(crate) fn group_results(
+    results: &[SearchResult],
+    group_by: GroupByFlag,
+) -> Vec<(String, Vec<&SearchResult>)> {
+    let mut sections: BTreeMap<(bool, String), Vec<&SearchResult>> = BTreeMap::new();
+    for result in results {
+        let key = match group_by {
+            GroupByFlag::None => (false, String::new()),
+            GroupByFlag::Language => match result.language() {
+                Some(language) => (false, language.to_string()),
+                None => (true, "Other".to_string()),
+            },
+            GroupByFlag::Directory => {
+                let parent = result.path.parent().map(display_path_text);
+                match parent.filter(|parent| !parent.is_empty()) {
+                    Some(parent) => (false, parent),
+                    None => (false, ".".to_string()),
+                }
+            }
+        };
+        sections.entry(key).or_default().push(result);
+    }
+    sections
+        .into_iter()
+        .map(|((_, name), results)| (name, results))
+        .collect()
+}
+
+/// How [`print_report_output`] and [`print_report_body`] render a report. Formats ignore the
+/// settings that do not apply to them.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ReportRenderOptions {
+    /// Print `json` and `lsp-json` output on a single line.
+    pub json_compact: bool,
+    pub with_line_numbers: bool,
+    pub no_headers: bool,
+    pub heading: HeadingStyle,
+    pub use_color: bool,
+    pub context: ContextLines,
+    /// Keep only each result's first matches by line; hunk and markdown output note how many
+    /// were left out.
+    pub max_matches_per_file: Option<usize>,
+    pub show_suppressed_placeholders: bool,
+    pub time_format: TimeFormat,
+}
+
 pub fn print_report_output(
     writer: &mut impl Write,
     report: &SearchReport,
     format: &Format,
-    with_line_numbers: bool,
-    no_headers: bool,
-    use_color: bool,
-    context_lines: usize,
-    show_suppressed_placeholders: bool,
-    time_format: TimeFormat,
+    options: &ReportRenderOptions,
 ) -> Result<()> {
+    print_report_body(writer, report, format, options)?;
+    // The footer is human-readable text, so keep it out of machine-readable output.
+    if !matches!(format, Format::LspJson | Format::Github | Format::Diff) {
+        print_report_footer(writer, report, format)?;
+    }
+    Ok(())
+}
+
+/// Like [`print_report_output`], but without the trailing summary footer.
+pub fn print_report_body(
+    writer: &mut impl Write,
+    report: &SearchReport,
+    format: &Format,
+    options: &ReportRenderOptions,
+) -> Result<()> {
+    let ReportRenderOptions {
+        json_compact,
+        with_line_numbers,
+        no_headers,
+        heading,
+        use_color,
+        context,
+        max_matches_per_file,
+        show_suppressed_placeholders,
+        time_format,
+    } = *options;
+    let capped;
+    let (report, omitted) = match max_matches_per_file {
+        Some(max) => {
+            capped = cap_matches_per_file(report, max);
+            (&capped.0, capped.1.as_slice())
+        }
+        None => (report, &[][..]),
+    };
+
     match format {
         Format::Find => print_find_results(writer, &report.results, time_format)?,
         Format::Paths => {
@@ -107,7 +235,10 @@ pub fn print_report_output(
                 .collect();
             print_paths_only(writer, &paths)?
         }
-        Format::Json => print_json_report(writer, report)?,
+        Format::Json => print_json_report(writer, report, json_compact)?,
+        Format::LspJson => print_lsp_json_report(writer, report, json_compact)?,
+        Format::Github => print_github_annotations(writer, &report.results)?,
+        Format::Diff => print_diff_results(writer, &report.results, context)?,
         Format::Cat => print_cat_results(
             writer,
             &report.results,
@@ -118,22 +249,26 @@ pub fn print_report_output(
         Format::Markdown => print_markdown_results(
             writer,
             &report.results,
+            omitted,
             with_line_numbers,
-            !no_headers,
+            (!no_headers).then_some(heading),
             show_suppressed_placeholders,
         )?,
         Format::Hunks => print_hunks_results(
             writer,
             &report.results,
+            omitted,
             with_line_numbers,
-            !no_headers,
+            (!no_headers).then_some(heading),
             use_color,
-            context_lines,
+            context,
             show_suppressed_placeholders,
         )?,
         Format::Summary => {
             print_summary_results(writer, &report.results, show_suppressed_placeholders)?
         }
+        Format::Aggregate => print_aggregate_results(writer, &report.results)?,
+        Format::DirSummary => print_dir_summary_results(writer, &report.results)?,
         Format::Diagnostics => print_diagnostics_results(
             writer,
             &report.results,
@@ -152,29 +287,92 @@ pub fn print_report_output(
             with_line_numbers,
             !no_headers,
             use_color,
-            context_lines,
+            context,
             show_suppressed_placeholders,
         )?,
     }
-    print_report_footer(writer, report, format)?;
     Ok(())
 }
 
-fn print_markdown_results(
+/// Keeps each result's first `max` matches by start line. Also returns how many matches were
+/// dropped from each result, in result order.
+fn cap_matches_per_file(report: &SearchReport, max: usize) -> (SearchReport, Vec<usize>) {
+    let mut capped = report.clone();
+    let omitted = capped
+        .results
+        .iter_mut()
+        .map(|result| {
+            let total = result.matches.len();
+            result.matches.sort_by_key(|matched| matched.start_line);
+            result.matches.truncate(max);
+            total - result.matches.len()
+        })
+        .collect();
+    (capped, omitted)
+}
+
+fn print_omitted_matches_note(writer: &mut impl Write, omitted: usize) -> Result<()> {
+    if omitted > 0 {
+        writeln!(writer, "(+{omitted} more)")?;
+    }
+    Ok(())
+}
+
+/// Prints the per-file header for hunk-style output. `heading` is `None` when headers are
+/// disabled altogether.
+fn print_file_heading(
     writer: &mut impl Write,
-    results: &[SearchResult],
-    with_line_numbers: bool,
-    with_headers: bool,
-    show_suppressed_placeholders: bool,
+    result: &SearchResult,
+    index: usize,
+    heading: Option<HeadingStyle>,
 ) -> Result<()> {
-    for (index, result) in results.iter().enumerate() {
-        if with_headers {
+    match heading {
+        Some(HeadingStyle::PerFile) => {
             if index > 0 {
                 writeln!(writer, "\n---\n")?;
             }
             writeln!(writer, "File: {}", display_path_text(&result.path))?;
+            print_content_aliases(writer, result)?;
             writeln!(writer, "---")?;
         }
+        Some(HeadingStyle::Grouped) => {
+            if index > 0 {
+                writeln!(writer)?;
+            }
+            writeln!(writer, "{}", display_path_text(&result.path))?;
+            print_content_aliases(writer, result)?;
+        }
+        Some(HeadingStyle::Inline) | None => {}
+    }
+    Ok(())
+}
+
+/// Names the files `--dedup-content` folded into this result.
+fn print_content_aliases(writer: &mut impl Write, result: &SearchResult) -> Result<()> {
+    let aliases = &result.metadata.content_aliases;
+    if !aliases.is_empty() {
+        let aliases: Vec<_> = aliases.iter().map(|path| display_path_text(path)).collect();
+        writeln!(writer, "Identical content: {}", aliases.join(", "))?;
+    }
+    Ok(())
+}
+
+/// The prefix written before every content line when paths are printed inline.
+fn inline_path_prefix(result: &SearchResult, heading: Option<HeadingStyle>) -> Option<String> {
+    matches!(heading, Some(HeadingStyle::Inline))
+        .then(|| format!("{}:", display_path_text(&result.path)))
+}
+
+fn print_markdown_results(
+    writer: &mut impl Write,
+    results: &[SearchResult],
+    omitted: &[usize],
+    with_line_numbers: bool,
+    heading: Option<HeadingStyle>,
+    show_suppressed_placeholders: bool,
+) -> Result<()> {
+    for (index, result) in results.iter().enumerate() {
+        print_file_heading(writer, result, index, heading)?;
 
         if !result.content_available() {
             if !show_suppressed_placeholders {
@@ -189,7 +387,111 @@ fn print_markdown_results(
             .extension()
             .and_then(|s| s.to_str())
             .unwrap_or("");
-        print_markdown_fenced_content(writer, &result.content, extension, with_line_numbers, 0)?;
+        match inline_path_prefix(result, heading) {
+            Some(prefix) => {
+                let mut fenced = Vec::new();
+                print_markdown_fenced_content(
+                    &mut fenced,
+                    &result.content,
+                    extension,
+                    with_line_numbers,
+                    0,
+                )?;
+                write_prefixed_lines(writer, &String::from_utf8_lossy(&fenced), &prefix)?;
+            }
+            None => print_markdown_fenced_content(
+                writer,
+                &result.content,
+                extension,
+                with_line_numbers,
+                0,
+            )?,
+        }
+        print_omitted_matches_note(writer, omitted.get(index).copied().unwrap_or(0))?;
+    }
+    Ok(())
+}
+
+/// Prints a GitHub Actions `::warning` workflow command per match, using the first line of
+/// the matched text as the message. Whole-file matches get one annotation at line 1.
+fn print_github_annotations(writer: &mut impl Write, results: &[SearchResult]) -> Result<()> {
+    for result in results {
+        let path = result
+            .path
+            .strip_prefix(".")
+            .unwrap_or(&result.path)
+            .to_string_lossy()
+            .replace('\\', "/");
+        let file = escape_github_property(&path);
+        if result.matches.is_empty() {
+            writeln!(
+                writer,
+                "::warning file={file},line=1,col=1::{}",
+                escape_github_message("whole-file match")
+            )?;
+            continue;
+        }
+        for matched in &result.matches {
+            writeln!(
+                writer,
+                "::warning file={file},line={},col={}::{}",
+                matched.start_line,
+                matched.start_column + 1,
+                escape_github_message(matched.first_line().trim())
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Escapes workflow command message data.
+fn escape_github_message(text: &str) -> String {
+    text.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escapes a workflow command property value such as `file=`.
+fn escape_github_property(text: &str) -> String {
+    escape_github_message(text)
+        .replace(':', "%3A")
+        .replace(',', "%2C")
+}
+
+/// Prints each result as a unified-diff block: `---`/`+++` file headers, then one `@@` hunk
+/// per matched line range with every line prefixed by `+`. Both sides of the `@@` header carry
+/// the matched range, so viewers show real line numbers; the output is not meant for
+/// `git apply`. Whole-file matches become a single hunk covering the file.
+fn print_diff_results(
+    writer: &mut impl Write,
+    results: &[SearchResult],
+    context: ContextLines,
+) -> Result<()> {
+    for result in results {
+        if !result.content_available() {
+            continue;
+        }
+        let lines: Vec<&str> = LinesWithEndings::from(&result.content).collect();
+        let line_ranges = if result.matches.is_empty() {
+            vec![0..lines.len()]
+        } else {
+            get_contextual_line_ranges_from_matches(&result.matches, &lines, context)
+        };
+        if lines.is_empty() || line_ranges.is_empty() {
+            continue;
+        }
+
+        let path = display_path_text(result.path.strip_prefix(".").unwrap_or(&result.path));
+        writeln!(writer, "--- a/{path}")?;
+        writeln!(writer, "+++ b/{path}")?;
+        for range in line_ranges {
+            let start = range.start + 1;
+            let count = range.len();
+            writeln!(writer, "@@ -{start},{count} +{start},{count} @@")?;
+            for line in &lines[range] {
+                writeln!(writer, "+{}", line.trim_end_matches(['\n', '\r']))?;
+            }
+        }
     }
     Ok(())
 }
@@ -226,7 +528,7 @@ fn print_cat_results(
     Ok(())
 }
 
-fn print_json_report(writer: &mut impl Write, report: &SearchReport) -> Result<()> {
+fn print_json_report(writer: &mut impl Write, report: &SearchReport, compact: bool) -> Result<()> {
     let output = JsonSearchOutput {
         schema_version: rdump_contracts::SCHEMA_VERSION,
         schema_reference: "rdump://docs/sdk",
@@ -236,7 +538,64 @@ fn print_json_report(writer: &mut impl Write, report: &SearchReport) -> Result<(
         stats: &report.stats,
         diagnostics: &report.diagnostics,
     };
-    serde_json::to_writer_pretty(writer, &output)?;
+    write_json(writer, &output, compact)
+}
+
+/// Serializes `value` on one line when `compact`, otherwise pretty-printed. The field set
+/// is the same either way.
+fn write_json(writer: &mut impl Write, value: &impl serde::Serialize, compact: bool) -> Result<()> {
+    if compact {
+        serde_json::to_writer(writer, value)?;
+    } else {
+        serde_json::to_writer_pretty(writer, value)?;
+    }
+    Ok(())
+}
+
+fn print_lsp_json_report(
+    writer: &mut impl Write,
+    report: &SearchReport,
+    compact: bool,
+) -> Result<()> {
+    let results = report
+        .results
+        .iter()
+        .map(|result| LspJsonResult {
+            path: result.path.display().to_string(),
+            content_state: content_state_label(&result.content_state),
+            matches: result
+                .matches
+                .iter()
+                .map(|matched| LspJsonMatch {
+                    start_line: matched.start_line,
+                    end_line: matched.end_line,
+                    start_column: matched.start_column,
+                    end_column: matched.end_column,
+                    byte_range: [matched.byte_range.start, matched.byte_range.end],
+                    range: LspRange {
+                        start: LspPosition {
+                            line: matched.start_line.saturating_sub(1),
+                            character: utf16_column(&result.content, matched.byte_range.start),
+                        },
+                        end: LspPosition {
+                            line: matched.end_line.saturating_sub(1),
+                            character: utf16_column(&result.content, matched.byte_range.end),
+                        },
+                    },
+                    text: &matched.text,
+                })
+                .collect(),
+        })
+        .collect();
+    let output = LspJsonOutput {
+        schema_version: rdump_contracts::SCHEMA_VERSION,
+        status: report.status(),
+        results,
+        stats: &report.stats,
+        diagnostics: &report.diagnostics,
+    };
+    write_json(&mut *writer, &output, compact)?;
+    writeln!(writer)?;
     Ok(())
 }
 
@@ -310,20 +669,15 @@ fn print_find_results(
 fn print_hunks_results(
     writer: &mut impl Write,
     results: &[SearchResult],
+    omitted: &[usize],
     with_line_numbers: bool,
-    with_headers: bool,
+    heading: Option<HeadingStyle>,
     use_color: bool,
-    context_lines: usize,
+    context: ContextLines,
     show_suppressed_placeholders: bool,
 ) -> Result<()> {
     for (index, result) in results.iter().enumerate() {
-        if with_headers {
-            if index > 0 {
-                writeln!(writer, "\n---\n")?;
-            }
-            writeln!(writer, "File: {}", display_path_text(&result.path))?;
-            writeln!(writer, "---")?;
-        }
+        print_file_heading(writer, result, index, heading)?;
 
         if !result.content_available() {
             if !show_suppressed_placeholders {
@@ -338,41 +692,90 @@ fn print_hunks_results(
             .extension()
             .and_then(|s| s.to_str())
             .unwrap_or("");
+        let prefix = inline_path_prefix(result, heading);
 
         if result.matches.is_empty() {
-            print_content_with_style(
+            print_hunk_content(
                 writer,
                 &result.content,
                 extension,
                 with_line_numbers,
                 use_color,
                 0,
+                prefix.as_deref(),
             )?;
             continue;
         }
 
         let lines: Vec<&str> = LinesWithEndings::from(&result.content).collect();
-        let line_ranges =
-            get_contextual_line_ranges_from_matches(&result.matches, &lines, context_lines);
+        let line_ranges = get_contextual_line_ranges_from_matches(&result.matches, &lines, context);
 
         for (range_index, range) in line_ranges.iter().enumerate() {
             if range_index > 0 {
-                writeln!(writer, "...")?;
+                match heading {
+                    Some(HeadingStyle::Grouped) => writeln!(writer)?,
+                    Some(HeadingStyle::Inline) => {}
+                    Some(HeadingStyle::PerFile) | None => writeln!(writer, "...")?,
+                }
+            }
+            if let Some(enclosing) = hunk_enclosing(&result.matches, range) {
+                writeln!(writer, "[in {enclosing}]")?;
             }
             let hunk_content = lines[range.clone()].join("");
-            print_content_with_style(
+            print_hunk_content(
                 writer,
                 &hunk_content,
                 extension,
                 with_line_numbers,
                 use_color,
                 range.start,
+                prefix.as_deref(),
             )?;
         }
+        print_omitted_matches_note(writer, omitted.get(index).copied().unwrap_or(0))?;
     }
     Ok(())
 }
 
+/// The enclosing-scope label of the first match that starts inside the 0-indexed `range`.
+fn hunk_enclosing<'a>(matches: &'a [Match], range: &std::ops::Range<usize>) -> Option<&'a str> {
+    matches
+        .iter()
+        .find(|matched| range.contains(&matched.start_line.saturating_sub(1)))
+        .and_then(|matched| matched.enclosing.as_deref())
+}
+
+fn print_hunk_content(
+    writer: &mut impl Write,
+    content: &str,
+    extension: &str,
+    with_line_numbers: bool,
+    use_color: bool,
+    start_line_number: usize,
+    prefix: Option<&str>,
+) -> Result<()> {
+    let Some(prefix) = prefix else {
+        return print_content_with_style(
+            writer,
+            content,
+            extension,
+            with_line_numbers,
+            use_color,
+            start_line_number,
+        );
+    };
+    let mut rendered = Vec::new();
+    print_content_with_style(
+        &mut rendered,
+        content,
+        extension,
+        with_line_numbers,
+        use_color,
+        start_line_number,
+    )?;
+    write_prefixed_lines(writer, &String::from_utf8_lossy(&rendered), prefix)
+}
+
 fn print_summary_results(
     writer: &mut impl Write,
     results: &[SearchResult],
@@ -395,6 +798,58 @@ fn print_summary_results(
     Ok(())
 }
 
+/// Prints one `predicate: count` line per predicate key across every result, sorted by key.
+/// Whole-file matches carry no hunks and are counted on their own line.
+fn print_aggregate_results(writer: &mut impl Write, results: &[SearchResult]) -> Result<()> {
+    let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+    let mut whole_files = 0usize;
+    for result in results {
+        if result.is_whole_file_match() {
+            whole_files += 1;
+            continue;
+        }
+        for matched in &result.matches {
+            let key = matched.predicate.as_deref().unwrap_or("other");
+            *counts.entry(key).or_default() += 1;
+        }
+    }
+    for (key, count) in counts {
+        writeln!(writer, "{key}: {count}")?;
+    }
+    if whole_files > 0 {
+        writeln!(writer, "whole-file: {whole_files}")?;
+    }
+    Ok(())
+}
+
+/// Prints one `dir/: N files, M matches` line per first-level directory under the root,
+/// sorted by name. Files directly under the root are rolled up as `./`, and whole-file
+/// matches add to the file count only.
+fn print_dir_summary_results(writer: &mut impl Write, results: &[SearchResult]) -> Result<()> {
+    let mut rollup: BTreeMap<String, (usize, usize)> = BTreeMap::new();
+    for result in results {
+        let mut components = root_relative_path(result).components();
+        let directory = match (components.next(), components.next()) {
+            (Some(first), Some(_)) => {
+                format!("{}/", display_path_text(Path::new(first.as_os_str())))
+            }
+            _ => "./".to_string(),
+        };
+        let (files, matches) = rollup.entry(directory).or_default();
+        *files += 1;
+        *matches += result.match_count();
+    }
+    for (directory, (files, matches)) in rollup {
+        let files_label = if files == 1 { "file" } else { "files" };
+        let matches_label = if matches == 1 { "match" } else { "matches" };
+        writeln!(
+            writer,
+            "{directory}: {files} {files_label}, {matches} {matches_label}"
+        )?;
+    }
+    Ok(())
+}
+
 fn print_matches_results(
     writer: &mut impl Write,
     results: &[SearchResult],
@@ -512,7 +967,7 @@ fn print_snippets_results(
     with_line_numbers: bool,
     with_headers: bool,
     use_color: bool,
-    context_lines: usize,
+    context: ContextLines,
     show_suppressed_placeholders: bool,
 ) -> Result<()> {
     for (index, result) in results.iter().enumerate() {
@@ -557,7 +1012,7 @@ fn print_snippets_results(
                 writeln!(writer, "...")?;
             }
 
-            let range = snippet_range_for_match(matched, lines.len(), context_lines);
+            let range = snippet_range_for_match(matched, lines.len(), context);
             writeln!(writer, "@@ {}-{} @@", range.start + 1, range.end)?;
             let snippet_content = lines[range.clone()].join("");
             print_content_with_style(
@@ -58,10 +58,35 @@ pub(crate) fn escape_human_text(text: &str) -> String {
     out
 }
 
+/// Converts a file byte offset into a UTF-16 code-unit column within its line, as editors
+/// speaking LSP expect. Offsets that do not land on a character boundary fall back to the
+/// byte column.
+pub(crate) fn utf16_column(content: &str, byte_offset: usize) -> usize {
+    let byte_offset = byte_offset.min(content.len());
+    let line_start = content.as_bytes()[..byte_offset]
+        .iter()
+        .rposition(|byte| *byte == b'\n')
+        .map_or(0, |index| index + 1);
+    content
+        .get(line_start..byte_offset)
+        .map_or(byte_offset - line_start, |line| line.encode_utf16().count())
+}
+
 pub(crate) fn display_path_text(path: &Path) -> String {
     escape_human_text(&path.display().to_string())
 }
 
+/// The result's path relative to the search root, falling back to its display path without
+/// a leading `./`.
+pub(crate) fn root_relative_path(result: &SearchResult) -> &Path {
+    result
+        .metadata
+        .file
+        .root_relative_path
+        .as_deref()
+        .unwrap_or_else(|| result.path.strip_prefix(".").unwrap_or(&result.path))
+}
+
 pub(crate) fn content_state_label(state: &ContentState) -> String {
     match state {
         ContentState::Loaded => "loaded".to_string(),
@@ -73,7 +98,7 @@ pub(crate) fn content_state_label(state: &ContentState) -> String {
 pub(crate) fn get_contextual_line_ranges(
     hunks: &[Range],
     lines: &[&str],
-    context_lines: usize,
+    context: crate::ContextLines,
 ) -> Vec<StdRange<usize>> {
     if hunks.is_empty() || lines.is_empty() {
         return vec![];
@@ -84,8 +109,8 @@ pub(crate) fn get_contextual_line_ranges(
         let start_line = hunk.start_point.row;
         let end_line = hunk.end_point.row;
 
-        let context_start = start_line.saturating_sub(context_lines);
-        let context_end = (end_line + context_lines).min(lines.len() - 1);
+        let context_start = start_line.saturating_sub(context.before);
+        let context_end = end_line.saturating_add(context.after).min(lines.len() - 1);
 
         if context_end >= context_start {
             line_ranges.push(context_start..context_end + 1);
@@ -98,7 +123,7 @@ pub(crate) fn get_contextual_line_ranges(
 pub(crate) fn get_contextual_line_ranges_from_matches(
     matches: &[crate::Match],
     lines: &[&str],
-    context_lines: usize,
+    context: crate::ContextLines,
 ) -> Vec<StdRange<usize>> {
     if matches.is_empty() || lines.is_empty() {
         return vec![];
@@ -109,8 +134,8 @@ pub(crate) fn get_contextual_line_ranges_from_matches(
         let start_line = matched.start_line.saturating_sub(1);
         let end_line = matched.end_line.saturating_sub(1);
 
-        let context_start = start_line.saturating_sub(context_lines);
-        let context_end = (end_line + context_lines).min(lines.len() - 1);
+        let context_start = start_line.saturating_sub(context.before);
+        let context_end = end_line.saturating_add(context.after).min(lines.len() - 1);
 
         if context_end >= context_start {
             line_ranges.push(context_start..context_end + 1);
@@ -123,13 +148,15 @@ pub(crate) fn get_contextual_line_ranges_from_matches(
 pub(crate) fn snippet_range_for_match(
     matched: &crate::Match,
     total_lines: usize,
-    context_lines: usize,
+    context: crate::ContextLines,
 ) -> StdRange<usize> {
     let start_line = matched.start_line.saturating_sub(1);
     let end_line = matched.end_line.saturating_sub(1);
 
-    let context_start = start_line.saturating_sub(context_lines);
-    let context_end = (end_line + context_lines).min(total_lines.saturating_sub(1));
+    let context_start = start_line.saturating_sub(context.before);
+    let context_end = end_line
+        .saturating_add(context.after)
+        .min(total_lines.saturating_sub(1));
 
     context_start..context_end + 1
 }
@@ -176,6 +203,28 @@ pub(crate) fn print_plain_content(
     Ok(())
 }
 
+/// Writes `content` with `prefix` before each line, ending with a newline so the next prefix
+/// starts a fresh line. A trailing fragment that carries no text (the ANSI reset after
+/// highlighted output) is written without a prefix.
+pub(crate) fn write_prefixed_lines(
+    writer: &mut impl Write,
+    content: &str,
+    prefix: &str,
+) -> Result<()> {
+    for line in LinesWithEndings::from(content) {
+        if !line.ends_with('\n') && line.trim_start_matches("\x1b[0m").is_empty() {
+            write!(writer, "{line}")?;
+        } else {
+            write!(writer, "{prefix}{line}")?;
+        }
+    }
+    let visible = content.trim_end_matches("\x1b[0m");
+    if !visible.is_empty() && !visible.ends_with('\n') {
+        writeln!(writer)?;
+    }
+    Ok(())
+}
+
 pub(crate) fn print_markdown_fenced_content(
     writer: &mut impl Write,
     content: &str,
@@ -53,6 +53,7 @@
 mod async_api;
 pub mod backend;
 pub mod commands;
+pub mod completion;
 pub mod config;
 pub mod content;
 mod engine;
@@ -64,18 +65,23 @@ pub mod parser;
 pub mod planner;
 pub mod predicates;
 pub mod request;
+mod session;
 pub mod support_matrix;
+#[cfg(feature = "cli")]
+mod watch;
 
 use anyhow::Result;
 #[cfg(feature = "cli")]
-use clap::{Parser, Subcommand, ValueEnum};
+use clap::{parser::ValueSource, CommandFactory, FromArgMatches, Parser, Subcommand, ValueEnum};
 pub use rdump_contracts as contracts;
-use rdump_contracts::{ErrorMode, ExecutionProfile, SemanticMatchMode, SnippetMode};
+use rdump_contracts::{
+    Encoding, ErrorMode, ExecutionProfile, RegexFlags, SemanticMatchMode, SnippetMode,
+};
 use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 use std::hash::{Hash, Hasher};
 #[cfg(unix)]
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 // =============================================================================
@@ -93,7 +99,8 @@ pub use crate::predicates::code_aware::SqlDialect;
 #[cfg(feature = "async")]
 pub use async_api::{
     search_all_async, search_all_async_with_runtime, search_async, search_async_with_progress,
-    search_async_with_runtime, search_async_with_runtime_and_progress,
+    search_async_with_runtime, search_async_with_runtime_and_progress, search_stream,
+    search_stream_with_runtime,
 };
 pub use backend::{
     BackendFileType, BackendMetadata, BackendPathIdentity, DiscoveryReport, DiscoveryRequest,
@@ -111,12 +118,13 @@ pub use request::{
     execute_search_request_with_runtime_and_progress,
     format_search_text as format_contract_search_text, search_options_from_request,
 };
+pub use session::{SearchSession, TreeCache, DEFAULT_TREE_CACHE_CAPACITY};
 
 // Bring our command functions into scope
 pub use crate::content::{ContentSkipReason, ContentState, SearchDiagnostic};
 use crate::predicates::code_aware::SqlDialect as CodeSqlDialect;
 #[cfg(feature = "cli")]
-use commands::{config::run_config, query::run_query};
+use commands::{config::run_config, dump::run_dump, query::run_query};
 #[cfg(feature = "cli")]
 use commands::{lang::run_lang, preset::run_preset, search::run_search};
 use std::ops::Range;
@@ -132,8 +140,18 @@ use tree_sitter::Range as TsRange;
 /// - `root`: current directory (`.`)
 /// - `presets`: empty (no preset filter)
 /// - `no_ignore`: false (respect ignore files)
+/// - `respect_global_gitignore`: true (apply the global gitignore and `.git/info/exclude`)
+/// - `ignore_parents`: true (apply ignore files from directories above the root)
 /// - `hidden`: false (skip hidden files)
 /// - `max_depth`: `None` (use default max depth)
+/// - `min_depth`: `None` (include files directly under the root)
+/// - `max_results`: `None` (no cap on matching files)
+/// - `offset`: 0 and `limit`: `None` (no pagination)
+/// - `same_file_system`: false (follow the walk across mount points)
+/// - `threads`: `None` (walk on the calling thread)
+/// - `context_lines`: `None` (no surrounding lines on matches)
+/// - `show_enclosing`: `false`
+/// - `track_predicates`: `false`
 /// - `sql_dialect`: `None` (auto-detect)
 ///
 /// This struct contains only the parameters needed for search logic,
@@ -149,12 +167,35 @@ pub struct SearchOptions {
     /// If true, ignore .gitignore rules.
     pub no_ignore: bool,
 
+    /// If false, skip the user's global gitignore and `.git/info/exclude` while still
+    /// honoring repo-local ignore files.
+    pub respect_global_gitignore: bool,
+
+    /// If true, apply ignore files in directories above `root` (such as the repository root's
+    /// `.gitignore` when searching a subdirectory), matching git.
+    pub ignore_parents: bool,
+
     /// If true, include hidden files and directories.
     pub hidden: bool,
 
     /// Maximum directory depth to search.
     pub max_depth: Option<usize>,
 
+    /// Minimum directory depth to search. Files directly under the root are at depth 1, so
+    /// `Some(2)` only searches inside subdirectories.
+    pub min_depth: Option<usize>,
+
+    /// Stop after this many matching files. `None` searches every candidate.
+    pub max_results: Option<usize>,
+
+    /// Number of matching files to skip before the first result is returned. Pagination needs
+    /// a stable order, so a nonzero `offset` or a `limit` requires `deterministic: true`.
+    pub offset: usize,
+
+    /// Return at most this many matching files after `offset`. With `offset: 20` and
+    /// `limit: Some(20)` a search yields results 20 through 39 of the sorted match list.
+    pub limit: Option<usize>,
+
     /// SQL dialect override for .sql files.
     pub sql_dialect: Option<SqlDialect>,
 
@@ -202,6 +243,74 @@ pub struct SearchOptions {
 
     /// If true, emit SQL dialect heuristic traces for `.sql` files.
     pub sql_trace: bool,
+
+    /// If true, report files in the query's metadata scope that do not match the full query.
+    pub files_without_match: bool,
+
+    /// Regexes that flag file content as secret-like, replacing the built-in heuristics.
+    /// `None` keeps the defaults and an empty list disables secret detection.
+    pub secret_patterns: Option<Vec<String>>,
+
+    /// Restrict content and semantic matches to a 1-based, inclusive `(start, end)` line window.
+    pub line_range: Option<(usize, usize)>,
+
+    /// Text encoding used to transcode file bytes before matching. `Auto` honors UTF-16 byte
+    /// order marks and otherwise reads UTF-8; match byte ranges refer to the transcoded text.
+    pub encoding: Encoding,
+
+    /// If true, visit files in sorted path order and report timing stats as zero, so repeated
+    /// searches over an unchanged tree produce identical output. Sorting needs every candidate
+    /// path in memory before the first file is evaluated, which adds up on very large trees.
+    pub deterministic: bool,
+
+    /// If true, do not descend into directories on a different file system (device) than the
+    /// root, like `find -xdev`. This has no effect on Windows.
+    pub same_file_system: bool,
+
+    /// Number of threads used to walk the tree. `None` and `Some(1)` walk on the calling thread
+    /// in a single, reproducible traversal order; `Some(0)` picks a parallel count automatically.
+    pub threads: Option<usize>,
+
+    /// Flags for regex-based content predicates (`matches:`). Dotall or multiline mode matches
+    /// against the whole file instead of one line at a time, so a hunk can span several lines.
+    pub regex_flags: RegexFlags,
+
+    /// If true, `path:` globs and substrings match case-insensitively. `name:` is always
+    /// case-insensitive.
+    pub ignore_case_paths: bool,
+
+    /// If true, `contains:` and `matches:` only match the term as a whole word, with a word
+    /// boundary (`\b`) on both sides, so `contains:cat` skips `category`.
+    pub whole_word: bool,
+
+    /// Extra file extensions to treat as a known language for semantic predicates, keyed by
+    /// lowercase extension without the dot (e.g. `mjs` -> `js`). Keys may span several dots
+    /// (`rs.in`); the longest mapped suffix of a file name wins. Targets are profile ids or
+    /// language names (`rust`).
+    pub ext_map: HashMap<String, String>,
+
+    /// Number of lines before and after each match to copy into [`Match::context_before`] and
+    /// [`Match::context_after`], like the CLI's `-C`. `None` leaves both empty.
+    pub context_lines: Option<usize>,
+
+    /// If true, each match records its innermost enclosing function, class, impl or module in
+    /// [`Match::enclosing`]. Costs a parse per matching file.
+    pub show_enclosing: bool,
+
+    /// If true, each match records the key of the predicate that produced it in
+    /// [`Match::predicate`], as `--format=aggregate` needs. Off by default since it keeps a
+    /// per-hunk lookup table while each file is evaluated.
+    pub track_predicates: bool,
+
+    /// If false, results are materialized without reading file content: `content` is empty,
+    /// `matches` is empty, and `content_state` is `Skipped { reason: NotRequested }`.
+    /// Matching still reads content when the query needs it.
+    pub load_content: bool,
+
+    /// Called with a [`Progress`] snapshot every [`PROGRESS_INTERVAL`] candidate files and
+    /// once more when the search finishes. The callback runs on the searching thread, so it
+    /// should return quickly.
+    pub progress: Option<ProgressCallback>,
 }
 
 impl Default for SearchOptions {
@@ -210,8 +319,14 @@ impl Default for SearchOptions {
             root: PathBuf::from("."),
             presets: vec![],
             no_ignore: false,
+            respect_global_gitignore: true,
+            ignore_parents: true,
             hidden: false,
             max_depth: None,
+            min_depth: None,
+            max_results: None,
+            offset: 0,
+            limit: None,
             sql_dialect: None,
             sql_strict: false,
             error_mode: ErrorMode::SkipErrors,
@@ -228,10 +343,65 @@ impl Default for SearchOptions {
             ignore_debug: false,
             language_debug: false,
             sql_trace: false,
+            files_without_match: false,
+            secret_patterns: None,
+            line_range: None,
+            encoding: Encoding::Auto,
+            deterministic: false,
+            same_file_system: false,
+            threads: None,
+            regex_flags: RegexFlags::default(),
+            ignore_case_paths: false,
+            whole_word: false,
+            ext_map: HashMap::new(),
+            context_lines: None,
+            show_enclosing: false,
+            track_predicates: false,
+            load_content: true,
+            progress: None,
         }
     }
 }
 
+/// Number of candidate files between two [`SearchOptions::progress`] reports.
+pub const PROGRESS_INTERVAL: usize = 64;
+
+/// Snapshot of a running search, passed to the [`SearchOptions::progress`] callback.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Progress {
+    /// Candidate files visited so far, whether or not they matched.
+    pub files_scanned: usize,
+    /// Files that matched the query so far. In the final report this equals the number of
+    /// results the search produced.
+    pub files_matched: usize,
+    /// Display path of the file about to be evaluated, or of the last file visited in the
+    /// final report. The search root when no file was visited.
+    pub current_path: PathBuf,
+}
+
+/// Shared progress callback for [`SearchOptions::progress`].
+#[derive(Clone)]
+pub struct ProgressCallback(Arc<dyn Fn(Progress) + Send + Sync>);
+
+impl ProgressCallback {
+    pub fn new(callback: impl Fn(Progress) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(callback))
+    }
+
+    pub(crate) fn report(&self, progress: Progress) {
+        (self.0)(progress)
+    }
+}
+
+impl std::fmt::Debug for ProgressCallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ProgressCallback(..)")
+    }
+}
+
+/// Fluent alternative to struct-literal construction of [`SearchOptions`]:
+/// `SearchOptions::builder().root(dir).hidden(true).preset("rust").build()`.
+/// Unset options keep their [`SearchOptions::default`] values.
 #[derive(Debug, Clone, Default)]
 pub struct SearchOptionsBuilder {
     options: SearchOptions,
@@ -243,6 +413,16 @@ impl SearchOptions {
             options: SearchOptions::default(),
         }
     }
+
+    /// Returns these options adjusted for searches that only report paths. Path output never
+    /// shows file content, so secret detection is disabled: secret-like files stay matchable
+    /// by content predicates and metadata-only queries never scan them.
+    pub(crate) fn for_path_output(&self) -> SearchOptions {
+        SearchOptions {
+            secret_patterns: Some(Vec::new()),
+            ..self.clone()
+        }
+    }
 }
 
 impl SearchOptionsBuilder {
@@ -256,11 +436,27 @@ impl SearchOptionsBuilder {
         self
     }
 
+    /// Appends one preset to those already set.
+    pub fn preset(mut self, preset: impl Into<String>) -> Self {
+        self.options.presets.push(preset.into());
+        self
+    }
+
     pub fn no_ignore(mut self, no_ignore: bool) -> Self {
         self.options.no_ignore = no_ignore;
         self
     }
 
+    pub fn respect_global_gitignore(mut self, respect_global_gitignore: bool) -> Self {
+        self.options.respect_global_gitignore = respect_global_gitignore;
+        self
+    }
+
+    pub fn ignore_parents(mut self, ignore_parents: bool) -> Self {
+        self.options.ignore_parents = ignore_parents;
+        self
+    }
+
     pub fn hidden(mut self, hidden: bool) -> Self {
         self.options.hidden = hidden;
         self
@@ -271,6 +467,26 @@ impl SearchOptionsBuilder {
         self
     }
 
+    pub fn min_depth(mut self, min_depth: Option<usize>) -> Self {
+        self.options.min_depth = min_depth;
+        self
+    }
+
+    pub fn max_results(mut self, max_results: Option<usize>) -> Self {
+        self.options.max_results = max_results;
+        self
+    }
+
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.options.offset = offset;
+        self
+    }
+
+    pub fn limit(mut self, limit: Option<usize>) -> Self {
+        self.options.limit = limit;
+        self
+    }
+
     pub fn sql_dialect(mut self, sql_dialect: Option<SqlDialect>) -> Self {
         self.options.sql_dialect = sql_dialect;
         self
@@ -354,6 +570,86 @@ impl SearchOptionsBuilder {
         self
     }
 
+    pub fn files_without_match(mut self, files_without_match: bool) -> Self {
+        self.options.files_without_match = files_without_match;
+        self
+    }
+
+    pub fn secret_patterns(mut self, secret_patterns: Option<Vec<String>>) -> Self {
+        self.options.secret_patterns = secret_patterns;
+        self
+    }
+
+    pub fn line_range(mut self, line_range: Option<(usize, usize)>) -> Self {
+        self.options.line_range = line_range;
+        self
+    }
+
+    pub fn encoding(mut self, encoding: Encoding) -> Self {
+        self.options.encoding = encoding;
+        self
+    }
+
+    pub fn deterministic(mut self, deterministic: bool) -> Self {
+        self.options.deterministic = deterministic;
+        self
+    }
+
+    pub fn same_file_system(mut self, same_file_system: bool) -> Self {
+        self.options.same_file_system = same_file_system;
+        self
+    }
+
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.options.threads = Some(threads);
+        self
+    }
+
+    pub fn regex_flags(mut self, regex_flags: RegexFlags) -> Self {
+        self.options.regex_flags = regex_flags;
+        self
+    }
+
+    pub fn ignore_case_paths(mut self, ignore_case_paths: bool) -> Self {
+        self.options.ignore_case_paths = ignore_case_paths;
+        self
+    }
+
+    pub fn whole_word(mut self, whole_word: bool) -> Self {
+        self.options.whole_word = whole_word;
+        self
+    }
+
+    pub fn ext_map(mut self, ext_map: HashMap<String, String>) -> Self {
+        self.options.ext_map = ext_map;
+        self
+    }
+
+    pub fn context_lines(mut self, context_lines: usize) -> Self {
+        self.options.context_lines = Some(context_lines);
+        self
+    }
+
+    pub fn show_enclosing(mut self, show_enclosing: bool) -> Self {
+        self.options.show_enclosing = show_enclosing;
+        self
+    }
+
+    pub fn track_predicates(mut self, track_predicates: bool) -> Self {
+        self.options.track_predicates = track_predicates;
+        self
+    }
+
+    pub fn load_content(mut self, load_content: bool) -> Self {
+        self.options.load_content = load_content;
+        self
+    }
+
+    pub fn progress(mut self, callback: impl Fn(Progress) + Send + Sync + 'static) -> Self {
+        self.options.progress = Some(ProgressCallback::new(callback));
+        self
+    }
+
     pub fn build(self) -> SearchOptions {
         self.options
     }
@@ -383,6 +679,89 @@ pub struct FileIdentity {
     pub resolution: PathResolution,
 }
 
+impl FileIdentity {
+    /// Returns the resolved path relative to the current working directory, using `..`
+    /// segments to climb out of it. Falls back to the resolved path when the working
+    /// directory is unavailable or on another drive.
+    pub fn cwd_relative_path(&self) -> PathBuf {
+        std::env::current_dir()
+            .ok()
+            .and_then(|cwd| dunce::canonicalize(cwd).ok())
+            .and_then(|cwd| relative_path_between(&cwd, &self.resolved_path))
+            .unwrap_or_else(|| self.resolved_path.clone())
+    }
+}
+
+/// Where non-fatal, per-file warnings (skipped binary, oversized or secret-like files, parse
+/// failures) go: stderr by default, nowhere once `--no-messages` makes them quiet. Errors are
+/// returned instead and always reach the user.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Messages {
+    quiet: bool,
+}
+
+impl Messages {
+    pub fn new(quiet: bool) -> Self {
+        Self { quiet }
+    }
+
+    pub fn warn(self, message: impl std::fmt::Display) {
+        if !self.quiet {
+            eprintln!("{message}");
+        }
+    }
+}
+
+/// Expands a leading `~` path component to the home directory, as a shell would. `~` elsewhere
+/// in the path, or followed by a user name (`~bob`), is left alone, as is every path when the
+/// home directory is unknown.
+pub(crate) fn expand_tilde(path: &Path) -> PathBuf {
+    let Ok(rest) = path.strip_prefix("~") else {
+        return path.to_path_buf();
+    };
+    match dirs::home_dir() {
+        Some(home) if rest.as_os_str().is_empty() => home,
+        Some(home) => home.join(rest),
+        None => path.to_path_buf(),
+    }
+}
+
+/// [`expand_tilde`] for predicate values such as `in:~/projects`.
+pub(crate) fn expand_tilde_str(value: &str) -> std::borrow::Cow<'_, str> {
+    if !value.starts_with('~') {
+        return value.into();
+    }
+    expand_tilde(Path::new(value))
+        .to_string_lossy()
+        .into_owned()
+        .into()
+}
+
+fn relative_path_between(base: &Path, target: &Path) -> Option<PathBuf> {
+    use std::path::Component;
+    let base: Vec<Component> = base.components().collect();
+    let target: Vec<Component> = target.components().collect();
+    if base.first() != target.first() {
+        return None;
+    }
+    let common = base
+        .iter()
+        .zip(&target)
+        .take_while(|(left, right)| left == right)
+        .count();
+    let mut relative = PathBuf::new();
+    for _ in common..base.len() {
+        relative.push("..");
+    }
+    for component in &target[common..] {
+        relative.push(component.as_os_str());
+    }
+    if relative.as_os_str().is_empty() {
+        relative.push(".");
+    }
+    Some(relative)
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Hash)]
 #[serde(rename_all = "snake_case")]
 pub enum SemanticSkipReason {
@@ -446,6 +825,10 @@ pub struct SearchResultMetadata {
     pub semantic_skip_reasons: Vec<SemanticSkipReason>,
     pub snapshot: Option<FileSnapshot>,
     pub snapshot_drift: bool,
+    /// Other matched files with byte-identical content, set when results are collapsed with
+    /// `--dedup-content`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub content_aliases: Vec<PathBuf>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -487,6 +870,9 @@ impl std::error::Error for SearchMaterializationError {}
 /// single filesystem snapshot. If a file changes between those stages, the
 /// content and diagnostics reflect the later read.
 ///
+/// Like [`Match`], fields may be added in minor releases; build values with
+/// `..Default::default()` so struct literals keep compiling.
+///
 /// # Example
 ///
 /// ```rust
@@ -512,6 +898,7 @@ impl std::error::Error for SearchMaterializationError {}
 ///         end_column: 12,
 ///         byte_range: 10..34,
 ///         text: String::from("fn main() {}"),
+///         ..Default::default()
 ///     }],
 ///     content: String::from("fn main() {}"),
 ///     content_state: rdump::ContentState::Loaded,
@@ -522,7 +909,7 @@ impl std::error::Error for SearchMaterializationError {}
 /// assert_eq!(hunked.match_count(), 1);
 /// assert_eq!(hunked.total_lines_matched(), 2);
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SearchResult {
     /// Path to the matched file.
     pub path: PathBuf,
@@ -587,6 +974,110 @@ impl SearchResult {
     pub fn semantic_skip_reasons(&self) -> &[SemanticSkipReason] {
         &self.metadata.semantic_skip_reasons
     }
+
+    /// Name of the language profile that handles this file (`"Rust"`, `"Python"`), looked up
+    /// by extension or by build-file name such as `Makefile`. `None` when no profile applies.
+    /// `--ext-map` remappings are not taken into account.
+    pub fn language(&self) -> Option<&'static str> {
+        language_for_path(&self.path, &HashMap::new())
+    }
+
+    /// SQL dialect detected from the content of a `.sql` file, the same way semantic
+    /// predicates pick one when no dialect is forced. Files without dialect markers report
+    /// [`SqlDialect::Generic`]; other files report `None`.
+    pub fn detected_dialect(&self) -> Option<SqlDialect> {
+        let is_sql = self
+            .path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("sql"));
+        if !is_sql {
+            return None;
+        }
+        Some(
+            crate::predicates::code_aware::detect_sql_dialect_for_debug(&self.content)
+                .unwrap_or(SqlDialect::Generic),
+        )
+    }
+
+    /// A heuristic relevance score for ranking results; higher is more relevant.
+    ///
+    /// Combines the number of matches, weighted so matches that look like definitions count
+    /// more and matches that look like comments count less, with the share of the file's
+    /// lines that matched. The score depends only on this result, so ranking is
+    /// deterministic, but it is not meaningful across different queries. Whole-file matches
+    /// score `0.0`.
+    pub fn score(&self) -> f64 {
+        if self.matches.is_empty() {
+            return 0.0;
+        }
+        let weighted: f64 = self
+            .matches
+            .iter()
+            .map(|m| match_kind_weight(m.first_line()))
+            .sum();
+        let matched_lines = self.matched_lines();
+        let total_lines = self
+            .content
+            .lines()
+            .count()
+            .max(matched_lines.last().copied().unwrap_or(1));
+        let density = matched_lines.len() as f64 / total_lines as f64;
+        weighted.ln_1p() + density
+    }
+}
+
+/// Weights a match for [`SearchResult::score`] by what its first line looks like.
+fn match_kind_weight(first_line: &str) -> f64 {
+    const MODIFIERS: &[&str] = &[
+        "pub",
+        "pub(crate)",
+        "export",
+        "default",
+        "async",
+        "public",
+        "private",
+        "protected",
+        "internal",
+        "static",
+        "abstract",
+        "final",
+        "unsafe",
+        "extern",
+    ];
+    const DEFINITIONS: &[&str] = &[
+        "fn",
+        "def",
+        "class",
+        "struct",
+        "enum",
+        "trait",
+        "impl",
+        "interface",
+        "func",
+        "function",
+        "type",
+        "record",
+        "module",
+        "mod",
+        "object",
+    ];
+
+    let trimmed = first_line.trim_start();
+    let is_comment = ["//", "/*", "*", "--", "<!--"]
+        .iter()
+        .any(|marker| trimmed.starts_with(marker))
+        || (trimmed.starts_with('#') && !trimmed.starts_with("#["));
+    if is_comment {
+        return 0.5;
+    }
+    let keyword = trimmed
+        .split_whitespace()
+        .find(|word| !MODIFIERS.contains(word));
+    if keyword.is_some_and(|word| DEFINITIONS.contains(&word)) {
+        2.0
+    } else {
+        1.0
+    }
 }
 
 /// A single match within a file.
@@ -594,6 +1085,9 @@ impl SearchResult {
 /// Line numbers are 1-indexed (editor convention). Columns and byte ranges are
 /// 0-indexed because they are byte offsets from the start of the line/file.
 ///
+/// Fields may be added in minor releases; build values with `..Default::default()` so
+/// struct literals keep compiling.
+///
 /// # Example
 ///
 /// ```rust
@@ -606,13 +1100,14 @@ impl SearchResult {
 ///     end_column: 12,
 ///     byte_range: 10..34,
 ///     text: String::from("fn main() {}\nprintln!(\"hi\");"),
+///     ..Default::default()
 /// };
 /// assert_eq!(m.line_count(), 2);
 /// assert!(m.is_multiline());
 /// assert_eq!(m.byte_len(), 24);
 /// assert_eq!(m.first_line(), "fn main() {}");
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Match {
     /// Starting line (1-indexed) of the match.
     pub start_line: usize,
@@ -626,6 +1121,20 @@ pub struct Match {
     pub byte_range: Range<usize>,
     /// The matched text (may be shortened for large hunks).
     pub text: String,
+    /// Up to [`SearchOptions::context_lines`] lines preceding `start_line`, oldest first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub context_before: Vec<String>,
+    /// Up to [`SearchOptions::context_lines`] lines following `end_line`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub context_after: Vec<String>,
+    /// Key of the predicate that produced this hunk (`func`, `struct`, `contains`, ...), set
+    /// when [`SearchOptions::track_predicates`] is on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub predicate: Option<String>,
+    /// Innermost named definition containing the match (`fn parse`, `impl Lexer`), set when
+    /// [`SearchOptions::show_enclosing`] is on and the file has a semantic profile.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enclosing: Option<String>,
 }
 
 impl Match {
@@ -684,6 +1193,17 @@ pub struct SearchStats {
     pub directory_hotspots: Vec<rdump_contracts::DirectoryHotspot>,
 }
 
+impl SearchStats {
+    /// Zeroes the wall-clock timing fields, which vary from run to run.
+    pub(crate) fn clear_timings(&mut self) {
+        self.walk_millis = 0;
+        self.prefilter_millis = 0;
+        self.evaluate_millis = 0;
+        self.materialize_millis = 0;
+        self.semaphore_wait_millis = 0;
+    }
+}
+
 /// Collected search results plus engine-level statistics and diagnostics.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchReport {
@@ -713,16 +1233,48 @@ impl SearchReport {
     }
 }
 
-/// Converts tree-sitter ranges into user-facing `Match` structs.
-fn ranges_to_matches(content: &str, ranges: &[TsRange]) -> Vec<Match> {
+/// Name of the language profile for `path`, honoring `ext_map` remappings.
+pub(crate) fn language_for_path(
+    path: &Path,
+    ext_map: &HashMap<String, String>,
+) -> Option<&'static str> {
+    crate::predicates::code_aware::profile_for_path(path, ext_map).map(|(_, profile)| profile.name)
+}
+
+/// Converts tree-sitter ranges into user-facing `Match` structs, copying up to
+/// `context_lines` surrounding lines into each match. `predicates[i]` and `scopes[i]`, when
+/// present, name the predicate that produced `ranges[i]` and the scope enclosing it.
+fn ranges_to_matches(
+    content: &str,
+    ranges: &[TsRange],
+    predicates: &[Option<String>],
+    scopes: &[Option<String>],
+    context_lines: Option<usize>,
+) -> Vec<Match> {
     if content.is_empty() {
         return Vec::new();
     }
+    let lines: Vec<&str> = if context_lines.is_some() {
+        content.lines().collect()
+    } else {
+        Vec::new()
+    };
+    let context = context_lines.unwrap_or(0);
 
     ranges
         .iter()
-        .filter_map(|range| {
+        .enumerate()
+        .filter_map(|(index, range)| {
             let text = content.get(range.start_byte..range.end_byte)?;
+            let start_row = range.start_point.row.min(lines.len());
+            // A range ending at column 0 stops before that line, which then belongs to the
+            // context after it.
+            let after_row = if range.end_point.column == 0 && range.end_point.row > start_row {
+                range.end_point.row
+            } else {
+                range.end_point.row + 1
+            }
+            .min(lines.len());
 
             Some(Match {
                 start_line: range.start_point.row + 1,
@@ -731,6 +1283,16 @@ fn ranges_to_matches(content: &str, ranges: &[TsRange]) -> Vec<Match> {
                 end_column: range.end_point.column,
                 byte_range: range.start_byte..range.end_byte,
                 text: text.to_string(),
+                context_before: lines[start_row.saturating_sub(context)..start_row]
+                    .iter()
+                    .map(|line| line.to_string())
+                    .collect(),
+                context_after: lines[after_row..(after_row + context).min(lines.len())]
+                    .iter()
+                    .map(|line| line.to_string())
+                    .collect(),
+                predicate: predicates.get(index).cloned().flatten(),
+                enclosing: scopes.get(index).cloned().flatten(),
             })
         })
         .collect()
@@ -738,37 +1300,55 @@ fn ranges_to_matches(content: &str, ranges: &[TsRange]) -> Vec<Match> {
 
 pub(crate) fn materialize_raw_search_item(raw: Result<RawSearchItem>) -> Result<SearchResult> {
     let raw = raw?;
-    let loaded = match crate::content::load_search_content_with_backend(
-        raw.backend.as_ref(),
-        &raw.resolved_path,
-        &raw.display_path,
-    ) {
-        Ok(c) => c,
-        Err(e) => {
-            let snapshot_drift = raw.snapshot.is_some();
-            let message = if snapshot_drift {
-                format!(
-                    "Materialization for {} may have drifted since evaluation: {e}",
-                    raw.resolved_path.display()
-                )
-            } else {
-                e.to_string()
-            };
-            return Err(SearchMaterializationError {
-                path: raw.display_path,
-                kind: SearchMaterializationFailureKind::ContentReadFailed,
-                snapshot_drift,
-                message,
+    let loaded = if !raw.load_content {
+        crate::content::LoadedContent {
+            content: Arc::from(""),
+            state: ContentState::Skipped {
+                reason: ContentSkipReason::NotRequested,
+            },
+            diagnostics: Vec::new(),
+        }
+    } else {
+        match crate::content::load_search_content_with_encoding(
+            raw.backend.as_ref(),
+            &raw.resolved_path,
+            &raw.display_path,
+            &raw.secret_detector,
+            raw.encoding,
+        ) {
+            Ok(c) => c,
+            Err(e) => {
+                let snapshot_drift = raw.snapshot.is_some();
+                let message = if snapshot_drift {
+                    format!(
+                        "Materialization for {} may have drifted since evaluation: {e}",
+                        raw.resolved_path.display()
+                    )
+                } else {
+                    e.to_string()
+                };
+                return Err(SearchMaterializationError {
+                    path: raw.display_path,
+                    kind: SearchMaterializationFailureKind::ContentReadFailed,
+                    snapshot_drift,
+                    message,
+                }
+                .into());
             }
-            .into());
         }
     };
 
     let content = loaded.content.as_ref().to_string();
-    let matches = if raw.ranges.is_empty() || !loaded.state.is_loaded() {
+    let mut matches = if raw.ranges.is_empty() || !loaded.state.is_loaded() {
         Vec::new()
     } else {
-        ranges_to_matches(&content, &raw.ranges)
+        ranges_to_matches(
+            &content,
+            &raw.ranges,
+            &raw.hunk_predicates,
+            &raw.hunk_scopes,
+            raw.context_lines,
+        )
     };
 
     let mut diagnostics = raw.diagnostics;
@@ -817,6 +1397,7 @@ pub(crate) fn materialize_raw_search_item(raw: Result<RawSearchItem>) -> Result<
             semantic_skip_reasons: raw.semantic_skip_reasons,
             snapshot: raw.snapshot,
             snapshot_drift,
+            content_aliases: Vec::new(),
         },
     })
 }
@@ -839,6 +1420,8 @@ pub(crate) fn materialize_raw_search_item(raw: Result<RawSearchItem>) -> Result<
 #[derive(Debug)]
 pub struct SearchResultIterator {
     inner: SearchResultIteratorInner,
+    /// Raw item pulled ahead by `peek_path`, yielded before anything from `inner`.
+    peeked: Option<Result<RawSearchItem>>,
 }
 
 #[derive(Debug)]
@@ -860,9 +1443,17 @@ pub(crate) struct RawSearchItem {
     pub root_relative_path: Option<PathBuf>,
     pub resolution: PathResolution,
     pub ranges: Vec<TsRange>,
+    /// Predicate key that produced each of `ranges`, when known.
+    pub hunk_predicates: Vec<Option<String>>,
+    /// Label of the scope enclosing each of `ranges`, filled only for `show_enclosing`.
+    pub hunk_scopes: Vec<Option<String>>,
     pub diagnostics: Vec<SearchDiagnostic>,
     pub semantic_skip_reasons: Vec<SemanticSkipReason>,
     pub snapshot: Option<FileSnapshot>,
+    pub secret_detector: crate::limits::SecretDetector,
+    pub encoding: Encoding,
+    pub context_lines: Option<usize>,
+    pub load_content: bool,
 }
 
 impl SearchResultIterator {
@@ -879,20 +1470,48 @@ impl SearchResultIterator {
                 stats,
                 diagnostics,
             },
+            peeked: None,
         }
     }
 
     pub(crate) fn from_raw_iter(iter: engine::SearchRawIterator) -> Self {
         Self {
             inner: SearchResultIteratorInner::Raw(iter),
+            peeked: None,
         }
     }
 
     /// Get an upper bound on the remaining results without advancing the iterator.
     pub fn remaining(&self) -> usize {
-        match &self.inner {
+        let pending = match &self.inner {
             SearchResultIteratorInner::Buffered { inner, .. } => inner.len(),
             SearchResultIteratorInner::Raw(iter) => iter.remaining_hint(),
+        };
+        pending + usize::from(self.peeked.is_some())
+    }
+
+    /// Returns the path of the next result without consuming it or reading its content.
+    ///
+    /// Lazily evaluated searches have to run the query over upcoming candidates to find the
+    /// next match, hence `&mut self`. Returns `None` when the results are exhausted or when
+    /// the next item is an error, which the following `next` call yields.
+    pub fn peek_path(&mut self) -> Option<&std::path::Path> {
+        if self.peeked.is_none() {
+            self.peeked = self.next_raw();
+        }
+        match self.peeked.as_ref()? {
+            Ok(item) => Some(&item.display_path),
+            Err(_) => None,
+        }
+    }
+
+    fn next_raw(&mut self) -> Option<Result<RawSearchItem>> {
+        if let Some(peeked) = self.peeked.take() {
+            return Some(peeked);
+        }
+        match &mut self.inner {
+            SearchResultIteratorInner::Buffered { inner, .. } => inner.next().map(Ok),
+            SearchResultIteratorInner::Raw(iter) => iter.next(),
         }
     }
 
@@ -933,20 +1552,21 @@ impl Iterator for SearchResultIterator {
     type Item = Result<SearchResult>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let raw = match &mut self.inner {
-            SearchResultIteratorInner::Buffered { inner, .. } => inner.next().map(Ok)?,
-            SearchResultIteratorInner::Raw(iter) => iter.next()?,
-        };
+        let raw = self.next_raw()?;
         Some(materialize_raw_search_item(raw))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
+        let peeked = usize::from(self.peeked.is_some());
         match &self.inner {
             SearchResultIteratorInner::Buffered { inner, .. } => {
-                let len = inner.len();
+                let len = inner.len() + peeked;
                 (len, Some(len))
             }
-            SearchResultIteratorInner::Raw(iter) => iter.size_hint(),
+            SearchResultIteratorInner::Raw(iter) => {
+                let (lower, upper) = iter.size_hint();
+                (lower + peeked, upper.map(|upper| upper + peeked))
+            }
         }
     }
 }
@@ -1096,7 +1716,7 @@ impl std::iter::FusedIterator for SearchPathIterator {}
 /// # Errors
 /// - Invalid query syntax (parse/validation failure)
 /// - Unknown preset name
-/// - Root directory missing or inaccessible
+/// - Root directory missing, inaccessible, or not a directory
 /// - Empty query with no presets
 ///
 /// # Examples
@@ -1150,7 +1770,7 @@ pub fn search_iter(query: &str, options: SearchOptions) -> Result<SearchResultIt
 /// # Errors
 /// - Invalid query syntax
 /// - Unknown preset name
-/// - Root directory missing or inaccessible
+/// - Root directory missing, inaccessible, or not a directory
 /// - First per-file error encountered during iteration
 ///
 /// # Examples
@@ -1191,6 +1811,14 @@ pub fn search_paths(query: &str, options: SearchOptions) -> Result<Vec<PathBuf>>
     search_path_iter(query, options)?.collect()
 }
 
+/// Search for `text` as a literal substring instead of parsing it as RQL, like `grep -F`.
+///
+/// Equivalent to `search` with a `contains:` query quoting the whole text, so input such as
+/// `a & b` is never split into predicates.
+pub fn search_literal(text: &str, options: SearchOptions) -> Result<Vec<SearchResult>> {
+    search(&crate::parser::literal_query(text), options)
+}
+
 // =============================================================================
 // CLI API
 // =============================================================================
@@ -1229,6 +1857,8 @@ pub enum Commands {
     /// Manage saved presets.
     #[cfg_attr(feature = "cli", command(visible_alias = "p"))]
     Preset(PresetArgs),
+    /// Bundle matching hunks into one LLM-friendly document with a file manifest.
+    Dump(DumpArgs),
 }
 
 #[derive(Debug, Clone, Default, PartialEq)]
@@ -1240,6 +1870,16 @@ pub enum ColorChoice {
     Never,
 }
 
+/// Output format for `rdump lang describe`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(ValueEnum))]
+pub enum LangDescribeFormat {
+    #[default]
+    Text,
+    /// Name, extensions, and supported predicates with descriptions, as JSON.
+    Json,
+}
+
 #[derive(Debug, Clone, Copy, Default, PartialEq)]
 #[cfg_attr(feature = "cli", derive(ValueEnum))]
 pub enum TimeFormat {
@@ -1250,6 +1890,35 @@ pub enum TimeFormat {
     Unix,
 }
 
+/// How hunk and markdown output label the file each block of content came from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HeadingStyle {
+    /// A `File:` header above each file, with `...` between hunks.
+    #[default]
+    PerFile,
+    /// The bare path printed once above each file, with blank lines between hunks.
+    Grouped,
+    /// No headers; every content line is prefixed with its path.
+    Inline,
+}
+
+/// Lines of context hunk and snippet output show around each match, clamped to the file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ContextLines {
+    pub before: usize,
+    pub after: usize,
+}
+
+impl ContextLines {
+    /// The same number of lines on both sides of a match.
+    pub fn symmetric(lines: usize) -> Self {
+        Self {
+            before: lines,
+            after: lines,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "cli", derive(ValueEnum))]
 pub enum SqlDialectFlag {
@@ -1270,6 +1939,17 @@ pub enum SemanticMatchModeFlag {
     Wildcard,
 }
 
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "cli", derive(ValueEnum))]
+pub enum EncodingFlag {
+    #[default]
+    Auto,
+    Utf8,
+    Latin1,
+    Utf16le,
+    Utf16be,
+}
+
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "cli", derive(ValueEnum))]
 pub enum ExecutionProfileFlag {
@@ -1285,6 +1965,7 @@ pub enum PathDisplayModeFlag {
     Relative,
     Absolute,
     RootRelative,
+    RelativeToCwd,
 }
 
 #[derive(Debug, Clone, Copy, Default)]
@@ -1295,6 +1976,28 @@ pub enum LineEndingModeFlag {
     Normalize,
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(ValueEnum))]
+pub enum SortByFlag {
+    /// Path order, as the files were searched.
+    #[default]
+    Path,
+    /// Highest [`SearchResult::score`] first; ties keep path order.
+    Relevance,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(ValueEnum))]
+pub enum GroupByFlag {
+    /// One flat list of results.
+    #[default]
+    None,
+    /// A section per [`SearchResult::language`]; files without a profile come last as `Other`.
+    Language,
+    /// A section per parent directory of the displayed path.
+    Directory,
+}
+
 impl From<SemanticMatchModeFlag> for rdump_contracts::SemanticMatchMode {
     fn from(value: SemanticMatchModeFlag) -> Self {
         match value {
@@ -1309,6 +2012,18 @@ impl From<SemanticMatchModeFlag> for rdump_contracts::SemanticMatchMode {
     }
 }
 
+impl From<EncodingFlag> for rdump_contracts::Encoding {
+    fn from(value: EncodingFlag) -> Self {
+        match value {
+            EncodingFlag::Auto => rdump_contracts::Encoding::Auto,
+            EncodingFlag::Utf8 => rdump_contracts::Encoding::Utf8,
+            EncodingFlag::Latin1 => rdump_contracts::Encoding::Latin1,
+            EncodingFlag::Utf16le => rdump_contracts::Encoding::Utf16Le,
+            EncodingFlag::Utf16be => rdump_contracts::Encoding::Utf16Be,
+        }
+    }
+}
+
 impl From<ExecutionProfileFlag> for rdump_contracts::ExecutionProfile {
     fn from(value: ExecutionProfileFlag) -> Self {
         match value {
@@ -1347,6 +2062,7 @@ impl From<PathDisplayModeFlag> for rdump_contracts::PathDisplayMode {
             PathDisplayModeFlag::Relative => rdump_contracts::PathDisplayMode::Relative,
             PathDisplayModeFlag::Absolute => rdump_contracts::PathDisplayMode::Absolute,
             PathDisplayModeFlag::RootRelative => rdump_contracts::PathDisplayMode::RootRelative,
+            PathDisplayModeFlag::RelativeToCwd => rdump_contracts::PathDisplayMode::RelativeToCwd,
         }
     }
 }
@@ -1371,17 +2087,20 @@ impl From<rdump_contracts::SqlDialectOption> for CodeSqlDialect {
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 #[cfg_attr(feature = "cli", derive(Parser))]
+// Repeating a flag keeps the last value, so command-line flags override RDUMP_DEFAULT_ARGS.
+#[cfg_attr(feature = "cli", command(args_override_self = true))]
 pub struct SearchArgs {
     /// The query string to search for, using rdump Query Language (RQL).
     ///
     /// RQL supports logical operators (&, |, !), parentheses, and key:value predicates.
     /// Values with spaces must be quoted (e.g., contains:'fn main').
+    /// near(A, B, N) keeps matches of A and B that start within N lines of each other.
     ///
     /// METADATA PREDICATES:
     /// ```text
-    /// ext:<str>               - File extension (e.g., "rs", "toml")
+    /// ext:<str>               - File extension (e.g., "rs", "toml", or "rs,go" for either)
     /// name:<glob>             - File name glob pattern (e.g., "test_*.rs")
     /// path:<str>              - Substring in the full file path
     /// in:<path>               - Directory path to search within
@@ -1398,9 +2117,10 @@ pub struct SearchArgs {
     /// CODE-AWARE PREDICATES for supported languages:
     /// ```text
     /// def:<str>               - A generic definition (class, struct, enum, etc.)
-    /// func:<str>              - A function or method
+    /// func:<str>              - A function or method (`func:.#2` for two parameters)
     /// import:<str>            - An import or use statement
-    /// call:<str>              - A function or method call site
+    /// call:<str>              - A function or method call site (`call:open#2` for two arguments)
+    /// route:<str>             - A web route path (Flask, Express; best-effort)
     /// ```
     ///
     /// GRANULAR DEFINITIONS:
@@ -1410,15 +2130,49 @@ pub struct SearchArgs {
     /// enum:<str>              - An enum definition
     /// interface:<str>         - An interface definition
     /// trait:<str>             - A trait definition
+    /// trait_method:<str>      - A method signature declared in a trait body (Rust)
+    /// interface_method:<str>  - A method signature declared in an interface (Go, TS, Java)
     /// type:<str>              - A type alias
     /// impl:<str>              - An implementation block (e.g., `impl User`)
     /// macro:<str>             - A macro definition
+    /// property:<str>          - A property declaration (C#)
+    /// extends:<str>           - A class's base class (Go: an embedded struct)
+    /// test:<str>              - A test whose name contains the value (heuristic, per language)
+    /// decorator:<str>         - A decorator or attribute by name (PHP `#[Route]`)
     /// ```
     ///
     /// SYNTACTIC CONTENT:
     /// ```text
     /// comment:<str>           - Text inside a comment (e.g., "TODO", "FIXME")
+    /// doc:<str>               - Text inside a doc comment or docstring only
     /// str:<str>               - Text inside a string literal
+    /// str:<kind>:<str>        - Only `raw`, `template` or `doc` strings (docstrings, Rust `///`)
+    /// ```
+    ///
+    /// BUILD FILES (Makefile, Dockerfile):
+    /// ```text
+    /// target:<str>            - A Makefile rule target (e.g., "build")
+    /// from:<str>              - The base image of a Dockerfile `FROM`
+    /// stage:<str>             - A Dockerfile build stage alias (`FROM ... AS <str>`)
+    /// ```
+    ///
+    /// TERRAFORM / HCL (.tf, .tfvars, .hcl):
+    /// ```text
+    /// def:<str>               - A block label (e.g., `aws_instance` or `web`)
+    /// block:<str>             - A block type (e.g., `resource`, `variable`, `module`)
+    /// ```
+    ///
+    /// PROTOBUF / gRPC (.proto):
+    /// ```text
+    /// struct:<str>            - A message (alias `message:`, e.g., `message:User`)
+    /// service:<str>           - A gRPC service definition
+    /// func:<str>              - An `rpc` method inside a service
+    /// ```
+    ///
+    /// MARKDOWN (.md, .markdown):
+    /// ```text
+    /// heading:<str>           - Text of an ATX or Setext heading (e.g., "Installation")
+    /// link:<str>              - A link target (e.g., "docs.rs")
     /// ```
     ///
     /// REACT-SPECIFIC PREDICATES (.jsx, .tsx):
@@ -1427,10 +2181,20 @@ pub struct SearchArgs {
     /// element:<str>           - A JSX element/tag (e.g., `div`, `MyComponent`)
     /// hook:<str>              - A React hook call (e.g., `useState`, `useEffect`)
     /// customhook:<str>        - A custom hook definition (e.g., `useAuth`)
-    /// prop:<str>              - A prop being passed to a JSX element
+    /// prop:<str>              - A prop being passed to a JSX element (`prop:variant=primary`)
+    /// ```
+    ///
+    /// SVELTE / VUE COMPONENTS (.svelte, .vue):
+    /// ```text
+    /// TypeScript predicates run against the `<script>` blocks only
+    /// element:<str>           - A tag in the template markup (e.g., `ul`, `TodoItem`)
+    /// component:<str>         - A PascalCase child component tag (e.g., `TodoItem`)
     /// ```
     #[cfg_attr(feature = "cli", arg(verbatim_doc_comment, name = "QUERY"))]
     pub query: Option<String>,
+    /// Treat QUERY as a literal string to find, like `grep -F`, instead of parsing it as RQL.
+    #[cfg_attr(feature = "cli", arg(long, short = 'F'))]
+    pub fixed_string: bool,
     /// Force the SQL dialect to use for .sql files (overrides auto-detection).
     #[cfg_attr(feature = "cli", arg(long, value_enum, ignore_case = true))]
     pub dialect: Option<SqlDialectFlag>,
@@ -1442,6 +2206,13 @@ pub struct SearchArgs {
     pub root: PathBuf,
     #[cfg_attr(feature = "cli", arg(short, long))]
     pub output: Option<PathBuf>,
+    /// Write each matching file's formatted output to its own file under this directory,
+    /// mirroring the file's path relative to the search root.
+    #[cfg_attr(
+        feature = "cli",
+        arg(long, value_name = "DIR", conflicts_with_all = ["output", "quiet", "replace"])
+    )]
+    pub output_dir: Option<PathBuf>,
     #[cfg_attr(feature = "cli", arg(short, long))]
     pub line_numbers: bool,
     #[cfg_attr(
@@ -1449,12 +2220,37 @@ pub struct SearchArgs {
         arg(long, help = "Alias for --format=cat, useful for piping")
     )]
     pub no_headers: bool,
+    /// Print each file's path once above its hunks, separating hunks with blank lines.
+    #[cfg_attr(feature = "cli", arg(long, overrides_with = "no_heading"))]
+    pub heading: bool,
+    /// Prefix every hunk line with its file's path instead of printing file headers.
+    #[cfg_attr(feature = "cli", arg(long, overrides_with = "heading"))]
+    pub no_heading: bool,
     #[cfg_attr(feature = "cli", arg(long, value_enum, default_value_t = Format::Hunks))]
     pub format: Format,
-    #[cfg_attr(feature = "cli", arg(long))]
+    /// Whether `--format` was given on the command line, so that naming the default
+    /// `hunks` still wins over the config's `[defaults] format`.
+    #[cfg_attr(feature = "cli", arg(skip))]
+    pub format_explicit: bool,
+    /// Print `json` and `lsp-json` output on a single line instead of pretty-printing it.
+    #[cfg_attr(feature = "cli", arg(long, visible_alias = "compact"))]
+    pub json_compact: bool,
+    #[cfg_attr(feature = "cli", arg(long, overrides_with = "ignore"))]
     pub no_ignore: bool,
+    /// Respect ignore files even when the config's `[defaults]` sets `no_ignore`.
+    #[cfg_attr(feature = "cli", arg(long, overrides_with = "no_ignore"))]
+    pub ignore: bool,
+    /// Skip the global gitignore and `.git/info/exclude` but keep repo-local ignore files.
     #[cfg_attr(feature = "cli", arg(long))]
+    pub no_global_ignore: bool,
+    /// Don't apply ignore files from directories above the search root.
+    #[cfg_attr(feature = "cli", arg(long))]
+    pub no_ignore_parent: bool,
+    #[cfg_attr(feature = "cli", arg(long, overrides_with = "no_hidden"))]
     pub hidden: bool,
+    /// Skip hidden files even when the config's `[defaults]` sets `hidden`.
+    #[cfg_attr(feature = "cli", arg(long, overrides_with = "hidden"))]
+    pub no_hidden: bool,
     #[cfg_attr(
         feature = "cli",
         arg(long, value_enum, default_value_t = ColorChoice::Auto, help = "When to use syntax highlighting")
@@ -1464,6 +2260,9 @@ pub struct SearchArgs {
     pub time_format: TimeFormat,
     #[cfg_attr(feature = "cli", arg(long))]
     pub max_depth: Option<usize>,
+    /// Skip files shallower than this depth; files directly under the root are at depth 1.
+    #[cfg_attr(feature = "cli", arg(long, value_name = "N"))]
+    pub min_depth: Option<usize>,
     #[cfg_attr(feature = "cli", arg(long, conflicts_with = "fail_fast"))]
     pub skip_errors: bool,
     #[cfg_attr(feature = "cli", arg(long, conflicts_with = "skip_errors"))]
@@ -1474,6 +2273,15 @@ pub struct SearchArgs {
     pub semantic_budget_ms: Option<u64>,
     #[cfg_attr(feature = "cli", arg(long, value_name = "COUNT"))]
     pub max_semantic_matches_per_file: Option<usize>,
+    /// Stop after N matching files.
+    #[cfg_attr(feature = "cli", arg(long, value_name = "N"))]
+    pub max_matches: Option<usize>,
+    /// Show at most N matches per file, noting how many were left out.
+    #[cfg_attr(feature = "cli", arg(long, value_name = "N"))]
+    pub max_matches_per_file: Option<usize>,
+    /// Cut displayed lines longer than N characters and end them with `…` (hunks and cat only).
+    #[cfg_attr(feature = "cli", arg(long, value_name = "N"))]
+    pub max_columns: Option<usize>,
     #[cfg_attr(feature = "cli", arg(long, value_name = "LANG"))]
     pub language_override: Option<String>,
     #[cfg_attr(
@@ -1499,10 +2307,64 @@ pub struct SearchArgs {
     pub show_suppressed_placeholders: bool,
     #[cfg_attr(feature = "cli", arg(long, value_enum, default_value_t = PathDisplayModeFlag::Relative))]
     pub path_display: PathDisplayModeFlag,
+    /// Print absolute, canonical paths. Shorthand for `--path-display absolute`.
+    #[cfg_attr(feature = "cli", arg(long, conflicts_with = "relative"))]
+    pub absolute: bool,
+    /// Print paths relative to the working directory instead of the search root.
+    /// Shorthand for `--path-display relative-to-cwd`.
+    #[cfg_attr(feature = "cli", arg(long))]
+    pub relative: bool,
     #[cfg_attr(feature = "cli", arg(long, value_enum, default_value_t = LineEndingModeFlag::Preserve))]
     pub line_endings: LineEndingModeFlag,
     #[cfg_attr(feature = "cli", arg(long))]
     pub no_match_text: bool,
+    /// Search files in sorted path order and zero timing stats so repeated runs print
+    /// byte-identical output.
+    #[cfg_attr(feature = "cli", arg(long))]
+    pub deterministic: bool,
+    /// Do not cross into other file systems, such as network mounts below the root
+    /// (like `find -xdev`). Ignored on Windows.
+    #[cfg_attr(feature = "cli", arg(long))]
+    pub one_file_system: bool,
+    /// Number of threads used to walk the tree in parallel (0 picks a count automatically).
+    /// Without it the walk is single-threaded.
+    #[cfg_attr(feature = "cli", arg(long, value_name = "N"))]
+    pub threads: Option<usize>,
+    /// Let `.` in `matches:` patterns match newlines, so a pattern can span lines.
+    #[cfg_attr(feature = "cli", arg(long))]
+    pub regex_dotall: bool,
+    /// Make `^` and `$` in `matches:` patterns match at line boundaries of the whole file,
+    /// so a pattern can span lines.
+    #[cfg_attr(feature = "cli", arg(long))]
+    pub regex_multiline: bool,
+    /// Match `matches:` patterns case-insensitively, as if prefixed with `(?i)`.
+    #[cfg_attr(feature = "cli", arg(long))]
+    pub regex_case_insensitive: bool,
+    /// Match `path:` globs and substrings case-insensitively, like `find -iname`.
+    /// `name:` is always case-insensitive.
+    #[cfg_attr(feature = "cli", arg(long = "iname", alias = "ignore-case-paths"))]
+    pub ignore_case_paths: bool,
+    /// Only match `contains:` and `matches:` terms as whole words, like `grep -w`.
+    #[cfg_attr(
+        feature = "cli",
+        arg(long = "word", short = 'w', alias = "word-regexp")
+    )]
+    pub whole_word: bool,
+    /// Order results by path or by a heuristic relevance score (match count, match density,
+    /// and definitions over comments). Applies to content-oriented formats.
+    #[cfg_attr(feature = "cli", arg(long, value_enum, default_value_t = SortByFlag::Path))]
+    pub sort_by: SortByFlag,
+    /// Split results into sections by language or parent directory, with a `=== name ===`
+    /// header before each. Applies to content-oriented formats.
+    #[cfg_attr(feature = "cli", arg(long, value_enum, default_value_t = GroupByFlag::None))]
+    pub group_by: GroupByFlag,
+    /// Treat files with extension EXT as language LANG for semantic predicates, e.g.
+    /// `--ext-map mjs=js` or `--ext-map rs.in=rust`. Repeatable.
+    #[cfg_attr(
+        feature = "cli",
+        arg(long, value_name = "EXT=LANG", value_parser = parse_ext_mapping)
+    )]
+    pub ext_map: Vec<(String, String)>,
     #[cfg_attr(
         feature = "cli",
         arg(
@@ -1514,9 +2376,127 @@ pub struct SearchArgs {
     )]
     pub context: Option<usize>,
 
+    /// Show LINES of context before matches for --format=hunks, overriding --context.
+    #[cfg_attr(feature = "cli", arg(long, short = 'B', value_name = "LINES"))]
+    pub context_before: Option<usize>,
+
+    /// Show LINES of context after matches for --format=hunks, overriding --context.
+    #[cfg_attr(feature = "cli", arg(long, short = 'A', value_name = "LINES"))]
+    pub context_after: Option<usize>,
+
+    /// Expand each match to its enclosing function, method, or class, so hunks show the whole
+    /// scope instead of `--context` lines. Matches outside any scope are shown as is.
+    #[cfg_attr(feature = "cli", arg(long))]
+    pub expand_to_scope: bool,
+
+    /// Print `[in fn foo]` above each --format=hunks hunk, naming the innermost function,
+    /// class, impl, or module around the match.
+    #[cfg_attr(feature = "cli", arg(long))]
+    pub show_enclosing: bool,
+
+    /// After the search, write a JSON summary (files scanned and matched, total matches,
+    /// elapsed milliseconds, matched files per language) to FILE, whatever the --format.
+    #[cfg_attr(feature = "cli", arg(long, value_name = "FILE"))]
+    pub summary_json: Option<PathBuf>,
+
+    /// Add a `metadata` object (size, lines, mtime, language) to each --format=json result.
+    #[cfg_attr(feature = "cli", arg(long))]
+    pub with_metadata: bool,
+
+    /// Do not read file bodies when printing results. Matching still reads content when the
+    /// query needs it; content-oriented formats print a placeholder per file.
+    #[cfg_attr(feature = "cli", arg(long))]
+    pub no_content: bool,
+
+    /// Print files with identical content once; the first path keeps the dump and the heading
+    /// lists the others. Applies to hunks and markdown output with file headings.
+    #[cfg_attr(feature = "cli", arg(long))]
+    pub dedup_content: bool,
+
     /// List files with metadata instead of dumping content. Alias for --format=find
     #[cfg_attr(feature = "cli", arg(long))]
     pub find: bool,
+
+    /// End each --format=paths entry with a NUL byte instead of a newline, for `xargs -0`.
+    #[cfg_attr(feature = "cli", arg(long = "null", short = '0'))]
+    pub null_separated: bool,
+
+    /// Print nothing; exit 0 if any file matches and 1 otherwise. Invalid queries exit 2.
+    #[cfg_attr(feature = "cli", arg(long, short))]
+    pub quiet: bool,
+
+    /// Suppress per-file warnings on stderr (skipped oversized or secret-like files, parse
+    /// failures), and the RDUMP_LOG_DIAGNOSTICS log. Errors still print.
+    #[cfg_attr(feature = "cli", arg(long, short = 's'))]
+    pub no_messages: bool,
+
+    /// Run the search, then re-run it whenever files under the root change, clearing the
+    /// terminal first. Stop with Ctrl-C.
+    #[cfg_attr(feature = "cli", arg(long, conflicts_with_all = ["quiet", "output_dir"]))]
+    pub watch: bool,
+
+    /// List files in the query's metadata scope (all files if the query has no metadata
+    /// predicates) that do not match the query.
+    #[cfg_attr(feature = "cli", arg(long, short = 'L', alias = "invert-match"))]
+    pub files_without_match: bool,
+
+    /// Only report content and semantic matches that start within this 1-based, inclusive
+    /// line window, e.g. `--lines 100:200`.
+    #[cfg_attr(
+        feature = "cli",
+        arg(long = "lines", value_name = "START:END", value_parser = parse_line_range)
+    )]
+    pub line_range: Option<(usize, usize)>,
+
+    /// Transcode file bytes from this encoding before matching. `auto` detects UTF-16 byte
+    /// order marks and otherwise reads UTF-8.
+    #[cfg_attr(
+        feature = "cli",
+        arg(long, value_enum, default_value_t = EncodingFlag::Auto)
+    )]
+    pub encoding: EncodingFlag,
+
+    /// Preview replacing every match with this text as a unified diff. Files are never
+    /// modified; overlapping matches are applied left to right.
+    #[cfg_attr(feature = "cli", arg(long, value_name = "TEXT"))]
+    pub replace: Option<String>,
+}
+
+#[cfg(feature = "cli")]
+fn parse_line_range(value: &str) -> std::result::Result<(usize, usize), String> {
+    let (start, end) = value
+        .split_once(':')
+        .ok_or_else(|| format!("expected START:END, got `{value}`"))?;
+    let start: usize = start
+        .trim()
+        .parse()
+        .map_err(|_| format!("invalid start line `{start}`"))?;
+    let end: usize = end
+        .trim()
+        .parse()
+        .map_err(|_| format!("invalid end line `{end}`"))?;
+    if start == 0 || end < start {
+        return Err(format!(
+            "line range must satisfy 1 <= START <= END, got `{value}`"
+        ));
+    }
+    Ok((start, end))
+}
+
+#[cfg(feature = "cli")]
+fn parse_ext_mapping(value: &str) -> std::result::Result<(String, String), String> {
+    let (extension, language) = value
+        .split_once('=')
+        .ok_or_else(|| format!("expected EXT=LANG, got `{value}`"))?;
+    let extension = extension
+        .trim()
+        .trim_start_matches('.')
+        .to_ascii_lowercase();
+    let language = language.trim().to_ascii_lowercase();
+    if extension.is_empty() || language.is_empty() {
+        return Err(format!("expected EXT=LANG, got `{value}`"));
+    }
+    Ok((extension, language))
 }
 
 #[derive(Debug)]
@@ -1635,7 +2615,14 @@ pub enum LangAction {
     /// List all supported languages.
     List,
     /// Describe the predicates available for a specific language.
-    Describe { language: String },
+    Describe {
+        language: String,
+        #[cfg_attr(
+            feature = "cli",
+            arg(long, value_enum, default_value_t = LangDescribeFormat::Text)
+        )]
+        format: LangDescribeFormat,
+    },
     /// Inventory the current root by extension and semantic-capable language coverage.
     Inventory {
         #[cfg_attr(feature = "cli", arg(long, default_value = "."))]
@@ -1650,6 +2637,34 @@ pub enum LangAction {
     },
 }
 
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "cli", derive(Parser))]
+pub struct DumpArgs {
+    /// The query to run; the same RQL `rdump search` accepts.
+    #[cfg_attr(feature = "cli", arg(name = "QUERY"))]
+    pub query: Option<String>,
+    #[cfg_attr(feature = "cli", arg(long, short))]
+    pub preset: Vec<String>,
+    #[cfg_attr(feature = "cli", arg(short, long, default_value = "."))]
+    pub root: PathBuf,
+    /// Lines of context to include around each match.
+    #[cfg_attr(
+        feature = "cli",
+        arg(short = 'C', long, value_name = "LINES", default_value_t = 3)
+    )]
+    pub context: usize,
+    /// Keep the whole bundle, manifest and closing note included, within N bytes (about 4
+    /// bytes per token), leaving out the hunks that would not fit.
+    #[cfg_attr(feature = "cli", arg(long, value_name = "N"))]
+    pub max_bytes: Option<usize>,
+    #[cfg_attr(feature = "cli", arg(long))]
+    pub hidden: bool,
+    #[cfg_attr(feature = "cli", arg(long))]
+    pub no_ignore: bool,
+    #[cfg_attr(feature = "cli", arg(short, long))]
+    pub output: Option<PathBuf>,
+}
+
 #[derive(Debug)]
 #[cfg_attr(feature = "cli", derive(Parser))]
 pub struct PresetArgs {
@@ -1676,14 +2691,19 @@ pub enum PresetAction {
     },
 }
 
-#[derive(Debug, Clone, Default, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "cli", derive(ValueEnum))]
+#[serde(rename_all = "kebab-case")]
 pub enum Format {
     /// Show only the specific code blocks ("hunks") that match a semantic query
     #[default]
     Hunks,
     /// One line per file with match counts and content state
     Summary,
+    /// Match counts across all files, grouped by the predicate that produced each hunk
+    Aggregate,
+    /// File and match counts per top-level directory under the root
+    DirSummary,
     /// One line per file with diagnostics and content policy details
     Diagnostics,
     /// One line per match with line/column locations
@@ -1694,6 +2714,12 @@ pub enum Format {
     Markdown,
     /// Machine-readable JSON
     Json,
+    /// JSON with LSP-style positions (0-indexed lines, UTF-16 columns) next to byte ranges
+    LspJson,
+    /// GitHub Actions `::warning` annotations, one per match
+    Github,
+    /// Matched hunks rendered as unified-diff blocks, for tools that ingest diffs
+    Diff,
     /// A simple list of matching file paths
     Paths,
     /// Raw concatenated file content, for piping
@@ -1702,10 +2728,41 @@ pub enum Format {
     Find,
 }
 
+/// Environment variable holding whitespace-separated flags that `rdump search` reads as if
+/// they were typed right after `search`. Flags on the command line come later, so they win.
+#[cfg(feature = "cli")]
+const DEFAULT_ARGS_ENV: &str = "RDUMP_DEFAULT_ARGS";
+
+/// Inserts `defaults` after the `search` subcommand (or its `s` alias) in `args`. Other
+/// subcommands do not take search flags and are left alone.
+#[cfg(feature = "cli")]
+fn args_with_defaults(
+    args: impl IntoIterator<Item = std::ffi::OsString>,
+    defaults: Option<&str>,
+) -> Vec<std::ffi::OsString> {
+    let mut args: Vec<std::ffi::OsString> = args.into_iter().collect();
+    let is_search = args
+        .get(1)
+        .is_some_and(|command| command == "search" || command == "s");
+    if let Some(defaults) = defaults.filter(|_| is_search) {
+        args.splice(
+            2..2,
+            defaults.split_whitespace().map(std::ffi::OsString::from),
+        );
+    }
+    args
+}
+
 // This is the function that will be called from main.rs
 #[cfg(feature = "cli")]
 pub fn run() -> Result<()> {
-    let cli = Cli::parse();
+    let defaults = std::env::var(DEFAULT_ARGS_ENV).ok();
+    let matches = Cli::command()
+        .get_matches_from(args_with_defaults(std::env::args_os(), defaults.as_deref()));
+    let mut cli = Cli::from_arg_matches(&matches).unwrap_or_else(|err| err.exit());
+    if let (Commands::Search(args), Some((_, search))) = (&mut cli.command, matches.subcommand()) {
+        args.format_explicit = search.value_source("format") == Some(ValueSource::CommandLine);
+    }
 
     match cli.command {
         Commands::Search(args) => run_search(args),
@@ -1717,6 +2774,7 @@ pub fn run() -> Result<()> {
             run_lang(action)
         }
         Commands::Preset(args) => run_preset(args.action),
+        Commands::Dump(args) => run_dump(args),
     }
 }
 
@@ -1751,12 +2809,65 @@ mod tests {
             root_relative_path: None,
             resolution: PathResolution::Canonical,
             ranges,
+            hunk_predicates: vec![],
+            hunk_scopes: vec![],
             diagnostics: vec![],
             semantic_skip_reasons: vec![],
             snapshot: None,
+            secret_detector: crate::limits::SecretDetector::Builtin,
+            encoding: Encoding::Auto,
+            context_lines: None,
+            load_content: true,
         }
     }
 
+    #[cfg(feature = "cli")]
+    #[test]
+    fn test_args_with_defaults_inserts_after_search_only() {
+        let args = |list: &[&str]| -> Vec<std::ffi::OsString> {
+            list.iter().map(std::ffi::OsString::from).collect()
+        };
+        assert_eq!(
+            args_with_defaults(
+                args(&["rdump", "search", "ext:rs"]),
+                Some(" --hidden  -C 2 ")
+            ),
+            args(&["rdump", "search", "--hidden", "-C", "2", "ext:rs"])
+        );
+        assert_eq!(
+            args_with_defaults(args(&["rdump", "s", "ext:rs"]), Some("--hidden")),
+            args(&["rdump", "s", "--hidden", "ext:rs"])
+        );
+        assert_eq!(
+            args_with_defaults(args(&["rdump", "lang", "list"]), Some("--hidden")),
+            args(&["rdump", "lang", "list"])
+        );
+        assert_eq!(
+            args_with_defaults(args(&["rdump", "search", "ext:rs"]), None),
+            args(&["rdump", "search", "ext:rs"])
+        );
+    }
+
+    #[test]
+    fn test_relative_path_between_climbs_out_of_base() {
+        assert_eq!(
+            relative_path_between(Path::new("/repo/tools"), Path::new("/repo/src/main.rs")),
+            Some(PathBuf::from("../src/main.rs"))
+        );
+        assert_eq!(
+            relative_path_between(Path::new("/repo"), Path::new("/repo/src/main.rs")),
+            Some(PathBuf::from("src/main.rs"))
+        );
+        assert_eq!(
+            relative_path_between(Path::new("/repo"), Path::new("/repo")),
+            Some(PathBuf::from("."))
+        );
+        assert_eq!(
+            relative_path_between(Path::new("/repo"), Path::new("relative.rs")),
+            None
+        );
+    }
+
     #[test]
     fn test_sql_dialect_flag_conversion_generic() {
         let flag = SqlDialectFlag::Generic;
@@ -1803,9 +2914,35 @@ mod tests {
         assert_eq!(options.root, PathBuf::from("."));
         assert!(options.presets.is_empty());
         assert!(!options.no_ignore);
+        assert!(options.respect_global_gitignore);
+        assert!(options.ignore_parents);
         assert!(!options.hidden);
         assert!(options.max_depth.is_none());
+        assert!(options.min_depth.is_none());
+        assert!(options.max_results.is_none());
+        assert_eq!(options.offset, 0);
+        assert!(options.limit.is_none());
         assert!(options.sql_dialect.is_none());
+        assert!(!options.same_file_system);
+        assert!(options.threads.is_none());
+        assert!(!options.track_predicates);
+    }
+
+    #[test]
+    fn test_search_options_builder_sets_fields_and_appends_presets() {
+        let options = SearchOptions::builder()
+            .root("src")
+            .hidden(true)
+            .preset("rust")
+            .preset("no-tests")
+            .max_depth(Some(2))
+            .build();
+        assert_eq!(options.root, PathBuf::from("src"));
+        assert!(options.hidden);
+        assert_eq!(options.presets, vec!["rust", "no-tests"]);
+        assert_eq!(options.max_depth, Some(2));
+        assert!(!options.no_ignore);
+        assert!(options.respect_global_gitignore);
     }
 
     #[test]
@@ -1835,6 +2972,10 @@ mod tests {
                 end_column: 12,
                 byte_range: 0..12,
                 text: "fn main() {}".to_string(),
+                context_before: Vec::new(),
+                context_after: Vec::new(),
+                predicate: None,
+                enclosing: None,
             }],
         );
         assert!(!result.is_whole_file_match());
@@ -1852,6 +2993,10 @@ mod tests {
                 end_column: 1,
                 byte_range: 0..100,
                 text: "...".to_string(),
+                context_before: Vec::new(),
+                context_after: Vec::new(),
+                predicate: None,
+                enclosing: None,
             }],
         );
         assert_eq!(result.matched_lines(), vec![5, 6, 7]);
@@ -1870,6 +3015,10 @@ mod tests {
                     end_column: 1,
                     byte_range: 0..50,
                     text: "...".to_string(),
+                    context_before: Vec::new(),
+                    context_after: Vec::new(),
+                    predicate: None,
+                    enclosing: None,
                 },
                 Match {
                     start_line: 2,
@@ -1878,6 +3027,10 @@ mod tests {
                     end_column: 1,
                     byte_range: 25..100,
                     text: "...".to_string(),
+                    context_before: Vec::new(),
+                    context_after: Vec::new(),
+                    predicate: None,
+                    enclosing: None,
                 },
             ],
         );
@@ -1903,6 +3056,10 @@ mod tests {
                     end_column: 5,
                     byte_range: 0..5,
                     text: "fn a()".to_string(),
+                    context_before: Vec::new(),
+                    context_after: Vec::new(),
+                    predicate: None,
+                    enclosing: None,
                 },
                 Match {
                     start_line: 3,
@@ -1911,6 +3068,10 @@ mod tests {
                     end_column: 5,
                     byte_range: 10..15,
                     text: "fn b()".to_string(),
+                    context_before: Vec::new(),
+                    context_after: Vec::new(),
+                    predicate: None,
+                    enclosing: None,
                 },
             ],
         );
@@ -1930,6 +3091,10 @@ mod tests {
                     end_column: 1,
                     byte_range: 0..50,
                     text: "...".to_string(),
+                    context_before: Vec::new(),
+                    context_after: Vec::new(),
+                    predicate: None,
+                    enclosing: None,
                 },
                 Match {
                     start_line: 5,
@@ -1938,12 +3103,68 @@ mod tests {
                     end_column: 1,
                     byte_range: 60..70,
                     text: "...".to_string(),
+                    context_before: Vec::new(),
+                    context_after: Vec::new(),
+                    predicate: None,
+                    enclosing: None,
                 },
             ],
         );
         assert_eq!(result.total_lines_matched(), 4);
     }
 
+    fn line_match(line: usize, text: &str) -> Match {
+        Match {
+            start_line: line,
+            end_line: line,
+            start_column: 0,
+            end_column: text.len(),
+            byte_range: 0..text.len(),
+            text: text.to_string(),
+            context_before: Vec::new(),
+            context_after: Vec::new(),
+            predicate: None,
+            enclosing: None,
+        }
+    }
+
+    #[test]
+    fn test_score_prefers_dense_definition_matches() {
+        let content = (1..=20)
+            .map(|i| format!("line {i}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let definition = sample_result(
+            "user.rs",
+            &content,
+            vec![
+                line_match(1, "pub struct User {"),
+                line_match(2, "fn user_name(&self) {"),
+            ],
+        );
+        let comments = sample_result(
+            "notes.rs",
+            &content,
+            vec![
+                line_match(5, "// user: see below"),
+                line_match(9, "# user notes"),
+            ],
+        );
+        let sparse = sample_result(
+            "big.rs",
+            &format!("{content}\n{content}\n{content}"),
+            vec![
+                line_match(1, "pub struct User {"),
+                line_match(2, "fn user_name(&self) {"),
+            ],
+        );
+
+        assert!(definition.score() > comments.score());
+        assert!(definition.score() > sparse.score());
+        assert_eq!(definition.score(), definition.clone().score());
+        assert_eq!(sample_result("whole.rs", &content, vec![]).score(), 0.0);
+    }
+
     #[test]
     fn test_search_result_is_send_sync() {
         fn assert_send_sync<T: Send + Sync>() {}
@@ -1959,6 +3180,10 @@ mod tests {
             end_column: 10,
             byte_range: 0..10,
             text: "fn main()".to_string(),
+            context_before: Vec::new(),
+            context_after: Vec::new(),
+            predicate: None,
+            enclosing: None,
         };
         assert_eq!(m.line_count(), 1);
     }
@@ -1972,6 +3197,10 @@ mod tests {
             end_column: 1,
             byte_range: 0..100,
             text: "...".to_string(),
+            context_before: Vec::new(),
+            context_after: Vec::new(),
+            predicate: None,
+            enclosing: None,
         };
         assert_eq!(m.line_count(), 10);
     }
@@ -1985,6 +3214,10 @@ mod tests {
             end_column: 5,
             byte_range: 0..5,
             text: "hello".to_string(),
+            context_before: Vec::new(),
+            context_after: Vec::new(),
+            predicate: None,
+            enclosing: None,
         };
         let multi = Match {
             start_line: 3,
@@ -1993,6 +3226,10 @@ mod tests {
             end_column: 5,
             byte_range: 0..50,
             text: "line1\nline2\nline3".to_string(),
+            context_before: Vec::new(),
+            context_after: Vec::new(),
+            predicate: None,
+            enclosing: None,
         };
         assert!(!single.is_multiline());
         assert!(multi.is_multiline());
@@ -2007,6 +3244,10 @@ mod tests {
             end_column: 12,
             byte_range: 10..22,
             text: "fn main() {}".to_string(),
+            context_before: Vec::new(),
+            context_after: Vec::new(),
+            predicate: None,
+            enclosing: None,
         };
         assert_eq!(m.byte_len(), 12);
     }
@@ -2020,6 +3261,10 @@ mod tests {
             end_column: 12,
             byte_range: 0..12,
             text: "fn main() {}".to_string(),
+            context_before: Vec::new(),
+            context_after: Vec::new(),
+            predicate: None,
+            enclosing: None,
         };
         let multi = Match {
             start_line: 1,
@@ -2028,6 +3273,10 @@ mod tests {
             end_column: 1,
             byte_range: 0..30,
             text: "fn main() {\n    println!(\"hi\");\n}".to_string(),
+            context_before: Vec::new(),
+            context_after: Vec::new(),
+            predicate: None,
+            enclosing: None,
         };
         let empty = Match {
             start_line: 1,
@@ -2036,6 +3285,10 @@ mod tests {
             end_column: 0,
             byte_range: 0..0,
             text: "".to_string(),
+            context_before: Vec::new(),
+            context_after: Vec::new(),
+            predicate: None,
+            enclosing: None,
         };
         assert_eq!(single.first_line(), "fn main() {}");
         assert_eq!(multi.first_line(), "fn main() {");
@@ -2054,6 +3307,10 @@ mod tests {
             end_column: 5,
             byte_range: 0..5,
             text: "hello".to_string(),
+            context_before: Vec::new(),
+            context_after: Vec::new(),
+            predicate: None,
+            enclosing: None,
         };
         let m2 = Match {
             start_line: 1,
@@ -2062,6 +3319,10 @@ mod tests {
             end_column: 5,
             byte_range: 0..5,
             text: "hello".to_string(),
+            context_before: Vec::new(),
+            context_after: Vec::new(),
+            predicate: None,
+            enclosing: None,
         };
         let m3 = Match {
             start_line: 2,
@@ -2070,6 +3331,10 @@ mod tests {
             end_column: 5,
             byte_range: 0..5,
             text: "hello".to_string(),
+            context_before: Vec::new(),
+            context_after: Vec::new(),
+            predicate: None,
+            enclosing: None,
         };
         assert_eq!(m1, m2);
         assert_ne!(m1, m3);
@@ -2085,7 +3350,7 @@ mod tests {
             end_point: tree_sitter::Point::new(0, 5),
         }];
 
-        let matches = ranges_to_matches(content, &ranges);
+        let matches = ranges_to_matches(content, &ranges, &[], &[], None);
         assert_eq!(matches.len(), 1);
         let m = &matches[0];
         assert_eq!(m.start_line, 1);
@@ -2106,7 +3371,7 @@ mod tests {
             end_point: tree_sitter::Point::new(0, 100),
         }];
 
-        let matches = ranges_to_matches(content, &ranges);
+        let matches = ranges_to_matches(content, &ranges, &[], &[], None);
         assert!(matches.is_empty());
     }
 
@@ -2120,7 +3385,7 @@ mod tests {
             end_point: tree_sitter::Point::new(0, 9),
         }];
 
-        let matches = ranges_to_matches(content, &ranges);
+        let matches = ranges_to_matches(content, &ranges, &[], &[], None);
 
         assert_eq!(matches.len(), 1);
         assert_eq!(matches[0].text, "你好");
@@ -2138,7 +3403,7 @@ mod tests {
             end_point: tree_sitter::Point::new(0, 0),
         }];
 
-        let matches = ranges_to_matches(content, &ranges);
+        let matches = ranges_to_matches(content, &ranges, &[], &[], None);
         assert!(matches.is_empty());
     }
 
@@ -2147,7 +3412,7 @@ mod tests {
         let content = "some content";
         let ranges: Vec<TsRange> = vec![];
 
-        let matches = ranges_to_matches(content, &ranges);
+        let matches = ranges_to_matches(content, &ranges, &[], &[], None);
         assert!(matches.is_empty());
     }
 
@@ -2161,7 +3426,7 @@ mod tests {
             end_point: tree_sitter::Point::new(0, 1),
         }];
 
-        let matches = ranges_to_matches(content, &ranges);
+        let matches = ranges_to_matches(content, &ranges, &[], &[], None);
 
         assert_eq!(matches.len(), 1);
         assert_eq!(matches[0].text, "x");
@@ -2180,7 +3445,7 @@ mod tests {
             end_point: tree_sitter::Point::new(2, 1),
         }];
 
-        let matches = ranges_to_matches(content, &ranges);
+        let matches = ranges_to_matches(content, &ranges, &[], &[], None);
 
         assert_eq!(matches.len(), 1);
         assert_eq!(matches[0].start_line, 1);
@@ -2205,6 +3470,29 @@ mod tests {
         assert_eq!(iter.remaining(), 1);
     }
 
+    #[test]
+    fn test_search_result_iterator_peek_path_does_not_advance() {
+        let results = vec![
+            raw_item(PathBuf::from("file1.txt"), vec![]),
+            raw_item(PathBuf::from("file2.txt"), vec![]),
+        ];
+        let mut iter = SearchResultIterator::new(results, empty_stats(), vec![]);
+
+        assert_eq!(iter.peek_path(), Some(std::path::Path::new("file1.txt")));
+        assert_eq!(iter.peek_path(), Some(std::path::Path::new("file1.txt")));
+        assert_eq!(iter.remaining(), 2);
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+
+        let first = iter.next().unwrap();
+        assert!(first.unwrap_err().to_string().contains("file1.txt"));
+        assert_eq!(iter.remaining(), 1);
+        assert_eq!(iter.peek_path(), Some(std::path::Path::new("file2.txt")));
+        assert_eq!(iter.remaining(), 1);
+        iter.next();
+        assert_eq!(iter.peek_path(), None);
+        assert_eq!(iter.remaining(), 0);
+    }
+
     #[test]
     fn test_search_result_iterator_error_contains_path_and_continues() {
         use std::fs;
@@ -2432,6 +3720,28 @@ mod tests {
         assert_eq!(result.matches[0].end_line, 1);
     }
 
+    #[test]
+    fn test_expand_tilde_only_expands_a_leading_component() {
+        let Some(home) = dirs::home_dir() else {
+            return;
+        };
+        assert_eq!(expand_tilde(Path::new("~")), home);
+        assert_eq!(
+            expand_tilde(Path::new("~/projects/foo")),
+            home.join("projects/foo")
+        );
+        assert_eq!(expand_tilde(Path::new("a/~/b")), PathBuf::from("a/~/b"));
+        assert_eq!(
+            expand_tilde(Path::new("~bob/src")),
+            PathBuf::from("~bob/src")
+        );
+        assert_eq!(expand_tilde_str("src/~backup"), "src/~backup");
+        assert_eq!(
+            expand_tilde_str("~/src"),
+            home.join("src").to_string_lossy()
+        );
+    }
+
     #[test]
     fn test_iterator_continues_after_error() {
         let dir = tempdir().unwrap();
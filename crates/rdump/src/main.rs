@@ -4,6 +4,7 @@ use std::process::ExitCode;
 fn main() -> ExitCode {
     match run() {
         Ok(()) => ExitCode::SUCCESS,
+        Err(err) if err.is::<rdump::commands::search::NoMatches>() => ExitCode::from(1),
         Err(err) => {
             eprintln!("{err}");
             let classified = rdump::request::classify_error_message(&err.to_string());
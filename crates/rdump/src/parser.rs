@@ -16,6 +16,7 @@ lazy_static::lazy_static! {
         PrattParser::new()
             .op(Op::infix(OR, Left))
             .op(Op::infix(AND, Left))
+            .op(Op::infix(ENCLOSES, Left))
     };
 }
 
@@ -38,20 +39,44 @@ pub enum PredicateKey {
     // Granular Definitions
     Class,
     Struct,
+    Field,
     Enum,
+    EnumVariant,
     Interface,
+    InterfaceMethod,
     Trait,
+    TraitMethod,
     Type,
     Impl,
+    Implements,
     Macro,
     Module,
+    Namespace,
     Object,
     Protocol,
+    Template,
+    Service,
     // Syntactic Content
     Comment,
+    Doc,
     Str,
+    StrExact,
     // Usage
     Call,
+    DefnBody,
+    // Build tooling
+    Target,
+    Stage,
+    From,
+    Block,
+    Route,
+    Property,
+    Extends,
+    Test,
+    Decorator,
+    // Documentation
+    Heading,
+    Link,
     // --- React-specific Predicates ---
     Component,
     Element,
@@ -79,18 +104,40 @@ impl AsRef<str> for PredicateKey {
             PredicateKey::Import => "import",
             PredicateKey::Class => "class",
             PredicateKey::Struct => "struct",
+            PredicateKey::Field => "field",
             PredicateKey::Enum => "enum",
+            PredicateKey::EnumVariant => "enum_variant",
             PredicateKey::Interface => "interface",
+            PredicateKey::InterfaceMethod => "interface_method",
             PredicateKey::Trait => "trait",
+            PredicateKey::TraitMethod => "trait_method",
             PredicateKey::Type => "type",
             PredicateKey::Impl => "impl",
+            PredicateKey::Implements => "implements",
             PredicateKey::Macro => "macro",
             PredicateKey::Module => "module",
+            PredicateKey::Namespace => "namespace",
             PredicateKey::Object => "object",
             PredicateKey::Protocol => "protocol",
+            PredicateKey::Template => "template",
+            PredicateKey::Service => "service",
             PredicateKey::Comment => "comment",
+            PredicateKey::Doc => "doc",
             PredicateKey::Str => "str",
+            PredicateKey::StrExact => "str_exact",
             PredicateKey::Call => "call",
+            PredicateKey::DefnBody => "defn_body",
+            PredicateKey::Target => "target",
+            PredicateKey::Stage => "stage",
+            PredicateKey::From => "from",
+            PredicateKey::Block => "block",
+            PredicateKey::Route => "route",
+            PredicateKey::Property => "property",
+            PredicateKey::Extends => "extends",
+            PredicateKey::Test => "test",
+            PredicateKey::Decorator => "decorator",
+            PredicateKey::Heading => "heading",
+            PredicateKey::Link => "link",
             PredicateKey::Component => "component",
             PredicateKey::Element => "element",
             PredicateKey::Hook => "hook",
@@ -121,19 +168,42 @@ impl From<&str> for PredicateKey {
             "func" => Self::Func,
             "import" => Self::Import,
             "class" => Self::Class,
-            "struct" => Self::Struct,
+            "struct" | "message" => Self::Struct,
+            "field" => Self::Field,
             "enum" => Self::Enum,
+            "enum_variant" => Self::EnumVariant,
             "interface" => Self::Interface,
+            "interface_method" => Self::InterfaceMethod,
             "trait" => Self::Trait,
+            "trait_method" => Self::TraitMethod,
             "type" => Self::Type,
             "impl" => Self::Impl,
+            "implements" => Self::Implements,
             "macro" => Self::Macro,
             "module" => Self::Module,
+            "namespace" => Self::Namespace,
             "object" => Self::Object,
             "protocol" => Self::Protocol,
+            "template" => Self::Template,
+            "service" => Self::Service,
             "comment" => Self::Comment,
+            "doc" => Self::Doc,
             "str" => Self::Str,
+            "str_exact" => Self::StrExact,
             "call" => Self::Call,
+            "defn_body" => Self::DefnBody,
+            "func_containing" => Self::DefnBody,
+            "target" => Self::Target,
+            "stage" => Self::Stage,
+            "from" => Self::From,
+            "block" => Self::Block,
+            "route" => Self::Route,
+            "property" => Self::Property,
+            "extends" | "superclass" => Self::Extends,
+            "test" => Self::Test,
+            "decorator" | "annotation" => Self::Decorator,
+            "heading" => Self::Heading,
+            "link" => Self::Link,
             // --- REACT ---
             "component" => Self::Component,
             "element" => Self::Element,
@@ -151,12 +221,16 @@ pub enum AstNode {
     Predicate(PredicateKey, String),
     LogicalOp(LogicalOperator, Box<AstNode>, Box<AstNode>),
     Not(Box<AstNode>),
+    /// `near(A, B, N)`: matches of A and B whose start lines are at most N lines apart.
+    Near(Box<AstNode>, Box<AstNode>, usize),
 }
 
 #[derive(Debug, PartialEq, Clone)]
 pub enum LogicalOperator {
     And,
     Or,
+    /// Keeps right-hand matches that lie inside a left-hand match.
+    Encloses,
 }
 
 impl AstNode {
@@ -169,6 +243,7 @@ impl AstNode {
                 let symbol = match op {
                     LogicalOperator::And => "&",
                     LogicalOperator::Or => "|",
+                    LogicalOperator::Encloses => ">",
                 };
                 format!(
                     "{} {symbol} {}",
@@ -180,6 +255,11 @@ impl AstNode {
                 AstNode::Predicate(_, _) => format!("!{}", inner.to_canonical_string()),
                 _ => format!("!({})", inner.to_canonical_string()),
             },
+            AstNode::Near(left, right, distance) => format!(
+                "near({}, {}, {distance})",
+                left.to_canonical_string(),
+                right.to_canonical_string()
+            ),
         }
     }
 }
@@ -199,6 +279,15 @@ pub fn parse_query(query: &str) -> Result<AstNode> {
     }
 }
 
+/// Builds a query that finds `text` as a literal `contains:` substring, quoting and escaping
+/// it so RQL operators such as `&`, `|` or `!` inside the text are not interpreted.
+pub fn literal_query(text: &str) -> String {
+    format!(
+        "contains:\"{}\"",
+        text.replace('\\', "\\\\").replace('"', "\\\"")
+    )
+}
+
 pub fn normalize_query(query: &str) -> Result<String> {
     Ok(parse_query(query)?.to_canonical_string())
 }
@@ -209,7 +298,7 @@ fn build_ast_from_expression_pairs(pairs: Pairs<Rule>) -> Result<AstNode> {
     if pairs
         .clone()
         .next()
-        .is_some_and(|p| matches!(p.as_rule(), Rule::AND | Rule::OR))
+        .is_some_and(|p| matches!(p.as_rule(), Rule::AND | Rule::OR | Rule::ENCLOSES))
     {
         return Err(anyhow!(
             "Invalid query syntax: query cannot start with an operator."
@@ -219,7 +308,7 @@ fn build_ast_from_expression_pairs(pairs: Pairs<Rule>) -> Result<AstNode> {
     if pairs
         .clone()
         .last()
-        .is_some_and(|p| matches!(p.as_rule(), Rule::AND | Rule::OR))
+        .is_some_and(|p| matches!(p.as_rule(), Rule::AND | Rule::OR | Rule::ENCLOSES))
     {
         return Err(anyhow!(
             "Invalid query syntax: query cannot end with an operator."
@@ -244,6 +333,7 @@ fn build_ast_from_expression_pairs(pairs: Pairs<Rule>) -> Result<AstNode> {
                 let op = match op.as_rule() {
                     Rule::AND => LogicalOperator::And,
                     Rule::OR => LogicalOperator::Or,
+                    Rule::ENCLOSES => LogicalOperator::Encloses,
                     _ => unreachable!(),
                 };
                 Ok(AstNode::LogicalOp(op, Box::new(lhs?), Box::new(rhs?)))
@@ -291,6 +381,16 @@ fn build_ast_from_term(pair: Pair<Rule>) -> Result<AstNode> {
             }
         }
         Rule::factor => build_ast_from_term(pair.into_inner().next().unwrap()),
+        Rule::near => {
+            let mut inner = pair.into_inner();
+            let left = build_ast_from_expression_pairs(inner.next().unwrap().into_inner())?;
+            let right = build_ast_from_expression_pairs(inner.next().unwrap().into_inner())?;
+            let distance = inner.next().unwrap().as_str();
+            let distance = distance
+                .parse::<usize>()
+                .map_err(|_| anyhow!("Invalid near() line distance: '{distance}'"))?;
+            Ok(AstNode::Near(Box::new(left), Box::new(right), distance))
+        }
         _ => Err(anyhow!("Unknown primary rule: {:?}", pair.as_rule())),
     }
 }
@@ -331,7 +431,8 @@ fn quote_if_needed(value: &str) -> String {
 
 fn wrap_if_needed(node: &AstNode, parent: &LogicalOperator) -> String {
     match (parent, node) {
-        (LogicalOperator::And, AstNode::LogicalOp(LogicalOperator::Or, _, _)) => {
+        (LogicalOperator::And, AstNode::LogicalOp(LogicalOperator::Or, _, _))
+        | (LogicalOperator::Encloses, AstNode::LogicalOp(_, _, _)) => {
             format!("({})", node.to_canonical_string())
         }
         _ => node.to_canonical_string(),
@@ -359,6 +460,14 @@ mod tests {
         assert_eq!(ast, *predicate(PredicateKey::Name, "foo bar"));
     }
 
+    #[test]
+    fn test_literal_query_escapes_operators_and_quotes() {
+        for text in ["a & b", "!(x | y)", r#"say "hi""#, r"C:\dir\", "'"] {
+            let ast = parse_query(&literal_query(text)).unwrap();
+            assert_eq!(ast, *predicate(PredicateKey::Contains, text), "{text}");
+        }
+    }
+
     #[test]
     fn test_parse_logical_and() {
         let ast = parse_query("ext:rs & name:\"foo\"").unwrap();
@@ -567,6 +676,100 @@ mod tests {
         assert_eq!(ast, final_ast);
     }
 
+    #[test]
+    fn test_parse_near_call() {
+        let ast = parse_query("ext:rs & near(comment:TODO, func:. | call:todo, 5)").unwrap();
+        let near = AstNode::Near(
+            predicate(PredicateKey::Comment, "TODO"),
+            Box::new(AstNode::LogicalOp(
+                LogicalOperator::Or,
+                predicate(PredicateKey::Func, "."),
+                predicate(PredicateKey::Call, "todo"),
+            )),
+            5,
+        );
+        assert_eq!(
+            ast,
+            AstNode::LogicalOp(
+                LogicalOperator::And,
+                predicate(PredicateKey::Ext, "rs"),
+                Box::new(near)
+            )
+        );
+        assert_eq!(
+            ast.to_canonical_string(),
+            "ext:rs & near(comment:TODO, func:. | call:todo, 5)"
+        );
+        // Commas not followed by whitespace stay part of the value.
+        assert_eq!(
+            parse_query("NEAR(in:src,lib , contains:x, 0)").unwrap(),
+            AstNode::Near(
+                predicate(PredicateKey::In, "src,lib"),
+                predicate(PredicateKey::Contains, "x"),
+                0
+            )
+        );
+        // Bare commas separate the arguments too.
+        assert_eq!(
+            parse_query("near(comment:TODO,func:.,5)").unwrap(),
+            AstNode::Near(
+                predicate(PredicateKey::Comment, "TODO"),
+                predicate(PredicateKey::Func, "."),
+                5
+            )
+        );
+        assert_eq!(
+            parse_query("near(ext:rs & contains:a,(in:'src,lib'),2) & in:x,y").unwrap(),
+            AstNode::LogicalOp(
+                LogicalOperator::And,
+                Box::new(AstNode::Near(
+                    Box::new(AstNode::LogicalOp(
+                        LogicalOperator::And,
+                        predicate(PredicateKey::Ext, "rs"),
+                        predicate(PredicateKey::Contains, "a"),
+                    )),
+                    predicate(PredicateKey::In, "src,lib"),
+                    2
+                )),
+                predicate(PredicateKey::In, "x,y"),
+            )
+        );
+        assert!(parse_query("near(comment:TODO, func:.)").is_err());
+        assert!(parse_query("near(comment:TODO, func:., -1)").is_err());
+    }
+
+    #[test]
+    fn test_parse_encloses_binds_tighter_than_and() {
+        let ast = parse_query("ext:rs & func:main > call:unwrap").unwrap();
+        let encloses = AstNode::LogicalOp(
+            LogicalOperator::Encloses,
+            predicate(PredicateKey::Func, "main"),
+            predicate(PredicateKey::Call, "unwrap"),
+        );
+        let final_ast = AstNode::LogicalOp(
+            LogicalOperator::And,
+            predicate(PredicateKey::Ext, "rs"),
+            Box::new(encloses),
+        );
+        assert_eq!(ast, final_ast);
+        assert_eq!(
+            ast.to_canonical_string(),
+            "ext:rs & func:main > call:unwrap"
+        );
+        assert_eq!(
+            parse_query("(func:a | func:b) > call:c")
+                .unwrap()
+                .to_canonical_string(),
+            "(func:a | func:b) > call:c"
+        );
+        assert!(parse_query("func:main >").is_err());
+        // Without surrounding whitespace `>` stays part of the value.
+        assert_eq!(
+            parse_query("size:>10kb").unwrap(),
+            *predicate(PredicateKey::Size, ">10kb")
+        );
+    }
+
     #[test]
     fn test_parse_unknown_predicate() {
         let ast = parse_query("unknown:predicate").unwrap();
@@ -660,15 +863,37 @@ mod tests {
         assert_eq!(PredicateKey::Import.as_ref(), "import");
         assert_eq!(PredicateKey::Class.as_ref(), "class");
         assert_eq!(PredicateKey::Struct.as_ref(), "struct");
+        assert_eq!(PredicateKey::Field.as_ref(), "field");
         assert_eq!(PredicateKey::Enum.as_ref(), "enum");
+        assert_eq!(PredicateKey::EnumVariant.as_ref(), "enum_variant");
         assert_eq!(PredicateKey::Interface.as_ref(), "interface");
+        assert_eq!(PredicateKey::InterfaceMethod.as_ref(), "interface_method");
         assert_eq!(PredicateKey::Trait.as_ref(), "trait");
+        assert_eq!(PredicateKey::TraitMethod.as_ref(), "trait_method");
         assert_eq!(PredicateKey::Type.as_ref(), "type");
         assert_eq!(PredicateKey::Impl.as_ref(), "impl");
+        assert_eq!(PredicateKey::Implements.as_ref(), "implements");
         assert_eq!(PredicateKey::Macro.as_ref(), "macro");
+        assert_eq!(PredicateKey::Namespace.as_ref(), "namespace");
+        assert_eq!(PredicateKey::Template.as_ref(), "template");
         assert_eq!(PredicateKey::Comment.as_ref(), "comment");
+        assert_eq!(PredicateKey::Doc.as_ref(), "doc");
         assert_eq!(PredicateKey::Str.as_ref(), "str");
+        assert_eq!(PredicateKey::StrExact.as_ref(), "str_exact");
         assert_eq!(PredicateKey::Call.as_ref(), "call");
+        assert_eq!(PredicateKey::DefnBody.as_ref(), "defn_body");
+        assert_eq!(PredicateKey::Target.as_ref(), "target");
+        assert_eq!(PredicateKey::Stage.as_ref(), "stage");
+        assert_eq!(PredicateKey::From.as_ref(), "from");
+        assert_eq!(PredicateKey::Block.as_ref(), "block");
+        assert_eq!(PredicateKey::Service.as_ref(), "service");
+        assert_eq!(PredicateKey::Route.as_ref(), "route");
+        assert_eq!(PredicateKey::Property.as_ref(), "property");
+        assert_eq!(PredicateKey::Extends.as_ref(), "extends");
+        assert_eq!(PredicateKey::Test.as_ref(), "test");
+        assert_eq!(PredicateKey::Decorator.as_ref(), "decorator");
+        assert_eq!(PredicateKey::Heading.as_ref(), "heading");
+        assert_eq!(PredicateKey::Link.as_ref(), "link");
         assert_eq!(PredicateKey::Component.as_ref(), "component");
         assert_eq!(PredicateKey::Element.as_ref(), "element");
         assert_eq!(PredicateKey::Hook.as_ref(), "hook");
@@ -4,7 +4,10 @@ use std::collections::{BTreeMap, BTreeSet, HashSet};
 
 use crate::config::{self, ConfigDiagnostic, PresetContribution};
 use crate::parser::{self, AstNode, PredicateKey};
-use crate::predicates::helpers::{parse_modified_predicate, parse_size_predicate};
+use crate::predicates::helpers::{
+    parse_modified_predicate, parse_modified_range, parse_size_predicate, parse_size_range,
+    ParsedTimeValue,
+};
 use crate::predicates::{
     content_predicate_keys, metadata_predicate_keys, react_predicate_keys, semantic_predicate_keys,
 };
@@ -36,10 +39,28 @@ pub struct QueryPreflight {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "kind", rename_all = "snake_case")]
 pub enum StableAstNode {
-    Predicate { key: String, value: String },
-    Not { child: Box<StableAstNode> },
-    And { children: Vec<StableAstNode> },
-    Or { children: Vec<StableAstNode> },
+    Predicate {
+        key: String,
+        value: String,
+    },
+    Not {
+        child: Box<StableAstNode>,
+    },
+    And {
+        children: Vec<StableAstNode>,
+    },
+    Or {
+        children: Vec<StableAstNode>,
+    },
+    Encloses {
+        outer: Box<StableAstNode>,
+        inner: Box<StableAstNode>,
+    },
+    Near {
+        left: Box<StableAstNode>,
+        right: Box<StableAstNode>,
+        distance: usize,
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -51,10 +72,18 @@ pub enum PredicateValuePlan {
         unit: String,
         target_size_bytes: u64,
     },
+    SizeRange {
+        min_size_bytes: Option<u64>,
+        max_size_bytes: Option<u64>,
+    },
     Modified {
         operator: String,
         value: serde_json::Value,
     },
+    ModifiedRange {
+        from: Option<serde_json::Value>,
+        to: Option<serde_json::Value>,
+    },
     Literal {
         normalized: String,
     },
@@ -258,6 +287,11 @@ pub fn optimize_ast(ast: AstNode) -> AstNode {
             Box::new(optimize_ast(*right)),
         ),
         AstNode::Not(inner) => AstNode::Not(Box::new(optimize_ast(*inner))),
+        AstNode::Near(left, right, distance) => AstNode::Near(
+            Box::new(optimize_ast(*left)),
+            Box::new(optimize_ast(*right)),
+            distance,
+        ),
         predicate @ AstNode::Predicate(_, _) => predicate,
     }
 }
@@ -269,6 +303,19 @@ pub fn simplify_ast(ast: AstNode) -> AstNode {
             AstNode::Not(grandchild) => *grandchild,
             other => AstNode::Not(Box::new(other)),
         },
+        AstNode::Near(left, right, distance) => AstNode::Near(
+            Box::new(simplify_ast(*left)),
+            Box::new(simplify_ast(*right)),
+            distance,
+        ),
+        // Containment is neither associative nor idempotent, so keep its shape as written.
+        AstNode::LogicalOp(crate::parser::LogicalOperator::Encloses, outer, inner) => {
+            AstNode::LogicalOp(
+                crate::parser::LogicalOperator::Encloses,
+                Box::new(simplify_ast(*outer)),
+                Box::new(simplify_ast(*inner)),
+            )
+        }
         AstNode::LogicalOp(op, left, right) => {
             let left = simplify_ast(*left);
             let right = simplify_ast(*right);
@@ -355,9 +402,14 @@ pub fn repo_language_inventory_with_runtime(
             root,
             display_root: options.root.clone(),
             no_ignore: options.no_ignore,
+            respect_global_gitignore: options.respect_global_gitignore,
+            ignore_parents: options.ignore_parents,
             hidden: options.hidden,
             max_depth: options.max_depth,
+            min_depth: options.min_depth,
             ignore_debug: false,
+            same_file_system: options.same_file_system,
+            threads: options.threads,
         })
     else {
         return Vec::new();
@@ -414,23 +466,34 @@ fn collect_predicate_plans(node: &AstNode, plans: &mut Vec<PredicatePlan>) -> Re
                 "semantic"
             };
             let value_plan = match key {
-                PredicateKey::Size => {
-                    let parsed = parse_size_predicate(value)?;
-                    PredicateValuePlan::Size {
-                        operator: parsed.operator.as_str().to_string(),
-                        numeric_value: parsed.numeric_value,
-                        unit: parsed.unit,
-                        target_size_bytes: parsed.target_size_bytes,
+                PredicateKey::Size => match parse_size_range(value)? {
+                    Some(range) => PredicateValuePlan::SizeRange {
+                        min_size_bytes: range.min_size_bytes,
+                        max_size_bytes: range.max_size_bytes,
+                    },
+                    None => {
+                        let parsed = parse_size_predicate(value)?;
+                        PredicateValuePlan::Size {
+                            operator: parsed.operator.as_str().to_string(),
+                            numeric_value: parsed.numeric_value,
+                            unit: parsed.unit,
+                            target_size_bytes: parsed.target_size_bytes,
+                        }
                     }
-                }
-                PredicateKey::Modified => {
-                    let parsed = parse_modified_predicate(value)?;
-                    PredicateValuePlan::Modified {
-                        operator: parsed.operator.as_str().to_string(),
-                        value: serde_json::to_value(parsed.value)
-                            .expect("parsed modified predicate should serialize"),
+                },
+                PredicateKey::Modified => match parse_modified_range(value)? {
+                    Some(range) => PredicateValuePlan::ModifiedRange {
+                        from: range.from.map(time_value_json),
+                        to: range.to.map(time_value_json),
+                    },
+                    None => {
+                        let parsed = parse_modified_predicate(value)?;
+                        PredicateValuePlan::Modified {
+                            operator: parsed.operator.as_str().to_string(),
+                            value: time_value_json(parsed.value),
+                        }
                     }
-                }
+                },
                 _ => PredicateValuePlan::Literal {
                     normalized: value.to_string(),
                 },
@@ -443,7 +506,7 @@ fn collect_predicate_plans(node: &AstNode, plans: &mut Vec<PredicatePlan>) -> Re
                 value_plan,
             });
         }
-        AstNode::LogicalOp(_, left, right) => {
+        AstNode::LogicalOp(_, left, right) | AstNode::Near(left, right, _) => {
             collect_predicate_plans(left, plans)?;
             collect_predicate_plans(right, plans)?;
         }
@@ -453,10 +516,14 @@ fn collect_predicate_plans(node: &AstNode, plans: &mut Vec<PredicatePlan>) -> Re
     Ok(())
 }
 
+fn time_value_json(value: ParsedTimeValue) -> serde_json::Value {
+    serde_json::to_value(value).expect("parsed modified predicate should serialize")
+}
+
 fn collect_predicates(node: &AstNode, keys: &mut Vec<PredicateKey>) {
     match node {
         AstNode::Predicate(key, _) => keys.push(key.clone()),
-        AstNode::LogicalOp(_, left, right) => {
+        AstNode::LogicalOp(_, left, right) | AstNode::Near(left, right, _) => {
             collect_predicates(left, keys);
             collect_predicates(right, keys);
         }
@@ -498,7 +565,7 @@ fn lint_and_groups(node: &AstNode, warnings: &mut Vec<String>) {
             collect_and_predicates(node, &mut group);
             lint_conjunction(&group, warnings);
         }
-        AstNode::LogicalOp(_, left, right) => {
+        AstNode::LogicalOp(_, left, right) | AstNode::Near(left, right, _) => {
             lint_and_groups(left, warnings);
             lint_and_groups(right, warnings);
         }
@@ -536,7 +603,13 @@ fn lint_conjunction(predicates: &[(&PredicateKey, &str)], warnings: &mut Vec<Str
         }
     }
 
-    if exts.len() > 1 {
+    // One `ext:rs,toml` list is a single predicate; separate ones only conflict when no
+    // extension appears in every list.
+    let shared_extensions = exts
+        .iter()
+        .map(|value| ext_list(value))
+        .reduce(|shared, list| shared.intersection(&list).cloned().collect());
+    if exts.len() > 1 && shared_extensions.is_some_and(|shared| shared.is_empty()) {
         warnings.push(format!(
             "Conjunction contains multiple ext: predicates ({}) which likely yields zero results unless files have compound extensions.",
             exts.into_iter().collect::<Vec<_>>().join(", ")
@@ -569,6 +642,15 @@ fn lint_conjunction(predicates: &[(&PredicateKey, &str)], warnings: &mut Vec<Str
     }
 }
 
+/// The extensions an `ext:` value accepts, lowercased, as the ext evaluator compares them.
+fn ext_list(value: &str) -> BTreeSet<String> {
+    value
+        .split(',')
+        .map(|ext| ext.trim().to_ascii_lowercase())
+        .filter(|ext| !ext.is_empty())
+        .collect()
+}
+
 fn contains_deprecated_content_alias(query: &str) -> bool {
     let lower = query.to_ascii_lowercase();
     lower.contains("content:")
@@ -612,6 +694,8 @@ fn predicate_cost(node: &AstNode) -> usize {
         AstNode::Not(inner) => predicate_cost(inner) + 5,
         AstNode::LogicalOp(crate::parser::LogicalOperator::Or, _, _) => 40,
         AstNode::LogicalOp(crate::parser::LogicalOperator::And, _, _) => 15,
+        AstNode::LogicalOp(crate::parser::LogicalOperator::Encloses, _, _) => 20,
+        AstNode::Near(_, _, _) => 20,
     }
 }
 
@@ -634,6 +718,17 @@ fn stable_ast(node: &AstNode) -> StableAstNode {
             collect_stable_children(node, crate::parser::LogicalOperator::Or, &mut children);
             StableAstNode::Or { children }
         }
+        AstNode::LogicalOp(crate::parser::LogicalOperator::Encloses, outer, inner) => {
+            StableAstNode::Encloses {
+                outer: Box::new(stable_ast(outer)),
+                inner: Box::new(stable_ast(inner)),
+            }
+        }
+        AstNode::Near(left, right, distance) => StableAstNode::Near {
+            left: Box::new(stable_ast(left)),
+            right: Box::new(stable_ast(right)),
+            distance: *distance,
+        },
     }
 }
 
@@ -687,7 +782,7 @@ fn contains_semantic_predicates(ast: &AstNode) -> bool {
         AstNode::Predicate(key, _) => {
             semantic_predicate_keys().contains(key) || react_predicate_keys().contains(key)
         }
-        AstNode::LogicalOp(_, left, right) => {
+        AstNode::LogicalOp(_, left, right) | AstNode::Near(left, right, _) => {
             contains_semantic_predicates(left) || contains_semantic_predicates(right)
         }
         AstNode::Not(inner) => contains_semantic_predicates(inner),
@@ -818,7 +913,7 @@ mod tests {
             [
                 PathBuf::from("src/lib.rs"),
                 PathBuf::from("src/main.rs"),
-                PathBuf::from("README.md"),
+                PathBuf::from("NOTES.txt"),
             ],
         )));
         let options = SearchOptions {
@@ -828,12 +923,12 @@ mod tests {
 
         let inventory = repo_language_inventory_with_runtime(&runtime, &options);
         assert_eq!(inventory.len(), 2);
-        assert_eq!(inventory[0].extension, "md");
-        assert_eq!(inventory[0].files, 1);
-        assert_eq!(inventory[0].semantic_profile, None);
-        assert_eq!(inventory[1].extension, "rs");
-        assert_eq!(inventory[1].files, 2);
-        assert_eq!(inventory[1].semantic_profile.as_deref(), Some("rs"));
+        assert_eq!(inventory[0].extension, "rs");
+        assert_eq!(inventory[0].files, 2);
+        assert_eq!(inventory[0].semantic_profile.as_deref(), Some("rs"));
+        assert_eq!(inventory[1].extension, "txt");
+        assert_eq!(inventory[1].files, 1);
+        assert_eq!(inventory[1].semantic_profile, None);
 
         let explanation = explain_query_with_runtime(&runtime, "func:main", &options).unwrap();
         assert!(explanation.preflight.semantic_predicates_present);
@@ -853,6 +948,23 @@ mod tests {
         assert!(explanation.stable_ast_json.contains("\"kind\": \"and\""));
     }
 
+    #[test]
+    fn lint_query_warns_only_for_disjoint_ext_predicates() {
+        let options = SearchOptions::default();
+        let ext_warnings = |query: &str| {
+            lint_query(query, &options)
+                .unwrap()
+                .into_iter()
+                .filter(|warning| warning.contains("multiple ext:"))
+                .count()
+        };
+
+        assert_eq!(ext_warnings("ext:rs,toml & contains:serde"), 0);
+        assert_eq!(ext_warnings("ext:rs,toml & ext:TOML & contains:serde"), 0);
+        assert_eq!(ext_warnings("ext:rs & ext:toml & contains:serde"), 1);
+        assert_eq!(ext_warnings("ext:rs,go & ext:toml,md & contains:serde"), 1);
+    }
+
     #[test]
     fn simplify_query_removes_duplicate_clauses() {
         let simplified = simplify_query("ext:rs & func:main & ext:rs").unwrap();
@@ -5,7 +5,7 @@ use anyhow::Result;
 use regex::Regex;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
-use tree_sitter::{Query, QueryCursor, StreamingIterator, Tree};
+use tree_sitter::{Node, Query, QueryCursor, Range, StreamingIterator, Tree};
 
 pub(super) struct ExecutionPlan {
     pub profile_key: String,
@@ -26,7 +26,16 @@ pub(super) fn resolve_execution_plan(
             telemetry.record_tree_cache_miss();
         }
     }
-    match context.get_tree(&profile_key, profile.language.clone()) {
+    let included_ranges = if context.has_tree_for(&profile_key) {
+        None
+    } else {
+        profiles::embedded_ranges(&profile_key, context.get_content()?)
+    };
+    let tree = match included_ranges {
+        Some(ranges) => context.get_tree_in_ranges(&profile_key, profile.language.clone(), &ranges),
+        None => context.get_tree(&profile_key, profile.language.clone()),
+    };
+    match tree {
         Ok(tree) => {
             if profile_key != SqlDialect::Generic.key()
                 && profile_key.starts_with("sql")
@@ -66,6 +75,25 @@ pub(super) fn execute_captures(
         cursor.set_match_limit(limit);
     }
     let mut ranges = Vec::new();
+    let (string_kind, value) = if *key == PredicateKey::Str {
+        split_string_kind(value)
+    } else {
+        (None, value)
+    };
+    let (arity, value) = if matches!(key, PredicateKey::Func | PredicateKey::Call) {
+        split_arity(value)
+    } else {
+        (None, value)
+    };
+    let (empty_body, value) = if *key == PredicateKey::Struct {
+        split_empty_body(value)
+    } else {
+        (false, value)
+    };
+    let (value, prop_value) = match value.split_once('=') {
+        Some((name, prop_value)) if *key == PredicateKey::Prop => (name, Some(prop_value)),
+        _ => (value, None),
+    };
     let source = content.as_bytes();
     let mut captures = cursor.matches(query, tree.root_node(), source);
     let started = Instant::now();
@@ -78,6 +106,14 @@ pub(super) fn execute_captures(
             }
             break;
         }
+        if *key == PredicateKey::Str && !has_string_kind(query, matched.captures, string_kind) {
+            continue;
+        }
+        if let Some(prop_value) = prop_value {
+            if !has_prop_value(query, matched.captures, source, prop_value, settings) {
+                continue;
+            }
+        }
         for capture in matched.captures {
             let capture_name = &query.capture_names()[capture.index as usize];
             if *capture_name != "match" {
@@ -85,8 +121,38 @@ pub(super) fn execute_captures(
             }
 
             let captured_node = capture.node;
-            let captured_text = captured_node.utf8_text(source)?;
-            if is_capture_match(key, value, captured_text, settings) {
+            if let Some(arity) = arity {
+                let count = if *key == PredicateKey::Call {
+                    argument_count(captured_node)
+                } else {
+                    parameter_count(captured_node)
+                };
+                if count != Some(arity) {
+                    continue;
+                }
+            }
+            if empty_body && !has_empty_body(captured_node) {
+                continue;
+            }
+            let captured_text = if *key == PredicateKey::Impl {
+                impl_type_name(captured_node, source)?
+            } else {
+                captured_node.utf8_text(source)?
+            };
+            let is_match = if *key == PredicateKey::Call && is_qualified_call_value(value, settings)
+            {
+                qualified_callee_text(captured_node, source)
+                    .is_some_and(|callee| match_text(&callee, value, settings, false))
+            } else {
+                is_capture_match(key, value, captured_text, settings)
+            };
+            // A docstring is also matched by the plain string pattern, and an optional prop
+            // value can yield the same attribute twice, so skip the repeat.
+            if is_capture_site(key, captured_node)
+                && is_match
+                && !(matches!(key, PredicateKey::Str | PredicateKey::Prop)
+                    && ranges.contains(&captured_node.range()))
+            {
                 ranges.push(captured_node.range());
             }
         }
@@ -95,6 +161,303 @@ pub(super) fn execute_captures(
     Ok(MatchResult::Hunks(ranges))
 }
 
+/// Splits a `str:` value into an optional string kind and the text to find, so `template:Hi`
+/// selects template literals containing `Hi`. Values without a known kind prefix are returned
+/// unchanged and match any string.
+fn split_string_kind(value: &str) -> (Option<&str>, &str) {
+    match value.split_once(':') {
+        Some((kind, rest)) if STRING_KINDS.contains(&kind) => (Some(kind), rest),
+        _ => (None, value),
+    }
+}
+
+/// Kinds selectable with `str:<kind>:`. Profile `Str` queries tag a string with its kind by
+/// capturing the same node as `@_<kind>` next to `@match`, e.g. `(template_string) @match
+/// @_template`. Matches tagged `@_comment` (such as Rust `///` doc comments) are only returned
+/// for an explicit `str:doc:`, so a plain `str:` keeps matching string literals alone.
+const STRING_KINDS: &[&str] = &["raw", "template", "doc"];
+
+fn has_string_kind(
+    query: &Query,
+    captures: &[tree_sitter::QueryCapture<'_>],
+    kind: Option<&str>,
+) -> bool {
+    let mut tags = captures
+        .iter()
+        .filter_map(|capture| query.capture_names()[capture.index as usize].strip_prefix('_'));
+    match kind {
+        Some(kind) => tags.any(|tag| tag == kind),
+        None => !tags.any(|tag| tag == "comment"),
+    }
+}
+
+/// Checks the `@_value` capture of a JSX attribute against the value of `prop:name=value`.
+/// String values are compared without quotes and expression values without braces, so
+/// `variant=primary` matches both `variant="primary"` and `variant={"primary"}`. Attributes
+/// without a value never match.
+fn has_prop_value(
+    query: &Query,
+    captures: &[tree_sitter::QueryCapture<'_>],
+    source: &[u8],
+    expected: &str,
+    settings: &CodeAwareSettings,
+) -> bool {
+    captures
+        .iter()
+        .filter(|capture| query.capture_names()[capture.index as usize] == "_value")
+        .filter_map(|capture| capture.node.utf8_text(source).ok())
+        .any(|text| {
+            let text = text
+                .strip_prefix('{')
+                .and_then(|text| text.strip_suffix('}'))
+                .map_or(text, str::trim);
+            match_text(strip_string_quotes(text), expected, settings, false)
+        })
+}
+
+/// Splits a `func:` or `call:` value into the name to find and an optional parameter or
+/// argument count, so `.#2` selects functions taking exactly two parameters and `open#2` calls
+/// passing two arguments. A `#` not followed by digits is part of the name.
+fn split_arity(value: &str) -> (Option<usize>, &str) {
+    match value.rsplit_once('#') {
+        Some((name, count)) if !count.is_empty() && count.bytes().all(|b| b.is_ascii_digit()) => {
+            (count.parse().ok(), name)
+        }
+        _ => (None, value),
+    }
+}
+
+/// Counts the parameters of the function named by `name_node`, or `None` when its definition
+/// has no `parameters` field. Receivers (`self`, Go method receivers) count only where the
+/// grammar lists them as parameters, and a Go declaration such as `a, b int` counts each name.
+fn parameter_count(name_node: Node<'_>) -> Option<usize> {
+    let parameters = name_node.parent()?.child_by_field_name("parameters")?;
+    let mut cursor = parameters.walk();
+    let count = parameters
+        .named_children(&mut cursor)
+        .filter(|parameter| {
+            let kind = parameter.kind();
+            !kind.contains("comment") && !kind.ends_with("_separator") && kind != "attribute_item"
+        })
+        .map(|parameter| {
+            if parameter.kind() == "parameter_declaration" {
+                let mut names = parameter.walk();
+                parameter
+                    .children_by_field_name("name", &mut names)
+                    .count()
+                    .max(1)
+            } else {
+                1
+            }
+        })
+        .sum();
+    Some(count)
+}
+
+/// Splits a `struct:` value into the name to find and whether a trailing `{}` asks for structs
+/// with an empty body, so `struct:Marker{}` selects `struct Marker;` but not a `Marker` with
+/// fields.
+fn split_empty_body(value: &str) -> (bool, &str) {
+    match value.strip_suffix("{}") {
+        Some(name) if !name.is_empty() => (true, name),
+        _ => (false, value),
+    }
+}
+
+/// Reports whether the definition named by `name_node` declares no fields. Rust keeps fields in
+/// the struct's `body` and Go in the field list of its `struct_type`; a Rust unit struct has no
+/// body at all and counts as empty, as do `struct Marker {}` and `struct Marker();`.
+fn has_empty_body(name_node: Node<'_>) -> bool {
+    let Some(definition) = name_node.parent() else {
+        return false;
+    };
+    let body = definition.child_by_field_name("body").or_else(|| {
+        definition
+            .child_by_field_name("type")
+            .and_then(|ty| ty.named_child(0))
+    });
+    let Some(body) = body else {
+        return definition.kind() == "struct_item";
+    };
+    let mut cursor = body.walk();
+    let has_fields = body
+        .named_children(&mut cursor)
+        .any(|field| !field.kind().contains("comment") && field.kind() != "attribute_item");
+    !has_fields
+}
+
+/// Counts the arguments of the call whose callee ends at `node`, or `None` when the call has no
+/// `arguments` field (Rust macro invocations, for example).
+fn argument_count(node: Node<'_>) -> Option<usize> {
+    let mut call = node.parent()?;
+    while !(call.kind().contains("call")
+        || call.kind().contains("invocation")
+        || call.kind() == "new_expression")
+    {
+        call = call.parent()?;
+    }
+    let arguments = call.child_by_field_name("arguments")?;
+    let mut cursor = arguments.walk();
+    let count = arguments
+        .named_children(&mut cursor)
+        .filter(|argument| !argument.kind().contains("comment"))
+        .count();
+    Some(count)
+}
+
+/// Runs `definition_query` and keeps the enclosing definition of each capture whose body
+/// contains a `call_query` capture matching `value`. Hunks span the whole definition.
+pub(super) fn execute_definitions_containing(
+    tree: &Tree,
+    content: &Arc<str>,
+    definition_query: &Query,
+    call_query: &Query,
+    value: &str,
+    settings: &CodeAwareSettings,
+) -> Result<MatchResult> {
+    let MatchResult::Hunks(calls) = execute_captures(
+        tree,
+        content,
+        call_query,
+        &PredicateKey::Call,
+        value,
+        settings,
+    )?
+    else {
+        return Ok(MatchResult::Hunks(Vec::new()));
+    };
+    if calls.is_empty() {
+        return Ok(MatchResult::Hunks(Vec::new()));
+    }
+    let MatchResult::Hunks(names) = execute_captures(
+        tree,
+        content,
+        definition_query,
+        &PredicateKey::Func,
+        ".",
+        settings,
+    )?
+    else {
+        return Ok(MatchResult::Hunks(Vec::new()));
+    };
+
+    let root = tree.root_node();
+    let mut ranges: Vec<Range> = Vec::new();
+    for name in names {
+        let Some(node) = root.descendant_for_byte_range(name.start_byte, name.end_byte) else {
+            continue;
+        };
+        let definition = enclosing_definition(node).range();
+        if ranges.contains(&definition) {
+            continue;
+        }
+        if calls.iter().any(|call| {
+            call.start_byte >= definition.start_byte && call.end_byte <= definition.end_byte
+        }) {
+            ranges.push(definition);
+        }
+    }
+    Ok(MatchResult::Hunks(ranges))
+}
+
+/// Widens a capture range (usually a definition's name) to its enclosing definition.
+pub(crate) fn enclosing_definition_range(tree: &Tree, range: Range) -> Range {
+    tree.root_node()
+        .descendant_for_byte_range(range.start_byte, range.end_byte)
+        .map_or(range, |node| enclosing_definition(node).range())
+}
+
+/// Climbs from a definition's name capture to the nearest ancestor with a `body` field,
+/// without leaving the body of an enclosing class or module. Body-less definitions fall
+/// back to the capture's direct parent.
+fn enclosing_definition(node: Node<'_>) -> Node<'_> {
+    if node.child_by_field_name("body").is_some() {
+        return node;
+    }
+    let mut current = node;
+    while let Some(parent) = current.parent() {
+        let is_enclosing_body = parent
+            .parent()
+            .and_then(|grandparent| grandparent.child_by_field_name("body"))
+            == Some(parent);
+        if is_enclosing_body {
+            break;
+        }
+        if parent.child_by_field_name("body").is_some() {
+            return parent;
+        }
+        current = parent;
+    }
+    node.parent().unwrap_or(node)
+}
+
+/// Kind fragments of the definitions hunks can be expanded to. Nodes must also have a `body`
+/// field, which keeps call sites and type references out.
+const SCOPE_KIND_FRAGMENTS: &[&str] = &[
+    "function",
+    "method",
+    "class",
+    "constructor",
+    "impl",
+    "module",
+    "mod_item",
+];
+
+/// Finds the nearest function, method, or class-like definition containing the bytes
+/// `start..end`, if there is one.
+pub(crate) fn enclosing_scope_range(tree: &Tree, start: usize, end: usize) -> Option<Range> {
+    let node = tree.root_node().descendant_for_byte_range(start, end)?;
+    enclosing_scope(node).map(|scope| scope.range())
+}
+
+/// Labels the nearest named scope containing the bytes `start..end` as `fn parse`,
+/// `class Lexer`, `impl Lexer`, or `mod tokens`. Anonymous functions are skipped in favor of
+/// the named scope around them.
+pub(crate) fn enclosing_scope_label(
+    tree: &Tree,
+    source: &str,
+    start: usize,
+    end: usize,
+) -> Option<String> {
+    let node = tree.root_node().descendant_for_byte_range(start, end)?;
+    let mut scope = enclosing_scope(node)?;
+    loop {
+        let name = scope
+            .child_by_field_name("name")
+            .or_else(|| scope.child_by_field_name("type"));
+        if let Some(name) = name {
+            let text = name.utf8_text(source.as_bytes()).ok()?;
+            return Some(format!("{} {text}", scope_kind_label(scope.kind())));
+        }
+        scope = enclosing_scope(scope.parent()?)?;
+    }
+}
+
+fn enclosing_scope(mut node: Node<'_>) -> Option<Node<'_>> {
+    loop {
+        let is_scope = node.child_by_field_name("body").is_some()
+            && SCOPE_KIND_FRAGMENTS
+                .iter()
+                .any(|fragment| node.kind().contains(fragment));
+        if is_scope {
+            return Some(node);
+        }
+        node = node.parent()?;
+    }
+}
+
+fn scope_kind_label(kind: &str) -> &'static str {
+    if kind.contains("class") {
+        "class"
+    } else if kind.contains("impl") {
+        "impl"
+    } else if kind.contains("module") || kind == "mod_item" {
+        "mod"
+    } else {
+        "fn"
+    }
+}
+
 fn fallback_execution_plan(
     context: &mut FileContext,
     profile_key: &str,
@@ -205,6 +568,77 @@ fn fallback_execution_plan(
     Ok(None)
 }
 
+/// `impl:` captures whole `impl` blocks; the value is compared with the implementing type's
+/// bare name, so `impl:User` covers `impl User`, `impl<T> User<T>` and `impl Display for User`.
+/// Haskell instances are compared with their first type argument, so `impl:Maybe` covers
+/// `instance Functor Maybe` and `instance Show a => Show (Maybe a)`.
+fn impl_type_name<'a>(node: Node<'_>, source: &'a [u8]) -> Result<&'a str> {
+    let mut target = if node.kind() == "instance" {
+        node.child_by_field_name("patterns")
+            .and_then(|patterns| patterns.named_child(0))
+            .unwrap_or(node)
+    } else {
+        node.child_by_field_name("type").unwrap_or(node)
+    };
+    loop {
+        let inner = match target.kind() {
+            "generic_type" => target.child_by_field_name("type"),
+            "scoped_type_identifier" => target.child_by_field_name("name"),
+            "apply" => target.child_by_field_name("constructor"),
+            "parens" => target.named_child(0),
+            "qualified" => target.child_by_field_name("id"),
+            _ => None,
+        };
+        match inner {
+            Some(inner) => target = inner,
+            None => break,
+        }
+    }
+    Ok(target.utf8_text(source)?)
+}
+
+/// `hook:` only accepts captures that sit in callee position of a call
+/// expression, while `customhook:` rejects them so that a definition such as
+/// `function useAuth()` is never reported as a hook call site (or vice versa).
+fn is_capture_site(key: &PredicateKey, node: Node<'_>) -> bool {
+    match key {
+        PredicateKey::Hook => is_call_site(node),
+        PredicateKey::CustomHook => !is_call_site(node),
+        _ => true,
+    }
+}
+
+fn is_call_site(node: Node<'_>) -> bool {
+    let callee = match node.parent() {
+        Some(parent) if parent.kind() == "member_expression" => parent,
+        _ => node,
+    };
+    callee.parent().is_some_and(|parent| {
+        parent.kind() == "call_expression" && parent.child_by_field_name("function") == Some(callee)
+    })
+}
+
+/// `call:` values with a receiver or path (`logger.log`, `fmt.Println`, `Vec::new`) are
+/// compared against the whole callee expression instead of the method name, so they only
+/// match calls on that receiver. Regex mode keeps matching the name alone.
+fn is_qualified_call_value(value: &str, settings: &CodeAwareSettings) -> bool {
+    settings.semantic_match_mode != rdump_contracts::SemanticMatchMode::Regex
+        && value != "."
+        && (value.contains('.') || value.contains("::"))
+}
+
+/// Returns the callee expression ending at a captured method name, from the start of the
+/// enclosing call up to the name (`fmt.Println` in `fmt.Println("hi")`), with whitespace
+/// removed so calls split across lines still compare equal.
+fn qualified_callee_text(node: Node<'_>, source: &[u8]) -> Option<String> {
+    let mut call = node.parent()?;
+    while !(call.kind().contains("call") || call.kind().contains("invocation")) {
+        call = call.parent()?;
+    }
+    let callee = std::str::from_utf8(&source[call.start_byte()..node.end_byte()]).ok()?;
+    Some(callee.chars().filter(|ch| !ch.is_whitespace()).collect())
+}
+
 fn is_capture_match(
     key: &PredicateKey,
     value: &str,
@@ -212,8 +646,20 @@ fn is_capture_match(
     settings: &CodeAwareSettings,
 ) -> bool {
     match key {
-        PredicateKey::Import | PredicateKey::Comment | PredicateKey::Str => {
-            match_text(captured_text, value, settings, true)
+        PredicateKey::Import
+        | PredicateKey::Comment
+        | PredicateKey::Doc
+        | PredicateKey::Str
+        | PredicateKey::Heading => match_text(captured_text, value, settings, true),
+        PredicateKey::Link => markdown_link_targets(captured_text)
+            .into_iter()
+            .any(|target| match_text(target, value, settings, true)),
+        PredicateKey::Template => value == "." || match_text(captured_text, value, settings, true),
+        PredicateKey::StrExact => {
+            match_text(strip_string_quotes(captured_text), value, settings, false)
+        }
+        PredicateKey::Test | PredicateKey::Route => {
+            value == "." || match_text(strip_string_quotes(captured_text), value, settings, true)
         }
         PredicateKey::Hook | PredicateKey::CustomHook => {
             value == "." || match_text(captured_text, value, settings, false)
@@ -223,6 +669,48 @@ fn is_capture_match(
     }
 }
 
+/// Returns the destinations of the inline links (`[text](target "title")`) in a Markdown
+/// paragraph, or the whole text when it is already a bare link-reference destination.
+fn markdown_link_targets(text: &str) -> Vec<&str> {
+    if !text.contains("](") {
+        return vec![text.trim_matches(['<', '>'])];
+    }
+    text.split("](")
+        .skip(1)
+        .filter_map(|rest| rest.split([')', ' ', '\t', '\n']).next())
+        .map(|target| target.trim_matches(['<', '>']))
+        .collect()
+}
+
+/// Removes string-literal delimiters, including prefixes such as `r#"…"#`, `b'…'`,
+/// `f"…"` or `@"…"` and Python triple quotes, leaving only the literal's contents.
+fn strip_string_quotes(text: &str) -> &str {
+    let Some(open) = text.find(['"', '\'', '`']) else {
+        return text;
+    };
+    let prefix = &text[..open];
+    if !prefix
+        .chars()
+        .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '#' | '@' | '$'))
+    {
+        return text;
+    }
+    let body = if prefix.contains('#') {
+        text[open..].trim_end_matches('#')
+    } else {
+        &text[open..]
+    };
+    for delimiter in ["\"\"\"", "'''", "\"", "'", "`"] {
+        if body.len() >= delimiter.len() * 2
+            && body.starts_with(delimiter)
+            && body.ends_with(delimiter)
+        {
+            return &body[delimiter.len()..body.len() - delimiter.len()];
+        }
+    }
+    text
+}
+
 fn match_text(
     captured_text: &str,
     value: &str,
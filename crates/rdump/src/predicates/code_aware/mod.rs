@@ -3,15 +3,21 @@ use crate::parser::PredicateKey;
 use crate::predicates::PredicateEvaluator;
 use anyhow::Result;
 use rdump_contracts::SemanticMatchMode;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
+use tree_sitter::{Parser, Tree};
 
 mod cache;
 mod execution;
 pub mod profiles;
 mod selection;
 
+pub(crate) use execution::{
+    enclosing_definition_range, enclosing_scope_label, enclosing_scope_range,
+};
+
 #[derive(Debug, Clone, Default)]
 pub struct CodeAwareSettings {
     pub sql_dialect: Option<SqlDialect>,
@@ -19,6 +25,7 @@ pub struct CodeAwareSettings {
     pub semantic_budget_ms: Option<u64>,
     pub max_semantic_matches_per_file: Option<usize>,
     pub language_override: Option<String>,
+    pub ext_map: HashMap<String, String>,
     pub semantic_match_mode: SemanticMatchMode,
     pub semantic_strict: bool,
     pub language_debug: bool,
@@ -104,6 +111,99 @@ impl SqlDialect {
     }
 }
 
+/// The lowercase extension used to pick a language profile for `path`. `ext_map` keys may
+/// span several dots (`rs.in`); the longest mapped suffix wins over the plain extension.
+/// Targets may name a profile id (`rs`) or a language (`rust`).
+fn mapped_extension(path: &Path, ext_map: &HashMap<String, String>) -> String {
+    let extension = path
+        .extension()
+        .and_then(|s| s.to_str())
+        .unwrap_or("")
+        .to_lowercase();
+    if ext_map.is_empty() {
+        return extension;
+    }
+    let file_name = path
+        .file_name()
+        .and_then(|s| s.to_str())
+        .unwrap_or("")
+        .to_lowercase();
+    let mapped = file_name
+        .match_indices('.')
+        .filter(|(index, _)| *index > 0)
+        .find_map(|(index, _)| ext_map.get(&file_name[index + 1..]));
+    let Some(target) = mapped else {
+        return extension;
+    };
+    let target = target.to_lowercase();
+    if profiles::get_profile(&target).is_some() {
+        return target;
+    }
+    profiles::find_canonical_language_profile(&target)
+        .map(|entry| entry.id.to_string())
+        .unwrap_or(target)
+}
+
+/// The profile key and language profile for `path`, from its `ext_map`-remapped extension or
+/// else a well-known file name such as `Makefile`. Unlike semantic evaluation this never reads
+/// the file, so `.sql` files get the generic dialect and shebang scripts no profile.
+pub(crate) fn profile_for_path(
+    path: &Path,
+    ext_map: &HashMap<String, String>,
+) -> Option<(String, &'static profiles::LanguageProfile)> {
+    let extension = mapped_extension(path, ext_map);
+    if let Some(profile) = profiles::get_profile(&extension) {
+        return Some((extension, profile));
+    }
+    if let Some(entry) = profiles::find_canonical_language_profile(&extension) {
+        return Some((entry.id.to_string(), entry.profile));
+    }
+    let key = profiles::profile_key_for_file_name(path.file_name()?.to_str()?)?;
+    Some((key.to_string(), profiles::get_profile(key)?))
+}
+
+/// Parses `content` with the profile [`profile_for_path`] picks for `path`. Returns `None` for
+/// files without a profile or when tree-sitter cannot parse them.
+pub(crate) fn parse_with_path_profile(
+    path: &Path,
+    ext_map: &HashMap<String, String>,
+    content: &str,
+) -> Option<Tree> {
+    let (profile_key, profile) = profile_for_path(path, ext_map)?;
+    let mut parser = Parser::new();
+    parser.set_language(&profile.language).ok()?;
+    if let Some(ranges) = profiles::embedded_ranges(&profile_key, content) {
+        parser.set_included_ranges(&ranges).ok()?;
+    }
+    parser.parse(content, None)
+}
+
+/// Labels the scope enclosing each of `ranges` (see [`enclosing_scope_label`]). The tree is
+/// the one semantic predicates already parsed for this file when the profile matches, and
+/// otherwise comes through the session's tree cache like any other parse.
+pub(crate) fn enclosing_scope_labels(
+    context: &mut FileContext,
+    ext_map: &HashMap<String, String>,
+    ranges: &[tree_sitter::Range],
+) -> Vec<Option<String>> {
+    let Some((profile_key, profile)) = profile_for_path(&context.path, ext_map) else {
+        return Vec::new();
+    };
+    let Ok(content) = context.get_content_arc() else {
+        return Vec::new();
+    };
+    let included_ranges = profiles::embedded_ranges(&profile_key, &content).unwrap_or_default();
+    let Ok(tree) =
+        context.get_tree_in_ranges(&profile_key, profile.language.clone(), &included_ranges)
+    else {
+        return Vec::new();
+    };
+    ranges
+        .iter()
+        .map(|range| enclosing_scope_label(tree, &content, range.start_byte, range.end_byte))
+        .collect()
+}
+
 /// The evaluator that uses tree-sitter to perform code-aware queries.
 #[derive(Debug, Clone)]
 pub struct CodeAwareEvaluator {
@@ -137,6 +237,20 @@ impl CodeAwareEvaluator {
     ) -> Result<std::sync::Arc<tree_sitter::Query>> {
         cache::compiled_query(profile_key, profile, key, query)
     }
+
+    fn profile_query(
+        &self,
+        profile_key: &str,
+        profile: &'static profiles::LanguageProfile,
+        key: &PredicateKey,
+    ) -> Result<Option<std::sync::Arc<tree_sitter::Query>>> {
+        match profile.queries.get(key) {
+            Some(query) if !query.is_empty() => self
+                .compiled_query(profile_key, profile, key, query)
+                .map(Some),
+            _ => Ok(None),
+        }
+    }
 }
 
 pub fn query_cache_metrics_snapshot() -> (usize, usize) {
@@ -158,14 +272,10 @@ impl PredicateEvaluator for CodeAwareEvaluator {
         key: &PredicateKey,
         value: &str,
     ) -> Result<MatchResult> {
-        // 1. Determine the language from the file extension.
-        let extension = context
-            .path
-            .extension()
-            .and_then(|s| s.to_str())
-            .unwrap_or("")
-            .to_lowercase();
-        let Some((profile_key, mut profile)) = self.select_language_profile(&extension, context)?
+        // 1. Determine the language from the file extension, honoring user remappings.
+        let extension = mapped_extension(&context.path, &self.settings.ext_map);
+        let Some((mut profile_key, mut profile)) =
+            self.select_language_profile(&extension, context)?
         else {
             if let Some(telemetry) = &self.settings.telemetry {
                 telemetry.record_unsupported_language();
@@ -179,6 +289,11 @@ impl PredicateEvaluator for CodeAwareEvaluator {
             );
             return Ok(MatchResult::Boolean(false)); // Not a supported language for this predicate.
         };
+        // Component files answer markup predicates from their template, not their script.
+        if let Some((markup_key, markup_profile)) = profiles::markup_profile(&profile_key, key) {
+            profile_key = markup_key.to_string();
+            profile = markup_profile;
+        }
 
         // 2. Resolve the execution profile and tree, including SQL fallback when needed.
         let Some(plan) =
@@ -200,14 +315,37 @@ impl PredicateEvaluator for CodeAwareEvaluator {
             );
             return Ok(MatchResult::Boolean(false));
         }
-        let ts_query_str = match profile.queries.get(key) {
-            Some(q) if !q.is_empty() => q,
-            _ => return Ok(MatchResult::Boolean(false)),
+        let Some(query) = self.profile_query(&plan.profile_key, profile, key)? else {
+            return Ok(MatchResult::Boolean(false));
         };
-        let query = self.compiled_query(&plan.profile_key, profile, key, ts_query_str)?;
 
-        // 4. Execute the query and build match hunks.
-        execution::execute_captures(&plan.tree, &content, &query, key, value, &self.settings)
+        // 4. Execute the query and build match hunks, keeping those that start in the line window.
+        let result = if *key == PredicateKey::DefnBody {
+            let Some(call_query) =
+                self.profile_query(&plan.profile_key, profile, &PredicateKey::Call)?
+            else {
+                return Ok(MatchResult::Boolean(false));
+            };
+            execution::execute_definitions_containing(
+                &plan.tree,
+                &content,
+                &query,
+                &call_query,
+                value,
+                &self.settings,
+            )?
+        } else {
+            execution::execute_captures(&plan.tree, &content, &query, key, value, &self.settings)?
+        };
+        match result {
+            MatchResult::Hunks(ranges) => Ok(MatchResult::Hunks(
+                ranges
+                    .into_iter()
+                    .filter(|range| context.row_in_line_window(range.start_point.row))
+                    .collect(),
+            )),
+            result => Ok(result),
+        }
     }
 }
 
@@ -268,6 +406,26 @@ mod tests {
         assert_eq!(selection::detect_sql_dialect(content), None);
     }
 
+    #[test]
+    fn test_detect_sql_dialect_mixed_signals_prefers_strongest() {
+        let content = "-- ported from mysql\nDELIMITER //\n\
+            CREATE FUNCTION a() RETURNS TABLE (id INT) LANGUAGE plpgsql AS $$ BEGIN END $$;\n\
+            CREATE FUNCTION b() RETURNS TABLE (id INT) LANGUAGE plpgsql AS $$ BEGIN END $$;";
+        let (dialect, trace) = selection::detect_sql_dialect_with_trace(content);
+        assert_eq!(dialect, Some(SqlDialect::Postgres));
+        assert!(trace.contains("sqlmysql=1"), "{trace}");
+        assert!(trace.contains("sqlpg=4"), "{trace}");
+    }
+
+    #[test]
+    fn test_detect_sql_dialect_mixed_signals_tie_prefers_mysql() {
+        let content = "DELIMITER //\nCREATE TRIGGER t BEGIN ATOMIC UPDATE x; END;";
+        assert_eq!(
+            selection::detect_sql_dialect(content),
+            Some(SqlDialect::Mysql)
+        );
+    }
+
     #[test]
     fn test_code_aware_evaluator_new() {
         let settings = CodeAwareSettings::default();
@@ -478,6 +636,90 @@ mod tests {
         assert!(result.is_match());
     }
 
+    #[test]
+    fn test_evaluate_string_kind_qualifiers() {
+        let dir = tempdir().unwrap();
+        let rust_path = dir.path().join("test.rs");
+        fs::write(
+            &rust_path,
+            "/// Greets the world.\nfn main() { let a = \"hello\"; let b = r\"raw hello\"; }",
+        )
+        .unwrap();
+        let js_path = dir.path().join("test.js");
+        fs::write(&js_path, "const a = 'hello';\nconst b = `hello ${name}`;\n").unwrap();
+
+        let evaluator = CodeAwareEvaluator::new(CodeAwareSettings::default());
+        let hunks = |path: &std::path::Path, value: &str| {
+            let mut context = FileContext::new(path.to_path_buf(), dir.path().to_path_buf());
+            match evaluator
+                .evaluate(&mut context, &PredicateKey::Str, value)
+                .unwrap()
+            {
+                MatchResult::Hunks(ranges) => ranges.len(),
+                MatchResult::Boolean(matched) => usize::from(matched),
+            }
+        };
+
+        assert_eq!(hunks(&rust_path, "hello"), 2);
+        assert_eq!(hunks(&rust_path, "raw:hello"), 1);
+        assert_eq!(hunks(&rust_path, "Greets"), 0);
+        assert_eq!(hunks(&rust_path, "doc:Greets"), 1);
+        assert_eq!(hunks(&rust_path, "doc:hello"), 0);
+        assert_eq!(hunks(&js_path, "hello"), 2);
+        assert_eq!(hunks(&js_path, "template:hello"), 1);
+    }
+
+    #[test]
+    fn test_evaluate_func_arity() {
+        let dir = tempdir().unwrap();
+        let files = [
+            (
+                "test.rs",
+                "fn main() {}\nfn add(a: i32, b: i32) -> i32 { a + b }\n",
+            ),
+            (
+                "test.py",
+                "def main():\n    pass\n\ndef add(a, *, b):\n    return a + b\n",
+            ),
+            (
+                "test.go",
+                "package main\n\nfunc main() {}\n\nfunc add(a, b int) int { return a + b }\n",
+            ),
+            (
+                "test.js",
+                "function main() {}\nfunction add(a, b = 1) { return a + b; }\n",
+            ),
+            (
+                "test.ts",
+                "function main(): void {}\nclass M { add(a: number, b?: number) { return a; } }\n",
+            ),
+        ];
+
+        let evaluator = CodeAwareEvaluator::new(CodeAwareSettings::default());
+        for (name, source) in files {
+            let path = dir.path().join(name);
+            fs::write(&path, source).unwrap();
+            let matched = |value: &str| {
+                let mut context = FileContext::new(path.clone(), dir.path().to_path_buf());
+                match evaluator
+                    .evaluate(&mut context, &PredicateKey::Func, value)
+                    .unwrap()
+                {
+                    MatchResult::Hunks(ranges) => ranges
+                        .iter()
+                        .map(|range| source[range.start_byte..range.end_byte].to_string())
+                        .collect::<Vec<_>>(),
+                    MatchResult::Boolean(_) => Vec::new(),
+                }
+            };
+
+            assert_eq!(matched(".#0"), ["main"], "{name}");
+            assert_eq!(matched(".#2"), ["add"], "{name}");
+            assert_eq!(matched("add#2"), ["add"], "{name}");
+            assert!(matched("main#2").is_empty(), "{name}");
+        }
+    }
+
     #[test]
     fn test_evaluate_call_contains() {
         let dir = tempdir().unwrap();
@@ -512,16 +754,64 @@ mod tests {
     #[test]
     fn test_evaluate_no_extension() {
         let dir = tempdir().unwrap();
-        let file_path = dir.path().join("Makefile");
-        fs::write(&file_path, "all:\n\techo hello").unwrap();
+        let file_path = dir.path().join("NOTES");
+        fs::write(&file_path, "fn main() {}").unwrap();
 
         let evaluator = CodeAwareEvaluator::new(CodeAwareSettings::default());
         let mut context = FileContext::new(file_path, dir.path().to_path_buf());
 
-        // No extension means unsupported
+        // No extension, no known file name, and no shebang means unsupported
         let result = evaluator
             .evaluate(&mut context, &PredicateKey::Func, "main")
             .unwrap();
         assert!(!result.is_match());
     }
+
+    #[test]
+    fn test_select_language_profile_via_file_name() {
+        let dir = tempdir().unwrap();
+        let evaluator = CodeAwareEvaluator::new(CodeAwareSettings::default());
+
+        for (file_name, expected_key, expected_name) in [
+            ("Makefile", "mk", "Makefile"),
+            ("GNUmakefile", "mk", "Makefile"),
+            ("Dockerfile", "dockerfile", "Dockerfile"),
+            ("Dockerfile.dev", "dockerfile", "Dockerfile"),
+        ] {
+            let file_path = dir.path().join(file_name);
+            fs::write(&file_path, "").unwrap();
+            let extension = file_path
+                .extension()
+                .and_then(|s| s.to_str())
+                .unwrap_or("")
+                .to_string();
+            let mut context = FileContext::new(file_path, dir.path().to_path_buf());
+
+            let (key, profile) = evaluator
+                .select_language_profile(&extension, &mut context)
+                .unwrap()
+                .unwrap();
+            assert_eq!(key, expected_key, "{file_name}");
+            assert_eq!(profile.name, expected_name, "{file_name}");
+        }
+    }
+
+    #[test]
+    fn test_evaluate_makefile_target() {
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("Makefile");
+        fs::write(&file_path, "all: build\n\nbuild:\n\tcargo build\n").unwrap();
+
+        let evaluator = CodeAwareEvaluator::new(CodeAwareSettings::default());
+        let mut context = FileContext::new(file_path, dir.path().to_path_buf());
+
+        let result = evaluator
+            .evaluate(&mut context, &PredicateKey::Target, "build")
+            .unwrap();
+        assert!(result.is_match());
+        let result = evaluator
+            .evaluate(&mut context, &PredicateKey::Func, "build")
+            .unwrap();
+        assert!(!result.is_match());
+    }
 }
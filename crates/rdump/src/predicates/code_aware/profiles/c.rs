@@ -1,4 +1,4 @@
-use super::LanguageProfile;
+use super::{LanguageProfile, BLOCK_DOC_COMMENT_QUERY};
 use crate::parser::PredicateKey;
 use std::collections::HashMap;
 
@@ -59,6 +59,7 @@ pub(super) fn create_c_profile() -> LanguageProfile {
     );
 
     queries.insert(PredicateKey::Comment, "(comment) @match".to_string());
+    queries.insert(PredicateKey::Doc, BLOCK_DOC_COMMENT_QUERY.to_string());
 
     queries.insert(
         PredicateKey::Str,
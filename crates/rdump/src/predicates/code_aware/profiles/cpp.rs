@@ -1,4 +1,4 @@
-use super::LanguageProfile;
+use super::{LanguageProfile, BLOCK_DOC_COMMENT_QUERY};
 use crate::parser::PredicateKey;
 use std::collections::HashMap;
 
@@ -39,6 +39,16 @@ pub(super) fn create_cpp_profile() -> LanguageProfile {
     // Type alias queries omitted to keep grammar-compat simple.
     queries.insert(PredicateKey::Func, func_query.to_string());
     queries.insert(PredicateKey::Macro, macro_query.to_string());
+    // Each segment of `namespace a::detail` is matchable on its own.
+    queries.insert(
+        PredicateKey::Namespace,
+        "[ (namespace_definition name: (namespace_identifier) @match) (nested_namespace_specifier (namespace_identifier) @match) ]".to_string(),
+    );
+    // The whole declaration is captured, so values match against its text.
+    queries.insert(
+        PredicateKey::Template,
+        "(template_declaration) @match".to_string(),
+    );
 
     // --- Calls & Imports ---
     queries.insert(
@@ -50,6 +60,7 @@ pub(super) fn create_cpp_profile() -> LanguageProfile {
 
     // --- Comments / Strings ---
     queries.insert(PredicateKey::Comment, "(comment) @match".to_string());
+    queries.insert(PredicateKey::Doc, BLOCK_DOC_COMMENT_QUERY.to_string());
     queries.insert(
         PredicateKey::Str,
         "[ (string_literal) @match (raw_string_literal) @match ]".to_string(),
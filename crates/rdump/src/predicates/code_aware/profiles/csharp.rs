@@ -8,11 +8,30 @@ pub(super) fn create_csharp_profile() -> LanguageProfile {
     let mut queries = HashMap::new();
 
     // --- Definitions ---
-    let class_query = "(class_declaration name: (identifier) @match)";
+    // Records such as `record Person(string Name)` are matched as classes.
+    let class_query = "
+    [
+        (class_declaration name: (identifier) @match)
+        (record_declaration name: (identifier) @match)
+    ]
+    ";
     let struct_query = "(struct_declaration name: (identifier) @match)";
     let enum_query = "(enum_declaration name: (identifier) @match)";
     let interface_query = "(interface_declaration name: (identifier) @match)";
-    let namespace_query = "(namespace_declaration name: (identifier) @match)";
+    let namespace_query = "
+    [
+        (namespace_declaration name: [ (identifier) (qualified_name) ] @match)
+        (file_scoped_namespace_declaration name: [ (identifier) (qualified_name) ] @match)
+    ]
+    ";
+    // Declared properties, including auto-properties, plus the positional parameters of a
+    // record, which the compiler turns into properties.
+    let property_query = "
+    [
+        (property_declaration name: (identifier) @match)
+        (record_declaration (parameter_list (parameter name: (identifier) @match)))
+    ]
+    ";
 
     let func_query = "
     [
@@ -38,6 +57,8 @@ pub(super) fn create_csharp_profile() -> LanguageProfile {
     queries.insert(PredicateKey::Enum, enum_query.to_string());
     queries.insert(PredicateKey::Interface, interface_query.to_string());
     queries.insert(PredicateKey::Type, namespace_query.to_string());
+    queries.insert(PredicateKey::Namespace, namespace_query.to_string());
+    queries.insert(PredicateKey::Property, property_query.to_string());
     queries.insert(PredicateKey::Func, func_query.to_string());
 
     // --- Imports ---
@@ -52,6 +73,11 @@ pub(super) fn create_csharp_profile() -> LanguageProfile {
 
     // --- Comments / Strings ---
     queries.insert(PredicateKey::Comment, "(comment) @match".to_string());
+    // XML doc comments (`///`) and `/** ... */` blocks.
+    queries.insert(
+        PredicateKey::Doc,
+        r#"((comment) @match (#match? @match "^(///|/\*\*[^/])"))"#.to_string(),
+    );
     queries.insert(
         PredicateKey::Str,
         "[ (string_literal) @match (verbatim_string_literal) @match (interpolated_string_expression) @match ]"
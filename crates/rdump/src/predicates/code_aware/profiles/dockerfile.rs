@@ -0,0 +1,29 @@
+use super::LanguageProfile;
+use crate::parser::PredicateKey;
+use std::collections::HashMap;
+
+/// Creates the profile for Dockerfiles, parsed with the `tree-sitter-containerfile` grammar
+/// (the `tree-sitter-dockerfile` crate still targets tree-sitter 0.20).
+pub(super) fn create_dockerfile_profile() -> LanguageProfile {
+    let language = tree_sitter_containerfile::LANGUAGE.into();
+    let mut queries = HashMap::new();
+
+    // `FROM <image> AS <stage>`: `from:` matches the image name, `stage:` the alias.
+    queries.insert(
+        PredicateKey::From,
+        "(from_instruction (image_spec name: (image_name) @match))".to_string(),
+    );
+    queries.insert(
+        PredicateKey::Stage,
+        "(from_instruction as: (image_alias) @match)".to_string(),
+    );
+
+    queries.insert(PredicateKey::Comment, "(comment) @match".to_string());
+
+    LanguageProfile {
+        name: "Dockerfile",
+        extensions: vec!["dockerfile"],
+        language,
+        queries,
+    }
+}
@@ -14,7 +14,16 @@ pub(super) fn create_elixir_profile() -> LanguageProfile {
     queries.insert(PredicateKey::Func, ident_query.to_string());
     queries.insert(PredicateKey::Call, ident_query.to_string());
     queries.insert(PredicateKey::Import, ident_query.to_string());
-    queries.insert(PredicateKey::Module, ident_query.to_string());
+    queries.insert(
+        PredicateKey::Module,
+        "
+        (call
+          target: (identifier) @_keyword
+          (arguments (alias) @match)
+          (#eq? @_keyword \"defmodule\"))
+        "
+        .to_string(),
+    );
     queries.insert(PredicateKey::Protocol, ident_query.to_string());
 
     // Comments / Strings.
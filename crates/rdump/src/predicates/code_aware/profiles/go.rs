@@ -18,11 +18,31 @@ pub(super) fn create_go_profile() -> LanguageProfile {
 
     queries.insert(PredicateKey::Def, [type_query, func_query].join("\n"));
     queries.insert(PredicateKey::Struct, struct_query.to_string());
+    // Go has no inheritance; an embedded struct field is the closest equivalent.
+    queries.insert(
+        PredicateKey::Extends,
+        "(field_declaration !name type: [ (type_identifier) @match (qualified_type name: (type_identifier) @match) ])".to_string(),
+    );
     queries.insert(PredicateKey::Interface, interface_query.to_string());
+    queries.insert(
+        PredicateKey::InterfaceMethod,
+        "(interface_type (method_elem name: (field_identifier) @match))".to_string(),
+    );
     queries.insert(PredicateKey::Type, type_query.to_string());
+    queries.insert(
+        PredicateKey::Field,
+        "(field_declaration name: (field_identifier) @match)".to_string(),
+    );
 
     // --- Functions & Calls ---
     queries.insert(PredicateKey::Func, func_query.to_string());
+    // Heuristic: `go test` runs top-level `TestXxx` functions whose suffix does not start
+    // with a lowercase letter.
+    queries.insert(
+        PredicateKey::Test,
+        "(function_declaration name: (identifier) @match (#match? @match \"^Test([^a-z]|$)\"))"
+            .to_string(),
+    );
     queries.insert(PredicateKey::Call, "(call_expression function: [ (identifier) @match (selector_expression field: (field_identifier) @match) ])".to_string());
 
     // --- Other ---
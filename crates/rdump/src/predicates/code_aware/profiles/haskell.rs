@@ -19,6 +19,19 @@ pub(super) fn create_haskell_profile() -> LanguageProfile {
     // Calls: approximate with variables.
     queries.insert(PredicateKey::Call, var_query.to_string());
 
+    // Typeclasses: `class Monad m where`, keyed by the class name.
+    queries.insert(
+        PredicateKey::Class,
+        "(class name: (name) @match)".to_string(),
+    );
+    // Instances: `implements:` is keyed by the class (`instance Show Foo` -> `Show`), while
+    // `impl:` captures the whole declaration and is matched against the instance type.
+    queries.insert(
+        PredicateKey::Implements,
+        "(instance name: [ (name) @match (qualified id: (name) @match) ])".to_string(),
+    );
+    queries.insert(PredicateKey::Impl, "(instance) @match".to_string());
+
     // Module declarations in Haskell
     queries.insert(PredicateKey::Module, "(module) @match".to_string());
 
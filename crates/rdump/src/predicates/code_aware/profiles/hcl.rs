@@ -0,0 +1,37 @@
+use super::LanguageProfile;
+use crate::parser::PredicateKey;
+use std::collections::HashMap;
+
+/// Creates the profile for HCL and Terraform files.
+pub(super) fn create_hcl_profile() -> LanguageProfile {
+    let language = tree_sitter_hcl::LANGUAGE.into();
+    let mut queries = HashMap::new();
+
+    // Quoted block labels: `resource "aws_instance" "web"` exposes both `aws_instance` and `web`.
+    queries.insert(
+        PredicateKey::Def,
+        "(block (string_lit (template_literal) @match))".to_string(),
+    );
+    // The block type keyword, e.g. `resource`, `variable`, `module`, or `locals`.
+    queries.insert(
+        PredicateKey::Block,
+        "(block . (identifier) @match)".to_string(),
+    );
+    queries.insert(
+        PredicateKey::Call,
+        "(function_call . (identifier) @match)".to_string(),
+    );
+
+    queries.insert(PredicateKey::Comment, "(comment) @match".to_string());
+    queries.insert(
+        PredicateKey::Str,
+        "[ (string_lit) @match (heredoc_template) @match ]".to_string(),
+    );
+
+    LanguageProfile {
+        name: "HCL",
+        extensions: vec!["hcl", "tf", "tfvars"],
+        language,
+        queries,
+    }
+}
@@ -17,8 +17,31 @@ pub(super) fn create_java_profile() -> LanguageProfile {
         format!("[ {class_query} {interface_query} {enum_query} ]"),
     );
     queries.insert(PredicateKey::Class, class_query.to_string());
+    queries.insert(
+        PredicateKey::Extends,
+        "(superclass [ (type_identifier) @match (generic_type (type_identifier) @match) ])"
+            .to_string(),
+    );
     queries.insert(PredicateKey::Interface, interface_query.to_string());
+    // Abstract methods only; `default` and `static` methods carry a body.
+    queries.insert(
+        PredicateKey::InterfaceMethod,
+        "(interface_declaration body: (interface_body (method_declaration name: (identifier) @match !body)))".to_string(),
+    );
+    queries.insert(
+        PredicateKey::Implements,
+        "(super_interfaces (type_list [ (type_identifier) @match (generic_type (type_identifier) @match) ]))".to_string(),
+    );
     queries.insert(PredicateKey::Enum, enum_query.to_string());
+    queries.insert(
+        PredicateKey::EnumVariant,
+        "(enum_constant name: (identifier) @match)".to_string(),
+    );
+    queries.insert(
+        PredicateKey::Field,
+        "(field_declaration declarator: (variable_declarator name: (identifier) @match))"
+            .to_string(),
+    );
 
     // --- Functions & Calls ---
     queries.insert(PredicateKey::Func, "[ (method_declaration name: (identifier) @match) (constructor_declaration name: (identifier) @match) ]".to_string());
@@ -33,6 +56,11 @@ pub(super) fn create_java_profile() -> LanguageProfile {
         PredicateKey::Comment,
         "[(line_comment) @match (block_comment) @match]".to_string(),
     );
+    // Javadoc blocks; Java block comments are `block_comment` rather than `comment`.
+    queries.insert(
+        PredicateKey::Doc,
+        r#"((block_comment) @match (#match? @match "^/\*\*[^/]"))"#.to_string(),
+    );
     queries.insert(PredicateKey::Str, "(string_literal) @match".to_string());
 
     LanguageProfile {
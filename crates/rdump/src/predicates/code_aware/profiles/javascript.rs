@@ -1,4 +1,4 @@
-use super::LanguageProfile;
+use super::{LanguageProfile, BLOCK_DOC_COMMENT_QUERY};
 use crate::parser::PredicateKey;
 use std::collections::HashMap;
 
@@ -12,8 +12,28 @@ pub(super) fn create_javascript_profile() -> LanguageProfile {
 
     queries.insert(PredicateKey::Def, [class_query, func_query].join("\n"));
     queries.insert(PredicateKey::Class, class_query.to_string());
+    queries.insert(
+        PredicateKey::Extends,
+        "(class_heritage [ (identifier) @match (member_expression property: (property_identifier) @match) ])".to_string(),
+    );
     queries.insert(PredicateKey::Func, func_query.to_string());
 
+    // Heuristic: Jest/Mocha-style `it("name", ...)` and `test("name", ...)` calls, including
+    // modifiers such as `test.only`, capturing the test name string.
+    queries.insert(
+        PredicateKey::Test,
+        "
+        (call_expression
+            function: [
+                (identifier) @_fn
+                (member_expression object: (identifier) @_fn)
+            ]
+            arguments: (arguments . [(string) (template_string)] @match)
+            (#match? @_fn \"^(it|test)$\"))
+        "
+        .to_string(),
+    );
+
     queries.insert(
         PredicateKey::Import,
         "(import_statement) @match".to_string(),
@@ -23,13 +43,29 @@ pub(super) fn create_javascript_profile() -> LanguageProfile {
        "[ (call_expression function: [ (identifier) @match (member_expression property: (property_identifier) @match) ]) (new_expression constructor: (identifier) @match) ]".to_string()
    );
 
+    // Heuristic: Express-style routing calls such as `app.get("/users", handler)` or
+    // `router.use("/api", ...)`, capturing the leading path string.
+    queries.insert(
+        PredicateKey::Route,
+        "
+        (call_expression
+            function: (member_expression property: (property_identifier) @_method)
+            arguments: (arguments . [(string) (template_string)] @match)
+            (#match? @_method \"^(route|get|post|put|patch|delete|head|options|all|use)$\")
+            (#match? @match \"^[\\\"'`]/\"))
+        "
+        .to_string(),
+    );
+
     queries.insert(
         PredicateKey::Comment,
         "[(comment) @match (regex) @match]".to_string(),
     ); // JS Regexes are basically comments
+
+    queries.insert(PredicateKey::Doc, BLOCK_DOC_COMMENT_QUERY.to_string());
     queries.insert(
         PredicateKey::Str,
-        "[(string) @match (template_string) @match]".to_string(),
+        "[(string) @match (template_string) @match @_template]".to_string(),
     );
 
     // --- React Hook Queries ---
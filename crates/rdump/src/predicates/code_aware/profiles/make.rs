@@ -0,0 +1,28 @@
+use super::LanguageProfile;
+use crate::parser::PredicateKey;
+use std::collections::HashMap;
+
+/// Creates the profile for Makefiles.
+pub(super) fn create_make_profile() -> LanguageProfile {
+    let language = tree_sitter_make::LANGUAGE.into();
+    let mut queries = HashMap::new();
+
+    // Rule targets (`build:`, `.PHONY:`) and variable definitions.
+    queries.insert(
+        PredicateKey::Target,
+        "(rule (targets (word) @match))".to_string(),
+    );
+    queries.insert(
+        PredicateKey::Def,
+        "(variable_assignment name: (word) @match)".to_string(),
+    );
+
+    queries.insert(PredicateKey::Comment, "(comment) @match".to_string());
+
+    LanguageProfile {
+        name: "Makefile",
+        extensions: vec!["mk", "mak"],
+        language,
+        queries,
+    }
+}
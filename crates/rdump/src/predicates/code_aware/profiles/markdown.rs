@@ -0,0 +1,39 @@
+use super::LanguageProfile;
+use crate::parser::PredicateKey;
+use std::collections::HashMap;
+
+/// Creates the profile for Markdown documents.
+///
+/// The block grammar leaves paragraph and heading text as opaque `inline` nodes, so `link:`
+/// captures whole paragraphs that contain an inline link and the evaluator picks the link
+/// targets out of their text.
+pub(super) fn create_markdown_profile() -> LanguageProfile {
+    let language = tree_sitter_md::LANGUAGE.into();
+    let mut queries = HashMap::new();
+
+    // ATX (`## Installation`) and Setext (underlined) headings, keyed by their text.
+    queries.insert(
+        PredicateKey::Heading,
+        r#"
+        (atx_heading (inline) @match)
+        (setext_heading (paragraph (inline) @match))
+        "#
+        .to_string(),
+    );
+    // Reference definitions (`[api]: https://...`) and paragraphs with `[text](target)` links.
+    queries.insert(
+        PredicateKey::Link,
+        r#"
+        (link_reference_definition (link_destination) @match)
+        ((inline) @match (#match? @match "\\]\\("))
+        "#
+        .to_string(),
+    );
+
+    LanguageProfile {
+        name: "Markdown",
+        extensions: vec!["md", "markdown"],
+        language,
+        queries,
+    }
+}
@@ -9,25 +9,35 @@ mod c;
 mod cpp;
 mod csharp;
 mod css;
+mod dockerfile;
 mod elixir;
 mod go;
 mod haskell;
+mod hcl;
 mod html;
 mod java;
 mod javascript;
 mod lua;
+mod make;
+mod markdown;
 mod ocaml;
 mod php;
+mod protobuf;
 mod python;
 mod react; // Add react module
 mod ruby;
 mod rust;
 mod scala;
+mod sfc;
 mod sql;
 mod swift;
 mod typescript;
 mod zig;
 
+/// `doc:` query for grammars that parse every comment as `(comment)`: `/** ... */` blocks
+/// only, since `/**/` is an ordinary empty comment.
+const BLOCK_DOC_COMMENT_QUERY: &str = r#"((comment) @match (#match? @match "^/\*\*[^/]"))"#;
+
 /// Defines the tree-sitter queries and metadata for a specific language.
 #[derive(Debug)]
 pub struct LanguageProfile {
@@ -44,10 +54,25 @@ pub struct CanonicalLanguageProfile {
     pub aliases: Vec<&'static str>,
 }
 
+impl CanonicalLanguageProfile {
+    /// Semantic predicates this language answers, sorted by name. Svelte and Vue add the markup
+    /// predicates that run against their template rather than their `<script>` blocks.
+    pub fn semantic_predicates(&self) -> Vec<&'static PredicateKey> {
+        let mut predicates: Vec<_> = self.profile.queries.keys().collect();
+        if is_component_file(self.id) {
+            predicates.extend(MARKUP_PROFILE.queries.keys());
+        }
+        predicates.sort_by(|left, right| left.as_ref().cmp(right.as_ref()));
+        predicates.dedup();
+        predicates
+    }
+}
+
 pub fn support_tier_for_id(id: &str) -> rdump_contracts::LanguageSupportTier {
     match id {
-        "html" | "css" | "sql" => rdump_contracts::LanguageSupportTier::Partial,
-        "hs" | "ml" | "swift" | "scala" => rdump_contracts::LanguageSupportTier::Experimental,
+        "html" | "css" | "sql" | "md" => rdump_contracts::LanguageSupportTier::Partial,
+        "hs" | "ml" | "swift" | "scala" | "dockerfile" | "mk" | "hcl" | "svelte" | "vue"
+        | "proto" => rdump_contracts::LanguageSupportTier::Experimental,
         _ => rdump_contracts::LanguageSupportTier::Stable,
     }
 }
@@ -73,6 +98,15 @@ pub(super) static LANGUAGE_PROFILES: Lazy<HashMap<&'static str, LanguageProfile>
         m.insert("exs", elixir::create_elixir_profile());
         m.insert("html", html::create_html_profile());
         m.insert("lua", lua::create_lua_profile());
+        m.insert("mk", make::create_make_profile());
+        m.insert("mak", make::create_make_profile());
+        m.insert("md", markdown::create_markdown_profile());
+        m.insert("markdown", markdown::create_markdown_profile());
+        m.insert("dockerfile", dockerfile::create_dockerfile_profile());
+        m.insert("hcl", hcl::create_hcl_profile());
+        m.insert("tf", hcl::create_hcl_profile());
+        m.insert("tfvars", hcl::create_hcl_profile());
+        m.insert("proto", protobuf::create_protobuf_profile());
         m.insert("ml", ocaml::create_ocaml_profile());
         m.insert("mli", ocaml::create_ocaml_profile());
         m.insert("zig", zig::create_zig_profile());
@@ -88,13 +122,77 @@ pub(super) static LANGUAGE_PROFILES: Lazy<HashMap<&'static str, LanguageProfile>
         m.insert("js", javascript::create_javascript_profile());
         m.insert("jsx", react::create_react_profile());
         m.insert("tsx", react::create_react_profile());
+        m.insert("svelte", sfc::create_svelte_profile());
+        m.insert("vue", sfc::create_vue_profile());
         m.insert(SqlDialect::Generic.key(), sql::create_generic_profile());
         m.insert(SqlDialect::Postgres.key(), sql::create_postgres_profile());
         m.insert(SqlDialect::Mysql.key(), sql::create_mysql_profile());
         m.insert(SqlDialect::Sqlite.key(), sql::create_sqlite_profile());
+        // `str_exact:` reuses each profile's string-literal query; only the comparison differs.
+        // `defn_body:` runs the function query and filters definitions by the calls inside them,
+        // so it is skipped for profiles that approximate both with the same identifier query.
+        for profile in m.values_mut() {
+            if let Some(query) = profile.queries.get(&PredicateKey::Str).cloned() {
+                profile.queries.insert(PredicateKey::StrExact, query);
+            }
+            let func = profile.queries.get(&PredicateKey::Func);
+            let call = profile.queries.get(&PredicateKey::Call);
+            if let (Some(func), Some(call)) = (func, call) {
+                if !func.is_empty() && !call.is_empty() && func != call {
+                    let query = func.clone();
+                    profile.queries.insert(PredicateKey::DefnBody, query);
+                }
+            }
+        }
         m
     });
 
+/// Answers markup predicates for component files whose main profile parses `<script>` blocks.
+static MARKUP_PROFILE: Lazy<LanguageProfile> = Lazy::new(sfc::create_markup_profile);
+
+/// Svelte and Vue files pair a `<script>` profile with [`MARKUP_PROFILE`] for their template.
+fn is_component_file(profile_key: &str) -> bool {
+    matches!(profile_key, "svelte" | "vue")
+}
+
+/// Returns the profile key and profile that run `key` against the template markup of a
+/// `.svelte`/`.vue` file, or `None` when `profile_key` should run `key` itself.
+pub(super) fn markup_profile(
+    profile_key: &str,
+    key: &PredicateKey,
+) -> Option<(&'static str, &'static LanguageProfile)> {
+    let is_markup_predicate = MARKUP_PROFILE.queries.contains_key(key);
+    (is_component_file(profile_key) && is_markup_predicate).then(|| ("sfcmarkup", &*MARKUP_PROFILE))
+}
+
+/// Returns the regions of `content` that `profile_key`'s grammar should parse, or `None` to
+/// parse the whole file. Component files only expose their `<script>` blocks, so positions in
+/// the tree still refer to the original file.
+pub(super) fn embedded_ranges(profile_key: &str, content: &str) -> Option<Vec<tree_sitter::Range>> {
+    is_component_file(profile_key).then(|| sfc::script_ranges(content))
+}
+
+/// Maps well-known build files that have no useful extension onto profile keys.
+static FILE_NAME_PROFILES: Lazy<HashMap<&'static str, &'static str>> = Lazy::new(|| {
+    let mut m = HashMap::new();
+    m.insert("makefile", "mk");
+    m.insert("gnumakefile", "mk");
+    m.insert("dockerfile", "dockerfile");
+    m.insert("containerfile", "dockerfile");
+    m
+});
+
+/// Resolves a profile key from a file name such as `Makefile` or `Dockerfile.dev`.
+pub(crate) fn profile_key_for_file_name(file_name: &str) -> Option<&'static str> {
+    let file_name = file_name.to_ascii_lowercase();
+    if let Some(key) = FILE_NAME_PROFILES.get(file_name.as_str()) {
+        return Some(*key);
+    }
+    // Variant Dockerfiles such as `Dockerfile.dev` keep the build-file name as their stem.
+    let (stem, _) = file_name.split_once('.')?;
+    (stem == "dockerfile").then_some("dockerfile")
+}
+
 /// Returns a list of all configured language profiles.
 pub fn list_language_profiles() -> Vec<&'static LanguageProfile> {
     LANGUAGE_PROFILES.values().collect()
@@ -231,14 +329,8 @@ pub fn render_language_profile_reference() -> String {
     );
 
     for entry in list_canonical_language_profiles() {
-        let mut predicates: Vec<_> = entry
-            .profile
-            .queries
-            .keys()
-            .map(|key| key.as_ref())
-            .collect();
-        predicates.sort_unstable();
-        predicates.dedup();
+        let predicates = entry.semantic_predicates();
+        let names: Vec<_> = predicates.iter().map(|key| key.as_ref()).collect();
 
         out.push_str(&format!(
             "## {} ({})\n\n- Support tier: `{}`\n- Aliases: `{}`\n- Extensions: `{}`\n- Semantic predicates: `{}`\n",
@@ -247,7 +339,7 @@ pub fn render_language_profile_reference() -> String {
             format!("{:?}", support_tier_for_id(entry.id)).to_lowercase(),
             entry.aliases.join(", "),
             entry.profile.extensions.join(", "),
-            predicates.join(", "),
+            names.join(", "),
         ));
 
         let caveats = semantic_caveats_for_id(entry.id);
@@ -261,9 +353,7 @@ pub fn render_language_profile_reference() -> String {
         }
 
         out.push_str("\n### Matching Rules\n\n");
-        let mut query_entries: Vec<_> = entry.profile.queries.iter().collect();
-        query_entries.sort_by(|(left, _), (right, _)| left.as_ref().cmp(right.as_ref()));
-        for (predicate, _) in query_entries {
+        for predicate in predicates {
             out.push_str(&format!(
                 "- `{}`: {}\n",
                 predicate.as_ref(),
@@ -278,11 +368,50 @@ pub fn render_language_profile_reference() -> String {
 
 pub fn predicate_matching_rule(predicate: &PredicateKey) -> &'static str {
     match predicate {
-        PredicateKey::Import | PredicateKey::Comment | PredicateKey::Str | PredicateKey::Call => {
+        PredicateKey::Call => {
+            "Substring match against the called name by default. Values with a receiver or path (`logger.log`, `fmt.Println`, `Vec::new`) must equal the whole callee expression instead. A `#N` suffix (`call:open#2`) keeps only calls passing exactly `N` arguments. `semantic_match_mode` can tighten this to exact, prefix, regex, or wildcard semantics; regex mode always matches the name alone."
+        }
+        PredicateKey::Doc => {
+            "Substring match against doc comments and docstrings only, case-sensitive like `comment:`; ordinary comments are skipped. `semantic_match_mode` can tighten this to exact, prefix, regex, or wildcard semantics."
+        }
+        PredicateKey::Import | PredicateKey::Comment | PredicateKey::Str | PredicateKey::Heading => {
             "Substring match against captured text by default. `semantic_match_mode` can tighten this to exact, prefix, regex, or wildcard semantics."
         }
-        PredicateKey::Hook | PredicateKey::CustomHook => {
-            "Exact match by default, with `.` accepted as the broad wildcard. `semantic_match_mode=wildcard` enables shell-style `*` matching."
+        PredicateKey::Template => {
+            "Substring match against the whole template declaration by default, with `.` accepted as the broad wildcard. `semantic_match_mode` can tighten this to exact, prefix, regex, or wildcard semantics."
+        }
+        PredicateKey::Route => {
+            "Best-effort: the query recognizes common routing call shapes (Flask-style decorators, Express-style `app.get(...)`), not framework semantics. Substring match against the path string with its quotes removed, with `.` accepted as the broad wildcard. `semantic_match_mode` can tighten this to exact, prefix, regex, or wildcard semantics."
+        }
+        PredicateKey::Link => {
+            "Substring match against each link destination (`[text](target)` or a `[label]: target` definition). Inline links are found by scanning paragraph text, so hunks span the whole paragraph. `semantic_match_mode` can tighten this to exact, prefix, regex, or wildcard semantics."
+        }
+        PredicateKey::DefnBody => {
+            "Returns whole function definitions whose body contains a call matching the value. Calls are compared like `call:` (substring by default, adjustable via `semantic_match_mode`)."
+        }
+        PredicateKey::Func => {
+            "Exact match by default, with `.` accepted as the broad wildcard. A `#N` suffix (`func:.#2`, `func:handle#0`) keeps only functions declaring exactly `N` parameters; Rust, Python, Go, JavaScript and TypeScript are supported. `semantic_match_mode` can switch to case-insensitive, prefix, regex, or wildcard behavior."
+        }
+        PredicateKey::Struct => {
+            "Exact match by default, with `.` accepted as the broad wildcard. A `{}` suffix (`struct:Marker{}`, `struct:.{}`) keeps only structs declaring no fields, unit structs included; Rust and Go are supported. `semantic_match_mode` can switch to case-insensitive, prefix, regex, or wildcard behavior."
+        }
+        PredicateKey::Test => {
+            "Heuristic: tests are recognized per language (Rust `#[test]` attributes, Zig `test` blocks, Python `test*` and Go `TestXxx` function names, JavaScript/TypeScript `it(...)`/`test(...)` calls), not by running a test framework. Substring match against the test's name with any surrounding quotes removed, with `.` accepted as the broad wildcard. `semantic_match_mode` can tighten this to exact, prefix, regex, or wildcard semantics."
+        }
+        PredicateKey::Impl => {
+            "Exact match against the implementing type's name, ignoring generic arguments and path qualifiers, with `.` accepted as the broad wildcard. Hunks span the whole `impl` block, trait impls included; Haskell `instance` declarations are matched by their first type argument. `semantic_match_mode` can switch to case-insensitive, prefix, regex, or wildcard behavior."
+        }
+        PredicateKey::StrExact => {
+            "Exact match against string-literal contents with the surrounding quotes removed. `semantic_match_mode` can switch to case-insensitive, prefix, regex, or wildcard behavior."
+        }
+        PredicateKey::Prop => {
+            "Exact match against the JSX attribute name by default, with `.` accepted as the broad wildcard. `prop:name=value` also requires the attribute's value to equal `value`, ignoring quotes and `{}` braces. `semantic_match_mode` can switch to case-insensitive, prefix, regex, or wildcard behavior."
+        }
+        PredicateKey::Hook => {
+            "Exact match against hook call sites only, with `.` accepted as the broad wildcard. `semantic_match_mode=wildcard` enables shell-style `*` matching."
+        }
+        PredicateKey::CustomHook => {
+            "Exact match against hook definitions only, with `.` accepted as the broad wildcard. `semantic_match_mode=wildcard` enables shell-style `*` matching."
         }
         _ => {
             "Exact match by default, with `.` accepted as the broad wildcard. `semantic_match_mode` can switch to case-insensitive, prefix, regex, or wildcard behavior."
@@ -297,7 +426,12 @@ pub fn semantic_caveats_for_id(id: &str) -> Vec<&'static str> {
         "css" => caveats.push("CSS semantic coverage is partial and focuses on selectors and declarations, not cascade resolution."),
         "sql" | "sqlgeneric" | "sqlpostgres" | "sqlmysql" | "sqlsqlite" => caveats.push("SQL dialect selection is heuristic unless overridden; enable strict mode to fail instead of falling back."),
         "jsx" | "tsx" => caveats.push("React-specific predicates are only available on JSX/TSX profiles and remain more permissive than language-core predicates."),
+        "md" => caveats.push("Markdown coverage is limited to headings and links; paragraph text is not parsed further, so `link:` hunks cover whole paragraphs."),
+        "svelte" | "vue" => caveats.push("Script predicates use the TypeScript queries and only see `<script>` blocks; `element:` and `component:` (PascalCase tags) match the template markup, whose template syntax is not parsed."),
+        "proto" => caveats.push("Protobuf coverage is structural: messages, enums, services and `rpc` methods are matched by name; options and field types are not captured."),
+        "hcl" => caveats.push("`def:` matches quoted block labels such as the resource type and name; unquoted labels and attribute names are not captured."),
         "hs" | "ml" | "swift" | "scala" => caveats.push("This profile is experimental; expect narrower predicate coverage and fewer regression fixtures."),
+        "dockerfile" | "mk" => caveats.push("Build-file profiles are selected by file name (`Dockerfile`, `Makefile`) as well as extension and only cover a few structural predicates."),
         _ => {}
     }
     if matches!(
@@ -21,11 +21,15 @@ pub(super) fn create_ocaml_profile() -> LanguageProfile {
     // Calls - approximate by identifiers.
     queries.insert(PredicateKey::Call, "(identifier) @match".to_string());
 
-    // Module definitions in OCaml
-    queries.insert(
-        PredicateKey::Module,
-        "(module_definition) @match".to_string(),
-    );
+    // Modules, functors (`module Make (X : S) = struct ... end`) and module types, by name.
+    // Parameters and module paths wrap their own `module_name`, so only the bound name matches.
+    let module_query = r#"
+    [
+      (module_binding (module_name) @match)
+      (module_type_definition (module_type_name) @match)
+    ]
+    "#;
+    queries.insert(PredicateKey::Module, module_query.to_string());
 
     // Comments / Strings
     queries.insert(PredicateKey::Comment, "(comment) @match".to_string());
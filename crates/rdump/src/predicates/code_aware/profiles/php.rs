@@ -1,4 +1,4 @@
-use super::LanguageProfile;
+use super::{LanguageProfile, BLOCK_DOC_COMMENT_QUERY};
 use crate::parser::PredicateKey;
 use std::collections::HashMap;
 
@@ -21,6 +21,16 @@ pub(super) fn create_php_profile() -> LanguageProfile {
     queries.insert(PredicateKey::Interface, interface_query.to_string());
     queries.insert(PredicateKey::Trait, trait_query.to_string());
     queries.insert(PredicateKey::Func, func_query.to_string());
+    // Each segment of `namespace App\Controllers;` is matchable on its own.
+    queries.insert(
+        PredicateKey::Namespace,
+        "(namespace_definition name: (namespace_name (name) @match))".to_string(),
+    );
+    // PHP 8 attributes, keyed by the attribute's short name: `#[Route]`, `#[ORM\Entity]`.
+    queries.insert(
+        PredicateKey::Decorator,
+        "(attribute [ (name) @match (qualified_name (name) @match) ])".to_string(),
+    );
 
     // --- Imports ---
     queries.insert(PredicateKey::Import, "(qualified_name) @match".to_string());
@@ -40,6 +50,7 @@ pub(super) fn create_php_profile() -> LanguageProfile {
 
     // --- Comments / Strings ---
     queries.insert(PredicateKey::Comment, "(comment) @match".to_string());
+    queries.insert(PredicateKey::Doc, BLOCK_DOC_COMMENT_QUERY.to_string());
     queries.insert(
         PredicateKey::Str,
         "[ (string) @match (encapsed_string) @match (heredoc) @match ]".to_string(),
@@ -0,0 +1,39 @@
+use super::LanguageProfile;
+use crate::parser::PredicateKey;
+use std::collections::HashMap;
+
+/// Creates the profile for Protocol Buffers (`.proto`) files, including gRPC services.
+pub(super) fn create_protobuf_profile() -> LanguageProfile {
+    let language = tree_sitter_proto::LANGUAGE.into();
+    let mut queries = HashMap::new();
+
+    let message_query = "(message (message_name) @match)";
+    let enum_query = "(enum (enum_name) @match)";
+    let service_query = "(service (service_name) @match)";
+
+    queries.insert(
+        PredicateKey::Def,
+        [message_query, enum_query, service_query].join("\n"),
+    );
+    // `struct:` (and its `message:` alias) matches message names, nested messages included.
+    queries.insert(PredicateKey::Struct, message_query.to_string());
+    queries.insert(PredicateKey::Enum, enum_query.to_string());
+    queries.insert(PredicateKey::Service, service_query.to_string());
+    // rpc methods declared inside a service.
+    queries.insert(PredicateKey::Func, "(rpc (rpc_name) @match)".to_string());
+    queries.insert(PredicateKey::Import, "(import (string) @match)".to_string());
+    queries.insert(
+        PredicateKey::Module,
+        "(package (full_ident) @match)".to_string(),
+    );
+
+    queries.insert(PredicateKey::Comment, "(comment) @match".to_string());
+    queries.insert(PredicateKey::Str, "(string) @match".to_string());
+
+    LanguageProfile {
+        name: "Protobuf",
+        extensions: vec!["proto"],
+        language,
+        queries,
+    }
+}
@@ -2,6 +2,15 @@ use super::LanguageProfile;
 use crate::parser::PredicateKey;
 use std::collections::HashMap;
 
+/// Docstrings are the leading string of a module, function or class body. `doc:` uses this
+/// query as is; `Str` adds it to the plain string pattern, and the `@_doc` tag selects it for
+/// `str:doc:`.
+const DOCSTRING_QUERY: &str = r#"
+(module . (expression_statement (string) @match @_doc))
+(function_definition body: (block . (expression_statement (string) @match @_doc)))
+(class_definition body: (block . (expression_statement (string) @match @_doc)))
+"#;
+
 /// Creates the profile for the Python language.
 pub(super) fn create_python_profile() -> LanguageProfile {
     let language = tree_sitter_python::LANGUAGE.into();
@@ -12,7 +21,28 @@ pub(super) fn create_python_profile() -> LanguageProfile {
 
     queries.insert(PredicateKey::Def, [class_query, func_query].join("\n"));
     queries.insert(PredicateKey::Class, class_query.to_string());
+    queries.insert(
+        PredicateKey::Extends,
+        "(class_definition superclasses: (argument_list [ (identifier) @match (attribute attribute: (identifier) @match) ]))".to_string(),
+    );
     queries.insert(PredicateKey::Func, func_query.to_string());
+    // Heuristic: pytest and unittest collect functions and `TestCase` methods named `test*`.
+    queries.insert(
+        PredicateKey::Test,
+        "(function_definition name: (identifier) @match (#match? @match \"^test\"))".to_string(),
+    );
+    // Python has no field declarations; instance attributes assigned through `self` stand in.
+    queries.insert(
+        PredicateKey::Field,
+        "
+        (assignment
+            left: (attribute
+                object: (identifier) @_object
+                attribute: (identifier) @match)
+            (#eq? @_object \"self\"))
+        "
+        .to_string(),
+    );
 
     // Query for `import` and `from ... import` statements.
     queries.insert(
@@ -40,8 +70,27 @@ pub(super) fn create_python_profile() -> LanguageProfile {
         .to_string(),
     );
 
+    // Heuristic: Flask/FastAPI-style route decorators such as `@app.route("/users")` or
+    // `@router.get("/users")`, capturing the leading path string.
+    queries.insert(
+        PredicateKey::Route,
+        "
+        (decorator
+            (call
+                function: (attribute attribute: (identifier) @_method)
+                arguments: (argument_list . (string) @match))
+            (#match? @_method \"^(route|get|post|put|patch|delete|head|options|websocket)$\")
+            (#match? @match \"^[rRuU]?[\\\"']/\"))
+        "
+        .to_string(),
+    );
+
     queries.insert(PredicateKey::Comment, "(comment) @match".to_string());
-    queries.insert(PredicateKey::Str, "(string) @match".to_string());
+    queries.insert(PredicateKey::Doc, DOCSTRING_QUERY.to_string());
+    queries.insert(
+        PredicateKey::Str,
+        format!("(string) @match\n{DOCSTRING_QUERY}"),
+    );
 
     LanguageProfile {
         name: "Python",
@@ -1,4 +1,4 @@
-use super::LanguageProfile;
+use super::{LanguageProfile, BLOCK_DOC_COMMENT_QUERY};
 use crate::parser::PredicateKey;
 use std::collections::HashMap;
 
@@ -54,10 +54,15 @@ pub(super) fn create_react_profile() -> LanguageProfile {
     queries.insert(PredicateKey::Element, element_query.to_string());
 
     // --- Hook Queries ---
+    // `hook:` targets call sites (`useState(...)`, `React.useState(...)`) while
+    // `customhook:` below targets definitions; keep the two capture sets disjoint.
     let hook_query = "
         (call_expression
-            function: (identifier) @match
-            (#match? @match \"^(use)\")
+            function: [
+                (identifier) @match
+                (member_expression property: (property_identifier) @match)
+            ]
+            (#match? @match \"^use([A-Z]|$)\")
         )
     ";
     let custom_hook_query = r#"
@@ -70,23 +75,30 @@ pub(super) fn create_react_profile() -> LanguageProfile {
       name: (identifier) @match
       value: (arrow_function))
     (#match? @match "^use[A-Z]"))
-  (export_statement
-    declaration: (function_declaration
-      name: (identifier) @match)
-    (#match? @match "^use[A-Z]"))
-  (export_statement
-    declaration: (lexical_declaration
-      (variable_declarator
-        name: (identifier) @match
-        value: (arrow_function)))
-    (#match? @match "^use[A-Z]"))
 ]
 "#;
     queries.insert(PredicateKey::Hook, hook_query.to_string());
+
+    // Heuristic: Jest/Mocha-style `it("name", ...)` and `test("name", ...)` calls, including
+    // modifiers such as `test.only`, capturing the test name string.
+    queries.insert(
+        PredicateKey::Test,
+        "
+        (call_expression
+            function: [
+                (identifier) @_fn
+                (member_expression object: (identifier) @_fn)
+            ]
+            arguments: (arguments . [(string) (template_string)] @match)
+            (#match? @_fn \"^(it|test)$\"))
+        "
+        .to_string(),
+    );
     queries.insert(PredicateKey::CustomHook, custom_hook_query.to_string());
 
     // --- Prop Query ---
-    let prop_query = "(jsx_attribute (property_identifier) @match)";
+    // `@_value` captures the attribute value, if any, for `prop:name=value`.
+    let prop_query = "(jsx_attribute (property_identifier) @match (_)? @_value)";
     queries.insert(PredicateKey::Prop, prop_query.to_string());
 
     // --- Generic Queries (reusing from TS) ---
@@ -94,10 +106,15 @@ pub(super) fn create_react_profile() -> LanguageProfile {
         PredicateKey::Import,
         "(import_statement) @match".to_string(),
     );
+    queries.insert(
+        PredicateKey::Extends,
+        "(extends_clause [ (identifier) @match (member_expression property: (property_identifier) @match) ])".to_string(),
+    );
     queries.insert(PredicateKey::Comment, "(comment) @match".to_string());
+    queries.insert(PredicateKey::Doc, BLOCK_DOC_COMMENT_QUERY.to_string());
     queries.insert(
         PredicateKey::Str,
-        "[(string) @match (template_string) @match]".to_string(),
+        "[(string) @match (template_string) @match @_template]".to_string(),
     );
 
     LanguageProfile {
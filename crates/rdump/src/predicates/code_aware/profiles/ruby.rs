@@ -37,10 +37,12 @@ pub(super) fn create_ruby_profile() -> LanguageProfile {
     );
 
     // --- Comments / Strings ---
+    // Symbol literals (`:save`, `:"save!"`) count as strings so `str:` can find them.
     queries.insert(PredicateKey::Comment, "(comment) @match".to_string());
     queries.insert(
         PredicateKey::Str,
-        "[ (string) @match (heredoc_body) @match ]".to_string(),
+        "[ (string) @match (heredoc_body) @match (simple_symbol) @match (delimited_symbol) @match ]"
+            .to_string(),
     );
 
     LanguageProfile {
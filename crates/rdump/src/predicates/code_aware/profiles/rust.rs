@@ -2,6 +2,13 @@ use super::LanguageProfile;
 use crate::parser::PredicateKey;
 use std::collections::HashMap;
 
+/// Outer (`///`, `/** */`) and inner (`//!`, `/*! */`) doc comments, shared by `doc:` and
+/// `str:doc:`. The `@_comment` tag keeps them out of a plain `str:` search.
+const DOC_COMMENT_QUERY: &str = "
+(line_comment doc: (doc_comment)) @match @_doc @_comment
+(block_comment doc: (doc_comment)) @match @_doc @_comment
+";
+
 /// Creates the profile for the Rust language.
 pub(super) fn create_rust_profile() -> LanguageProfile {
     let language = tree_sitter_rust::LANGUAGE.into();
@@ -11,7 +18,8 @@ pub(super) fn create_rust_profile() -> LanguageProfile {
     let enum_query = "(enum_item name: (_) @match)";
     let trait_query = "(trait_item name: (_) @match)";
     let type_query = "(type_item name: (type_identifier) @match)";
-    let impl_query = "(impl_item type: (type_identifier) @match)";
+    // The whole block is the hunk; the value is matched against the implementing type.
+    let impl_query = "(impl_item) @match";
     let macro_query = "(macro_definition name: (identifier) @match)";
     let module_query = "(mod_item name: (identifier) @match)";
 
@@ -30,8 +38,22 @@ pub(super) fn create_rust_profile() -> LanguageProfile {
     queries.insert(PredicateKey::Trait, trait_query.to_string());
     queries.insert(PredicateKey::Type, type_query.to_string());
     queries.insert(PredicateKey::Impl, impl_query.to_string());
+    // Trait impls, keyed by the trait: `impl Display for User`, `impl fmt::Display for User`,
+    // `impl From<u8> for User`.
+    queries.insert(
+        PredicateKey::Implements,
+        "(impl_item trait: [ (type_identifier) @match (scoped_type_identifier name: (type_identifier) @match) (generic_type type: [ (type_identifier) @match (scoped_type_identifier name: (type_identifier) @match) ]) ])".to_string(),
+    );
     queries.insert(PredicateKey::Macro, macro_query.to_string());
     queries.insert(PredicateKey::Module, module_query.to_string());
+    queries.insert(
+        PredicateKey::EnumVariant,
+        "(enum_variant name: (identifier) @match)".to_string(),
+    );
+    queries.insert(
+        PredicateKey::Field,
+        "(field_declaration name: (field_identifier) @match)".to_string(),
+    );
 
     // Query for standalone functions and methods in traits or impls.
     queries.insert(
@@ -44,6 +66,34 @@ pub(super) fn create_rust_profile() -> LanguageProfile {
         "
         .to_string(),
     );
+    // Only the bodiless signatures a trait declares; default methods and impls stay under `func:`.
+    queries.insert(
+        PredicateKey::TraitMethod,
+        "(trait_item body: (declaration_list (function_signature_item name: (identifier) @match)))"
+            .to_string(),
+    );
+    // Test functions: `#[test]`, `#[tokio::test]`, and the like, optionally followed by one
+    // more attribute such as `#[should_panic]` or `#[ignore]`.
+    queries.insert(
+        PredicateKey::Test,
+        r#"
+        (
+            (attribute_item (attribute [ (identifier) @_attr (scoped_identifier name: (identifier) @_attr) ]))
+            .
+            (function_item name: (identifier) @match)
+            (#eq? @_attr "test")
+        )
+        (
+            (attribute_item (attribute [ (identifier) @_attr (scoped_identifier name: (identifier) @_attr) ]))
+            .
+            (attribute_item)
+            .
+            (function_item name: (identifier) @match)
+            (#eq? @_attr "test")
+        )
+        "#
+        .to_string(),
+    );
     // Query for the entire `use` declaration. We will match against its text content.
     queries.insert(
         PredicateKey::Import,
@@ -61,9 +111,11 @@ pub(super) fn create_rust_profile() -> LanguageProfile {
         PredicateKey::Comment,
         "[(line_comment) @match (block_comment) @match]".to_string(),
     );
+    queries.insert(PredicateKey::Doc, DOC_COMMENT_QUERY.to_string());
+    // Raw strings are tagged for `str:raw:`; doc comments are only reachable via `str:doc:`.
     queries.insert(
         PredicateKey::Str,
-        "[(string_literal) @match (raw_string_literal) @match]".to_string(),
+        format!("(string_literal) @match\n(raw_string_literal) @match @_raw\n{DOC_COMMENT_QUERY}"),
     );
 
     LanguageProfile {
@@ -8,17 +8,30 @@ pub(super) fn create_scala_profile() -> LanguageProfile {
     let mut queries = HashMap::new();
 
     // --- Definitions ---
+    // `case class` and `implicit class` are plain class definitions with modifiers.
     let class_query = "(class_definition name: (identifier) @match)";
     let object_query = "(object_definition name: (identifier) @match)";
     let trait_query = "(trait_definition name: (identifier) @match)";
-    let func_query = "(function_definition name: (identifier) @match)";
+    let type_query = "(type_definition name: (type_identifier) @match)";
+    // Named Scala 3 `given` instances; anonymous givens have no name to match.
+    let given_query = "(given_definition name: (identifier) @match)";
+    // Abstract `def`s in traits are declarations rather than definitions.
+    let func_query = "[(function_definition name: (identifier) @match) (function_declaration name: (identifier) @match)]";
 
     queries.insert(
         PredicateKey::Def,
-        [class_query, object_query, trait_query, func_query].join("\n"),
+        [
+            class_query,
+            object_query,
+            trait_query,
+            type_query,
+            given_query,
+            func_query,
+        ]
+        .join("\n"),
     );
     queries.insert(PredicateKey::Class, class_query.to_string());
-    queries.insert(PredicateKey::Type, object_query.to_string());
+    queries.insert(PredicateKey::Type, type_query.to_string());
     queries.insert(PredicateKey::Object, object_query.to_string());
     queries.insert(PredicateKey::Trait, trait_query.to_string());
     queries.insert(PredicateKey::Func, func_query.to_string());
@@ -0,0 +1,126 @@
+use super::{typescript, LanguageProfile};
+use crate::parser::PredicateKey;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::HashMap;
+use tree_sitter::{Point, Range};
+
+/// Template elements; `element:` compares the tag name exactly, so `element:Button` only
+/// matches the component tag while `element:button` matches the native one.
+const ELEMENT_QUERY: &str = "
+    [
+        (start_tag (tag_name) @match)
+        (self_closing_tag (tag_name) @match)
+    ]
+";
+
+/// Child components used in the template, which by convention have PascalCase tags.
+const COMPONENT_QUERY: &str = "
+    [
+        (start_tag (tag_name) @match (#match? @match \"^[A-Z]\"))
+        (self_closing_tag (tag_name) @match (#match? @match \"^[A-Z]\"))
+    ]
+";
+
+static SCRIPT_BLOCK: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?is)<script\b[^>]*>(.*?)</script\s*>").unwrap());
+
+/// Creates the profile for Svelte components.
+pub(super) fn create_svelte_profile() -> LanguageProfile {
+    create_component_profile("Svelte", vec!["svelte"])
+}
+
+/// Creates the profile for Vue single-file components.
+pub(super) fn create_vue_profile() -> LanguageProfile {
+    create_component_profile("Vue", vec!["vue"])
+}
+
+/// Script predicates reuse the TypeScript queries, which also cover plain JavaScript, and run
+/// against the `<script>` blocks only (see [`script_ranges`]). Markup predicates live on
+/// [`create_markup_profile`], which parses the template as HTML.
+fn create_component_profile(name: &'static str, extensions: Vec<&'static str>) -> LanguageProfile {
+    let profile = typescript::create_typescript_profile();
+    LanguageProfile {
+        name,
+        extensions,
+        ..profile
+    }
+}
+
+/// Creates the HTML-based profile that answers markup predicates for `.svelte`/`.vue` files.
+/// Template syntax such as `{#if}` blocks or `v-if` attributes is parsed as plain text and
+/// attributes, which leaves the tags themselves intact.
+pub(super) fn create_markup_profile() -> LanguageProfile {
+    let mut queries = HashMap::new();
+    queries.insert(PredicateKey::Element, ELEMENT_QUERY.to_string());
+    queries.insert(PredicateKey::Component, COMPONENT_QUERY.to_string());
+    LanguageProfile {
+        name: "Component markup",
+        extensions: Vec::new(),
+        language: tree_sitter_html::LANGUAGE.into(),
+        queries,
+    }
+}
+
+/// Returns the contents of every `<script>` block in `content`, in file order, as tree-sitter
+/// ranges. A file without a script yields a single empty range, because an empty list would
+/// make the parser read the whole file.
+pub(super) fn script_ranges(content: &str) -> Vec<Range> {
+    let mut ranges: Vec<Range> = SCRIPT_BLOCK
+        .captures_iter(content)
+        .filter_map(|captures| captures.get(1))
+        .map(|body| Range {
+            start_byte: body.start(),
+            end_byte: body.end(),
+            start_point: point_at(content, body.start()),
+            end_point: point_at(content, body.end()),
+        })
+        .collect();
+    if ranges.is_empty() {
+        ranges.push(Range {
+            start_byte: 0,
+            end_byte: 0,
+            start_point: Point::new(0, 0),
+            end_point: Point::new(0, 0),
+        });
+    }
+    ranges
+}
+
+fn point_at(content: &str, byte: usize) -> Point {
+    let before = &content[..byte];
+    let row = before.matches('\n').count();
+    let column = before
+        .rfind('\n')
+        .map_or(byte, |newline| byte - newline - 1);
+    Point::new(row, column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn script_ranges_cover_each_script_body() {
+        let content = "<script context=\"module\">\nexport const a = 1;\n</script>\n\n<h1>Hi</h1>\n<script lang=\"ts\">let b = 2;</script>\n";
+        let ranges = script_ranges(content);
+        assert_eq!(ranges.len(), 2);
+        assert_eq!(
+            &content[ranges[0].start_byte..ranges[0].end_byte],
+            "\nexport const a = 1;\n"
+        );
+        assert_eq!(ranges[0].start_point, Point::new(0, 25));
+        assert_eq!(
+            &content[ranges[1].start_byte..ranges[1].end_byte],
+            "let b = 2;"
+        );
+        assert_eq!(ranges[1].start_point, Point::new(5, 18));
+    }
+
+    #[test]
+    fn script_ranges_are_empty_without_a_script() {
+        let ranges = script_ranges("<template><div /></template>\n");
+        assert_eq!(ranges.len(), 1);
+        assert_eq!(ranges[0].start_byte, ranges[0].end_byte);
+    }
+}
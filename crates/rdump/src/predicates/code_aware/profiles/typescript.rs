@@ -1,4 +1,4 @@
-use super::LanguageProfile;
+use super::{LanguageProfile, BLOCK_DOC_COMMENT_QUERY};
 use crate::parser::PredicateKey;
 use std::collections::HashMap;
 
@@ -16,11 +16,47 @@ pub(super) fn create_typescript_profile() -> LanguageProfile {
     queries.insert(PredicateKey::Def, def_query);
 
     queries.insert(PredicateKey::Class, class_query.to_string());
+    queries.insert(
+        PredicateKey::Extends,
+        "(extends_clause [ (identifier) @match (member_expression property: (property_identifier) @match) ])".to_string(),
+    );
     queries.insert(PredicateKey::Interface, interface_query.to_string());
+    queries.insert(
+        PredicateKey::InterfaceMethod,
+        "(interface_declaration body: (interface_body (method_signature name: (property_identifier) @match)))".to_string(),
+    );
+    queries.insert(
+        PredicateKey::Implements,
+        "(implements_clause [ (type_identifier) @match (generic_type name: (type_identifier) @match) ])".to_string(),
+    );
     queries.insert(PredicateKey::Type, type_query.to_string());
     queries.insert(PredicateKey::Enum, enum_query.to_string());
+    queries.insert(
+        PredicateKey::EnumVariant,
+        "[ (enum_body name: (property_identifier) @match) (enum_assignment name: (property_identifier) @match) ]".to_string(),
+    );
+    queries.insert(
+        PredicateKey::Field,
+        "[ (public_field_definition name: (property_identifier) @match) (property_signature name: (property_identifier) @match) ]".to_string(),
+    );
 
     queries.insert(PredicateKey::Func, "[ (function_declaration name: (identifier) @match) (method_definition name: (property_identifier) @match) ]".to_string());
+
+    // Heuristic: Jest/Mocha-style `it("name", ...)` and `test("name", ...)` calls, including
+    // modifiers such as `test.only`, capturing the test name string.
+    queries.insert(
+        PredicateKey::Test,
+        "
+        (call_expression
+            function: [
+                (identifier) @_fn
+                (member_expression object: (identifier) @_fn)
+            ]
+            arguments: (arguments . [(string) (template_string)] @match)
+            (#match? @_fn \"^(it|test)$\"))
+        "
+        .to_string(),
+    );
     queries.insert(
         PredicateKey::Import,
         "(import_statement) @match".to_string(),
@@ -30,10 +66,25 @@ pub(super) fn create_typescript_profile() -> LanguageProfile {
        "[ (call_expression function: [ (identifier) @match (member_expression property: (property_identifier) @match) ]) (new_expression constructor: [ (identifier) @match (type_identifier) @match ]) ]".to_string()
    );
 
+    // Heuristic: Express-style routing calls such as `app.get("/users", handler)` or
+    // `router.use("/api", ...)`, capturing the leading path string.
+    queries.insert(
+        PredicateKey::Route,
+        "
+        (call_expression
+            function: (member_expression property: (property_identifier) @_method)
+            arguments: (arguments . [(string) (template_string)] @match)
+            (#match? @_method \"^(route|get|post|put|patch|delete|head|options|all|use)$\")
+            (#match? @match \"^[\\\"'`]/\"))
+        "
+        .to_string(),
+    );
+
     queries.insert(PredicateKey::Comment, "(comment) @match".to_string());
+    queries.insert(PredicateKey::Doc, BLOCK_DOC_COMMENT_QUERY.to_string());
     queries.insert(
         PredicateKey::Str,
-        "[(string) @match (template_string) @match]".to_string(),
+        "[(string) @match (template_string) @match @_template]".to_string(),
     );
 
     // --- React Hook Queries ---
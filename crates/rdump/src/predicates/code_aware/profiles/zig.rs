@@ -16,6 +16,12 @@ pub(super) fn create_zig_profile() -> LanguageProfile {
     queries.insert(PredicateKey::Enum, ident.to_string());
     queries.insert(PredicateKey::Type, ident.to_string());
 
+    // Named test blocks: `test "parse works" { ... }` and `test parse { ... }`.
+    queries.insert(
+        PredicateKey::Test,
+        "(test_declaration [ (string) @match (identifier) @match ])".to_string(),
+    );
+
     // Imports (@import).
     queries.insert(
         PredicateKey::Import,
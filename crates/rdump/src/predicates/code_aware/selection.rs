@@ -60,6 +60,19 @@ pub(super) fn select_language_profile(
         return Ok(Some((key, profile)));
     }
 
+    if let Some(file_name) = context.path.file_name().and_then(|name| name.to_str()) {
+        if let Some(key) = profiles::profile_key_for_file_name(file_name) {
+            if let Some(profile) = profiles::get_profile(key) {
+                emit_language_debug(
+                    settings,
+                    context,
+                    format!("Selected semantic profile `{key}` from file name `{file_name}`."),
+                );
+                return Ok(Some((key.to_string(), profile)));
+            }
+        }
+    }
+
     if key.is_empty() {
         if let Some(key) = detect_shebang_profile(context)? {
             if let Some(profile) = profiles::get_profile(&key) {
@@ -124,29 +137,58 @@ pub(crate) fn detect_sql_dialect(content: &str) -> Option<SqlDialect> {
     detect_sql_dialect_with_trace(content).0
 }
 
+/// Scores every dialect marker and picks the dialect with the most occurrences.
+///
+/// Each `DELIMITER //` counts toward MySQL, each `BEGIN ATOMIC` toward SQLite, and each
+/// `RETURNS TABLE` or `LANGUAGE plpgsql` toward PostgreSQL. Ties are broken in favour of
+/// MySQL, then SQLite, then PostgreSQL, which keeps the historical order for files that
+/// carry a single marker of each kind.
 pub(crate) fn detect_sql_dialect_with_trace(content: &str) -> (Option<SqlDialect>, String) {
-    if MYSQL_DELIMITER_RE.is_match(content) {
-        return (
-            Some(SqlDialect::Mysql),
-            "Detected `sqlmysql` because the file contains a `DELIMITER //` directive.".to_string(),
-        );
-    }
-    if SQLITE_BEGIN_ATOMIC_RE.is_match(content) {
-        return (
-            Some(SqlDialect::Sqlite),
-            "Detected `sqlsqlite` because the file contains `BEGIN ATOMIC`.".to_string(),
-        );
-    }
-    if POSTGRES_RETURNS_TABLE_RE.is_match(content) || content.contains("language plpgsql") {
+    let scores = [
+        (
+            SqlDialect::Mysql,
+            MYSQL_DELIMITER_RE.find_iter(content).count(),
+        ),
+        (
+            SqlDialect::Sqlite,
+            SQLITE_BEGIN_ATOMIC_RE.find_iter(content).count(),
+        ),
+        (
+            SqlDialect::Postgres,
+            POSTGRES_RETURNS_TABLE_RE.find_iter(content).count()
+                + POSTGRES_PLPGSQL_RE.find_iter(content).count(),
+        ),
+    ];
+    // `max_by_key` keeps the last maximum, so iterate in reverse to let earlier entries win ties.
+    let Some((dialect, _)) = scores
+        .iter()
+        .rev()
+        .copied()
+        .max_by_key(|(_, score)| *score)
+        .filter(|(_, score)| *score > 0)
+    else {
         return (
-            Some(SqlDialect::Postgres),
-            "Detected `sqlpostgres` because the file contains PostgreSQL-specific `RETURNS TABLE` or `LANGUAGE plpgsql` syntax."
-                .to_string(),
+            None,
+            "No dialect-specific SQL heuristic matched; falling back to `sqlgeneric`.".to_string(),
         );
-    }
+    };
+
+    let tally = scores
+        .iter()
+        .map(|(dialect, score)| format!("{}={score}", dialect.key()))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let reason = match dialect {
+        SqlDialect::Mysql => "`DELIMITER //` directives",
+        SqlDialect::Sqlite => "`BEGIN ATOMIC` blocks",
+        _ => "PostgreSQL-specific `RETURNS TABLE` or `LANGUAGE plpgsql` syntax",
+    };
     (
-        None,
-        "No dialect-specific SQL heuristic matched; falling back to `sqlgeneric`.".to_string(),
+        Some(dialect),
+        format!(
+            "Detected `{}` from {reason} (marker counts: {tally}).",
+            dialect.key()
+        ),
     )
 }
 
@@ -199,3 +241,5 @@ static SQLITE_BEGIN_ATOMIC_RE: Lazy<Regex> =
     Lazy::new(|| Regex::new("(?i)begin\\s+atomic").unwrap());
 static POSTGRES_RETURNS_TABLE_RE: Lazy<Regex> =
     Lazy::new(|| Regex::new("(?i)returns\\s+table").unwrap());
+static POSTGRES_PLPGSQL_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new("(?i)language\\s+plpgsql").unwrap());
@@ -2,6 +2,7 @@ use super::PredicateEvaluator;
 use crate::evaluator::{FileContext, MatchResult};
 use crate::parser::PredicateKey;
 use anyhow::Result;
+use regex::RegexBuilder;
 use tree_sitter::Range;
 
 pub(super) struct ContainsEvaluator;
@@ -13,10 +14,25 @@ impl PredicateEvaluator for ContainsEvaluator {
         _key: &PredicateKey,
         value: &str,
     ) -> Result<MatchResult> {
+        let (skip, take) = context.line_window();
+        // Whole-word matching needs boundaries, so it switches to a bounded regex.
+        let word = context
+            .whole_word()
+            .then(|| {
+                RegexBuilder::new(&format!(r"\b{}\b", regex::escape(value)))
+                    .case_insensitive(true)
+                    .build()
+            })
+            .transpose()?;
+        let needle = value.to_lowercase();
         let content = context.get_content()?;
         let mut ranges = Vec::new();
-        for (i, line) in content.lines().enumerate() {
-            if line.to_lowercase().contains(&value.to_lowercase()) {
+        for (i, line) in content.lines().enumerate().skip(skip).take(take) {
+            let found = match &word {
+                Some(word) => word.is_match(line),
+                None => line.to_lowercase().contains(&needle),
+            };
+            if found {
                 let start_byte = content.lines().take(i).map(|l| l.len() + 1).sum();
                 let end_byte = start_byte + line.len();
                 let range = Range {
@@ -64,4 +80,25 @@ mod tests {
             .unwrap()
             .is_match());
     }
+
+    #[test]
+    fn test_contains_evaluator_whole_word() {
+        let file = create_temp_file("category = 1\nconcat(a)\n");
+        let mut context = FileContext::new(file.path().to_path_buf(), PathBuf::from("/"));
+        context.set_whole_word(true);
+        let evaluator = ContainsEvaluator;
+        assert!(!evaluator
+            .evaluate(&mut context, &PredicateKey::Contains, "cat")
+            .unwrap()
+            .is_match());
+        assert!(evaluator
+            .evaluate(&mut context, &PredicateKey::Contains, "Category")
+            .unwrap()
+            .is_match());
+        // The term is escaped, so regex syntax in it stays literal.
+        assert!(evaluator
+            .evaluate(&mut context, &PredicateKey::Contains, "concat(a")
+            .unwrap()
+            .is_match());
+    }
 }
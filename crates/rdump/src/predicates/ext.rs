@@ -3,6 +3,8 @@ use crate::evaluator::{FileContext, MatchResult};
 use crate::parser::PredicateKey;
 use anyhow::Result;
 
+/// Matches the file extension case-insensitively. A comma-separated value (`ext:rs,go`)
+/// matches any of the listed extensions.
 pub(super) struct ExtEvaluator;
 impl PredicateEvaluator for ExtEvaluator {
     fn evaluate(
@@ -16,7 +18,10 @@ impl PredicateEvaluator for ExtEvaluator {
             .extension()
             .and_then(|s| s.to_str())
             .unwrap_or("");
-        Ok(MatchResult::Boolean(file_ext.eq_ignore_ascii_case(value)))
+        Ok(MatchResult::Boolean(value.split(',').any(|ext| {
+            let ext = ext.trim();
+            !ext.is_empty() && file_ext.eq_ignore_ascii_case(ext)
+        })))
     }
 }
 
@@ -60,4 +65,25 @@ mod tests {
             "Dotfiles should have no extension"
         );
     }
+
+    #[test]
+    fn test_ext_evaluator_comma_separated_list() {
+        let evaluator = ExtEvaluator;
+        for (file, expected) in [("main.rs", true), ("main.GO", true), ("main.py", false)] {
+            let mut context = FileContext::new(PathBuf::from(file), PathBuf::from("/"));
+            assert_eq!(
+                evaluator
+                    .evaluate(&mut context, &PredicateKey::Ext, "rs,go")
+                    .unwrap()
+                    .is_match(),
+                expected,
+                "{file}"
+            );
+        }
+        let mut context_no_ext = FileContext::new(PathBuf::from("README"), PathBuf::from("/"));
+        assert!(!evaluator
+            .evaluate(&mut context_no_ext, &PredicateKey::Ext, "rs,")
+            .unwrap()
+            .is_match());
+    }
 }
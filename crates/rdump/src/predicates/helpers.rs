@@ -52,7 +52,26 @@ pub(crate) struct ParsedModifiedPredicate {
     pub value: ParsedTimeValue,
 }
 
+/// A `low..high` size value: at least `min_size_bytes` and below `max_size_bytes`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct ParsedSizeRange {
+    pub min_size_bytes: Option<u64>,
+    pub max_size_bytes: Option<u64>,
+}
+
+/// A `from..to` modified value: at or after `from` and before `to`. Relative bounds are
+/// points in the past, so `7d..1d` spans from seven days ago to one day ago.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct ParsedModifiedRange {
+    pub from: Option<ParsedTimeValue>,
+    pub to: Option<ParsedTimeValue>,
+}
+
 pub(crate) fn parse_and_compare_size(file_size: u64, query: &str) -> Result<bool> {
+    if let Some(range) = parse_size_range(query)? {
+        return Ok(range.min_size_bytes.is_none_or(|min| file_size >= min)
+            && range.max_size_bytes.is_none_or(|max| file_size < max));
+    }
     let parsed = parse_size_predicate(query)?;
 
     match parsed.operator {
@@ -63,6 +82,17 @@ pub(crate) fn parse_and_compare_size(file_size: u64, query: &str) -> Result<bool
 }
 
 pub(crate) fn parse_and_compare_time(modified_time: SystemTime, query: &str) -> Result<bool> {
+    if let Some(range) = parse_modified_range(query)? {
+        let after_from = match &range.from {
+            Some(from) => modified_time >= threshold_system_time(from)?,
+            None => true,
+        };
+        let before_to = match &range.to {
+            Some(to) => modified_time < threshold_system_time(to)?,
+            None => true,
+        };
+        return Ok(after_from && before_to);
+    }
     let parsed = parse_modified_predicate(query)?;
     let threshold_time = threshold_system_time(&parsed.value)?;
 
@@ -89,6 +119,66 @@ pub(crate) fn parse_and_compare_time(modified_time: SystemTime, query: &str) ->
 pub(crate) fn parse_size_predicate(query: &str) -> Result<ParsedSizePredicate> {
     let query = query.trim();
     let (operator, size_str) = parse_operator_prefix(query)?;
+    let (numeric_value, unit, target_size_bytes) = parse_size_value(size_str)?;
+
+    Ok(ParsedSizePredicate {
+        operator,
+        raw_value: query.to_string(),
+        numeric_value,
+        unit,
+        target_size_bytes,
+    })
+}
+
+/// Parses `low..high`, `low..`, or `..high` sizes. Returns `None` for values without `..`.
+pub(crate) fn parse_size_range(query: &str) -> Result<Option<ParsedSizeRange>> {
+    let Some((low, high)) = split_range(query)? else {
+        return Ok(None);
+    };
+    let bound = |value: Option<&str>| -> Result<Option<u64>> {
+        value
+            .map(|value| parse_size_value(value).map(|(_, _, bytes)| bytes))
+            .transpose()
+    };
+    Ok(Some(ParsedSizeRange {
+        min_size_bytes: bound(low)?,
+        max_size_bytes: bound(high)?,
+    }))
+}
+
+/// Parses `from..to`, `from..`, or `..to` times. Returns `None` for values without `..`.
+pub(crate) fn parse_modified_range(query: &str) -> Result<Option<ParsedModifiedRange>> {
+    let Some((from, to)) = split_range(query)? else {
+        return Ok(None);
+    };
+    Ok(Some(ParsedModifiedRange {
+        from: from.map(parse_time_value).transpose()?,
+        to: to.map(parse_time_value).transpose()?,
+    }))
+}
+
+/// Splits `low..high` into its bounds, either of which may be left empty. Bounds take no
+/// comparison operator.
+fn split_range(query: &str) -> Result<Option<(Option<&str>, Option<&str>)>> {
+    let Some((low, high)) = query.trim().split_once("..") else {
+        return Ok(None);
+    };
+    let bound = |value: &str| -> Result<Option<&str>> {
+        let value = value.trim();
+        if value.starts_with(['>', '<', '=']) {
+            return Err(anyhow!("Range bounds take no operator: '{query}'"));
+        }
+        Ok((!value.is_empty()).then_some(value))
+    };
+    let (low, high) = (bound(low)?, bound(high)?);
+    if low.is_none() && high.is_none() {
+        return Err(anyhow!("Range needs at least one bound: '{query}'"));
+    }
+    Ok(Some((low, high)))
+}
+
+/// Parses a size such as `10kb` into its number, unit, and byte count.
+fn parse_size_value(size_str: &str) -> Result<(f64, String, u64)> {
     let size_str = size_str.trim().to_lowercase();
     let (num_str, unit) = size_str.split_at(
         size_str
@@ -106,20 +196,24 @@ pub(crate) fn parse_size_predicate(query: &str) -> Result<ParsedSizePredicate> {
         _ => return Err(anyhow!("Invalid size unit: {unit}")),
     };
 
-    Ok(ParsedSizePredicate {
-        operator,
-        raw_value: query.to_string(),
-        numeric_value,
-        unit,
-        target_size_bytes: (numeric_value * multiplier) as u64,
-    })
+    Ok((numeric_value, unit, (numeric_value * multiplier) as u64))
 }
 
 pub(crate) fn parse_modified_predicate(query: &str) -> Result<ParsedModifiedPredicate> {
     let query = query.trim();
     let (operator, time_str) = parse_operator_prefix(query)?;
-    let time_str = time_str.trim();
+    let value = parse_time_value(time_str)?;
+
+    Ok(ParsedModifiedPredicate {
+        operator,
+        raw_value: query.to_string(),
+        value,
+    })
+}
 
+/// Parses a relative (`7d`) or absolute (`2024-01-01`) time.
+fn parse_time_value(time_str: &str) -> Result<ParsedTimeValue> {
+    let time_str = time_str.trim();
     let value = if let Ok((duration, amount, unit)) = parse_relative_time(time_str) {
         ParsedTimeValue::Relative {
             amount,
@@ -138,12 +232,7 @@ pub(crate) fn parse_modified_predicate(query: &str) -> Result<ParsedModifiedPred
     } else {
         return Err(anyhow!("Invalid date format: '{time_str}'"));
     };
-
-    Ok(ParsedModifiedPredicate {
-        operator,
-        raw_value: query.to_string(),
-        value,
-    })
+    Ok(value)
 }
 
 fn parse_operator_prefix(query: &str) -> Result<(PredicateOperator, &str)> {
@@ -312,6 +401,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_and_compare_time_ranges() {
+        let now = SystemTime::now();
+        let three_days_ago = now - Duration::from_secs(3 * 86400);
+        assert!(parse_and_compare_time(three_days_ago, "7d..1d").unwrap());
+        assert!(!parse_and_compare_time(now, "7d..1d").unwrap());
+        assert!(parse_and_compare_time(now, "1d..").unwrap());
+        assert!(!parse_and_compare_time(three_days_ago, "1d..").unwrap());
+
+        let new_year = parse_absolute_time("2024-01-01").unwrap().0;
+        assert!(parse_and_compare_time(new_year, "2024-01-01..2024-02-01").unwrap());
+        assert!(!parse_and_compare_time(new_year, "2023-12-01..2024-01-01").unwrap());
+        assert!(parse_and_compare_time(new_year, "..2024-01-02").unwrap());
+        assert!(parse_and_compare_time(new_year, "..").is_err());
+    }
+
     #[test]
     fn test_parse_modified_predicate_reports_absolute_shape() {
         let parsed = parse_modified_predicate("=2024-01-01").unwrap();
@@ -1,12 +1,59 @@
-use anyhow::Result;
-use globset::Glob;
-use std::path::PathBuf;
+use anyhow::{anyhow, Result};
+use globset::{Glob, GlobMatcher};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 
 use super::PredicateEvaluator;
 use crate::evaluator::{FileContext, MatchResult};
 use crate::parser::PredicateKey;
 
-pub(super) struct InPathEvaluator;
+/// Matches files directly inside a directory, or inside any directory matching a glob
+/// (`in:**/handlers`, `in:src/**/api`). Globs are compiled once per value and reused for every
+/// file in the search.
+#[derive(Default)]
+pub(super) struct InPathEvaluator {
+    globs: Mutex<HashMap<String, Arc<GlobMatcher>>>,
+}
+
+impl InPathEvaluator {
+    fn glob(&self, pattern: &str) -> Result<Arc<GlobMatcher>> {
+        let mut globs = self
+            .globs
+            .lock()
+            .map_err(|_| anyhow!("in: glob cache lock poisoned"))?;
+        if let Some(glob) = globs.get(pattern) {
+            return Ok(Arc::clone(glob));
+        }
+        let glob = Arc::new(Glob::new(pattern)?.compile_matcher());
+        globs.insert(pattern.to_string(), Arc::clone(&glob));
+        Ok(glob)
+    }
+
+    /// Globs match the file's directory, or the file itself so `in:src/**` covers every depth.
+    /// Relative globs are matched against root-relative paths and absolute globs against
+    /// resolved paths. A trailing `/` is ignored, as it is for plain directories.
+    fn is_in_glob(&self, context: &FileContext, pattern: &str) -> Result<bool> {
+        let glob = self.glob(pattern.trim_end_matches('/'))?;
+        let absolute = Path::new(pattern).is_absolute();
+        let target = |path: &Path| -> PathBuf {
+            if absolute {
+                path.to_path_buf()
+            } else {
+                path.strip_prefix(context.root_path())
+                    .unwrap_or(path)
+                    .to_path_buf()
+            }
+        };
+        let path = context.resolved_path();
+        if glob.is_match(target(path)) {
+            return Ok(true);
+        }
+        Ok(path
+            .parent()
+            .is_some_and(|parent| glob.is_match(target(parent))))
+    }
+}
 
 impl PredicateEvaluator for InPathEvaluator {
     fn evaluate(
@@ -15,56 +62,79 @@ impl PredicateEvaluator for InPathEvaluator {
         _key: &PredicateKey,
         value: &str,
     ) -> Result<MatchResult> {
-        // Check for glob metacharacters to switch between logic paths.
-        if value.contains('*') || value.contains('?') || value.contains('[') || value.contains('{')
-        {
-            let glob = Glob::new(value)?.compile_matcher();
-            let relative_path = context
-                .root_relative_path()
-                .unwrap_or(context.resolved_path());
-            if glob.is_match(relative_path) {
-                return Ok(MatchResult::Boolean(true));
-            }
-
-            if let Some(parent) = context.resolved_path().parent() {
-                let relative_parent = parent.strip_prefix(context.root_path()).unwrap_or(parent);
-                Ok(MatchResult::Boolean(glob.is_match(relative_parent)))
-            } else {
-                Ok(MatchResult::Boolean(false))
-            }
-        } else {
-            // --- Non-recursive Exact-Path Logic ---
-            let target_dir = PathBuf::from(value);
-            let absolute_target_dir = if target_dir.is_absolute() {
-                target_dir
+        for directory in split_directories(value) {
+            let directory = crate::expand_tilde_str(directory);
+            let is_match = if is_glob(&directory) {
+                self.is_in_glob(context, &directory)?
             } else {
-                context.root_path().join(target_dir)
+                is_in_directory(context, &directory)?
             };
-
-            let canonical_target = match context.normalized_path(&absolute_target_dir) {
-                Ok(identity) => identity.resolved_path,
-                Err(_) => return Ok(MatchResult::Boolean(false)),
-            };
-            let Ok(target_metadata) = context.backend().stat(&canonical_target) else {
-                return Ok(MatchResult::Boolean(false));
-            };
-            if target_metadata.file_type != crate::backend::BackendFileType::Directory {
-                return Ok(MatchResult::Boolean(false));
+            if is_match {
+                return Ok(MatchResult::Boolean(true));
             }
+        }
+        Ok(MatchResult::Boolean(false))
+    }
+}
 
-            if let Some(file_parent) = context.resolved_path().parent() {
-                let canonical_file_parent = match context.normalized_path(file_parent) {
-                    Ok(identity) => identity.resolved_path,
-                    Err(_) => return Ok(MatchResult::Boolean(false)),
-                };
-                Ok(MatchResult::Boolean(
-                    canonical_file_parent == canonical_target,
-                ))
-            } else {
-                Ok(MatchResult::Boolean(false))
+fn is_glob(value: &str) -> bool {
+    value.contains('*') || value.contains('?') || value.contains('[') || value.contains('{')
+}
+
+/// Splits a comma-separated `in:` value into its directories. Commas inside glob braces
+/// (`in:{src,lib}/api`) belong to the glob and do not split.
+pub(super) fn split_directories(value: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (index, ch) in value.char_indices() {
+        match ch {
+            '{' => depth += 1,
+            '}' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(&value[start..index]);
+                start = index + 1;
             }
+            _ => {}
         }
     }
+    parts.push(&value[start..]);
+    parts
+        .into_iter()
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .collect()
+}
+
+fn is_in_directory(context: &mut FileContext, value: &str) -> Result<bool> {
+    // --- Non-recursive Exact-Path Logic ---
+    let target_dir = PathBuf::from(value);
+    let absolute_target_dir = if target_dir.is_absolute() {
+        target_dir
+    } else {
+        context.root_path().join(target_dir)
+    };
+
+    let canonical_target = match context.normalized_path(&absolute_target_dir) {
+        Ok(identity) => identity.resolved_path,
+        Err(_) => return Ok(false),
+    };
+    let Ok(target_metadata) = context.backend().stat(&canonical_target) else {
+        return Ok(false);
+    };
+    if target_metadata.file_type != crate::backend::BackendFileType::Directory {
+        return Ok(false);
+    }
+
+    if let Some(file_parent) = context.resolved_path().parent() {
+        let canonical_file_parent = match context.normalized_path(file_parent) {
+            Ok(identity) => identity.resolved_path,
+            Err(_) => return Ok(false),
+        };
+        Ok(canonical_file_parent == canonical_target)
+    } else {
+        Ok(false)
+    }
 }
 
 #[cfg(test)]
@@ -75,7 +145,7 @@ mod tests {
 
     #[test]
     fn test_in_path_evaluator_exact() -> Result<()> {
-        let evaluator = InPathEvaluator;
+        let evaluator = InPathEvaluator::default();
 
         // Create a temporary directory structure
         let root_dir = tempdir()?;
@@ -149,9 +219,38 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_in_path_evaluator_multiple_directories() -> Result<()> {
+        let evaluator = InPathEvaluator::default();
+        let root_dir = tempdir()?;
+        let root_path = root_dir.path();
+        fs::create_dir_all(root_path.join("src"))?;
+        fs::create_dir_all(root_path.join("lib"))?;
+        let file = root_path.join("lib").join("util.rs");
+        fs::write(&file, "")?;
+
+        let mut context = FileContext::new(file, root_path.to_path_buf());
+        assert!(evaluator
+            .evaluate(&mut context, &PredicateKey::In, "src,lib")?
+            .is_match());
+        assert!(evaluator
+            .evaluate(&mut context, &PredicateKey::In, "src, l?b")?
+            .is_match());
+        assert!(!evaluator
+            .evaluate(&mut context, &PredicateKey::In, "src,tests")?
+            .is_match());
+
+        assert_eq!(split_directories("src, lib,"), vec!["src", "lib"]);
+        assert_eq!(
+            split_directories("{src,lib}/api,docs"),
+            vec!["{src,lib}/api", "docs"]
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_in_path_evaluator_wildcard() -> Result<()> {
-        let evaluator = InPathEvaluator;
+        let evaluator = InPathEvaluator::default();
 
         // Create a temporary directory structure
         let root_dir = tempdir()?;
@@ -228,7 +327,7 @@ mod tests {
 
     #[test]
     fn test_in_path_evaluator_relative_wildcard() -> Result<()> {
-        let evaluator = InPathEvaluator;
+        let evaluator = InPathEvaluator::default();
 
         // Create a temporary directory structure
         let root_dir = tempdir()?;
@@ -264,7 +363,7 @@ mod tests {
 
     #[test]
     fn test_in_path_evaluator_advanced_wildcards() -> Result<()> {
-        let evaluator = InPathEvaluator;
+        let evaluator = InPathEvaluator::default();
         let root_dir = tempdir()?;
         let root_path = root_dir.path();
 
@@ -331,7 +430,7 @@ mod tests {
 
     #[test]
     fn test_in_path_evaluator_user_scenario() -> Result<()> {
-        let evaluator = InPathEvaluator;
+        let evaluator = InPathEvaluator::default();
         let root_dir = tempdir()?;
         let root_path = root_dir.path();
 
@@ -385,10 +484,35 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_in_path_evaluator_glob_trailing_slash_and_absolute() -> Result<()> {
+        let evaluator = InPathEvaluator::default();
+        let root_dir = tempdir()?;
+        let root_path = root_dir.path();
+        let handlers = root_path.join("src").join("api").join("handlers");
+        fs::create_dir_all(&handlers)?;
+        let file = handlers.join("users.rs");
+        fs::write(&file, "")?;
+        let mut context = FileContext::new(file, root_path.to_path_buf());
+
+        assert!(evaluator
+            .evaluate(&mut context, &PredicateKey::In, "src/**/handlers/")?
+            .is_match());
+        let absolute = format!("{}/**/handlers", root_path.display());
+        assert!(evaluator
+            .evaluate(&mut context, &PredicateKey::In, &absolute)?
+            .is_match());
+        let absolute_other = format!("{}/**/models", root_path.display());
+        assert!(!evaluator
+            .evaluate(&mut context, &PredicateKey::In, &absolute_other)?
+            .is_match());
+        Ok(())
+    }
+
     #[test]
     fn test_in_path_evaluator_root_file_no_parent() -> Result<()> {
         // This tests lines 34 and 63 - file at root with no real parent
-        let evaluator = InPathEvaluator;
+        let evaluator = InPathEvaluator::default();
 
         let root_dir = tempdir()?;
         let root_path = root_dir.path();
@@ -413,7 +537,7 @@ mod tests {
     #[test]
     fn test_in_path_evaluator_canonicalize_failure() -> Result<()> {
         // This tests lines 51 and 57 - canonicalize errors
-        let evaluator = InPathEvaluator;
+        let evaluator = InPathEvaluator::default();
 
         let root_dir = tempdir()?;
         let root_path = root_dir.path();
@@ -3,7 +3,7 @@ use crate::evaluator::{FileContext, MatchResult};
 use crate::limits::MAX_REGEX_EVAL_DURATION;
 use crate::parser::PredicateKey;
 use anyhow::Result;
-use regex::RegexBuilder;
+use regex::Regex;
 use std::time::Instant;
 use tree_sitter::Range;
 
@@ -15,14 +15,25 @@ impl PredicateEvaluator for MatchesEvaluator {
         _key: &PredicateKey,
         value: &str,
     ) -> Result<MatchResult> {
+        let (skip, take) = context.line_window();
+        let flags = context.regex_flags();
+        let re = if context.whole_word() {
+            context
+                .regex_builder(&format!(r"\b(?:{value})\b"))
+                .build()?
+        } else {
+            context.regex_builder(value).build()?
+        };
         let content = context.get_content()?;
-        let re = RegexBuilder::new(value)
-            .size_limit(10 * 1024 * 1024) // Cap regex state to avoid pathological patterns
-            .build()?;
+        if flags.dotall || flags.multiline {
+            return Ok(MatchResult::Hunks(spanning_ranges(
+                &re, content, skip, take,
+            )?));
+        }
 
         let mut ranges = Vec::new();
         let start = Instant::now();
-        for (i, line) in content.lines().enumerate() {
+        for (i, line) in content.lines().enumerate().skip(skip).take(take) {
             if i % 100 == 0 && start.elapsed() > MAX_REGEX_EVAL_DURATION {
                 anyhow::bail!("Regex evaluation timed out");
             }
@@ -44,6 +55,67 @@ impl PredicateEvaluator for MatchesEvaluator {
     }
 }
 
+/// Matches `re` against the whole line window at once, for dotall and multiline patterns that
+/// may span lines. Each match is widened to the full lines it touches, and matches that share
+/// a line are merged into one range.
+fn spanning_ranges(re: &Regex, content: &str, skip: usize, take: usize) -> Result<Vec<Range>> {
+    let line_starts: Vec<usize> = std::iter::once(0)
+        .chain(content.match_indices('\n').map(|(index, _)| index + 1))
+        .collect();
+    let row_of = |byte: usize| line_starts.partition_point(|&start| start <= byte) - 1;
+    let line_end = |row: usize| {
+        let end = line_starts
+            .get(row + 1)
+            .map_or(content.len(), |&next| next - 1);
+        if content[..end].ends_with('\r') {
+            end - 1
+        } else {
+            end
+        }
+    };
+
+    let window_start = line_starts.get(skip).copied().unwrap_or(content.len());
+    let window_end = skip
+        .checked_add(take)
+        .and_then(|end| line_starts.get(end))
+        .copied()
+        .unwrap_or(content.len());
+
+    let mut ranges: Vec<Range> = Vec::new();
+    let started = Instant::now();
+    for found in re.find_iter(&content[window_start..window_end]) {
+        if started.elapsed() > MAX_REGEX_EVAL_DURATION {
+            anyhow::bail!("Regex evaluation timed out");
+        }
+        let start_row = row_of(window_start + found.start());
+        let end_row = row_of(window_start + found.end().max(found.start() + 1) - 1);
+        let end_byte = line_end(end_row);
+        let end_point = tree_sitter::Point {
+            row: end_row,
+            column: end_byte - line_starts[end_row],
+        };
+        if let Some(last) = ranges.last_mut() {
+            if last.end_point.row >= start_row {
+                if end_row > last.end_point.row {
+                    last.end_byte = end_byte;
+                    last.end_point = end_point;
+                }
+                continue;
+            }
+        }
+        ranges.push(Range {
+            start_byte: line_starts[start_row],
+            end_byte,
+            start_point: tree_sitter::Point {
+                row: start_row,
+                column: 0,
+            },
+            end_point,
+        });
+    }
+    Ok(ranges)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -85,4 +157,46 @@ mod tests {
             .unwrap()
             .is_match());
     }
+
+    #[test]
+    fn test_matches_dotall_spans_lines() {
+        let file = create_temp_file("intro\nfn main() {\n    run();\n}\noutro\n");
+        let mut context = FileContext::new(file.path().to_path_buf(), PathBuf::from("/"));
+        let evaluator = MatchesEvaluator;
+        let pattern = r"fn main\(\).*run\(\)";
+        assert!(!evaluator
+            .evaluate(&mut context, &PredicateKey::Matches, pattern)
+            .unwrap()
+            .is_match());
+
+        context.set_regex_flags(rdump_contracts::RegexFlags {
+            dotall: true,
+            ..Default::default()
+        });
+        let MatchResult::Hunks(ranges) = evaluator
+            .evaluate(&mut context, &PredicateKey::Matches, pattern)
+            .unwrap()
+        else {
+            panic!("expected hunks");
+        };
+        assert_eq!(ranges.len(), 1);
+        assert_eq!(ranges[0].start_point.row, 1);
+        assert_eq!(ranges[0].end_point.row, 2);
+        assert_eq!(ranges[0].start_byte, "intro\n".len());
+        assert_eq!(ranges[0].end_byte, "intro\nfn main() {\n    run();".len());
+    }
+
+    #[test]
+    fn test_matches_case_insensitive_flag() {
+        let file = create_temp_file("const API_KEY = 1;\n");
+        let mut context = FileContext::new(file.path().to_path_buf(), PathBuf::from("/"));
+        context.set_regex_flags(rdump_contracts::RegexFlags {
+            case_insensitive: true,
+            ..Default::default()
+        });
+        assert!(MatchesEvaluator
+            .evaluate(&mut context, &PredicateKey::Matches, "api_key")
+            .unwrap()
+            .is_match());
+    }
 }
@@ -60,18 +60,40 @@ pub fn semantic_predicate_keys() -> Vec<PredicateKey> {
         PredicateKey::Import,
         PredicateKey::Class,
         PredicateKey::Struct,
+        PredicateKey::Field,
         PredicateKey::Enum,
+        PredicateKey::EnumVariant,
         PredicateKey::Interface,
+        PredicateKey::InterfaceMethod,
         PredicateKey::Trait,
+        PredicateKey::TraitMethod,
         PredicateKey::Type,
         PredicateKey::Impl,
+        PredicateKey::Implements,
         PredicateKey::Macro,
         PredicateKey::Module,
+        PredicateKey::Namespace,
         PredicateKey::Object,
         PredicateKey::Protocol,
+        PredicateKey::Template,
+        PredicateKey::Service,
         PredicateKey::Comment,
+        PredicateKey::Doc,
         PredicateKey::Str,
+        PredicateKey::StrExact,
         PredicateKey::Call,
+        PredicateKey::DefnBody,
+        PredicateKey::Target,
+        PredicateKey::Stage,
+        PredicateKey::From,
+        PredicateKey::Block,
+        PredicateKey::Route,
+        PredicateKey::Property,
+        PredicateKey::Extends,
+        PredicateKey::Test,
+        PredicateKey::Decorator,
+        PredicateKey::Heading,
+        PredicateKey::Link,
     ]
 }
 
@@ -91,16 +113,27 @@ pub fn validate_predicate_value(key: &PredicateKey, value: &str) -> Result<()> {
             if value.is_empty() {
                 anyhow::bail!("Invalid glob pattern: cannot be empty.");
             }
-            Pattern::new(value)?;
+            if let Some(source) = name::regex_source(value) {
+                name::compile_name_regex(source)?;
+            } else {
+                Pattern::new(value)?;
+            }
             Ok(())
         }
         PredicateKey::Path | PredicateKey::In => {
-            if value.contains('*')
-                || value.contains('?')
-                || value.contains('[')
-                || value.contains('{')
-            {
-                Glob::new(value)?;
+            let parts = if *key == PredicateKey::In {
+                in_path::split_directories(value)
+            } else {
+                vec![value]
+            };
+            for part in parts {
+                if part.contains('*')
+                    || part.contains('?')
+                    || part.contains('[')
+                    || part.contains('{')
+                {
+                    Glob::new(part)?;
+                }
             }
             Ok(())
         }
@@ -130,10 +163,10 @@ pub fn create_metadata_predicate_registry(
         HashMap::new();
 
     registry.insert(PredicateKey::Ext, Box::new(ExtEvaluator));
-    registry.insert(PredicateKey::Name, Box::new(NameEvaluator));
+    registry.insert(PredicateKey::Name, Box::new(NameEvaluator::default()));
     registry.insert(PredicateKey::Path, Box::new(PathEvaluator));
     registry.insert(PredicateKey::PathExact, Box::new(PathEvaluator));
-    registry.insert(PredicateKey::In, Box::new(InPathEvaluator));
+    registry.insert(PredicateKey::In, Box::new(InPathEvaluator::default()));
     registry.insert(PredicateKey::Size, Box::new(SizeEvaluator));
     registry.insert(PredicateKey::Modified, Box::new(ModifiedEvaluator));
 
@@ -164,18 +197,40 @@ pub fn create_predicate_registry_with_settings(
     registry.insert(PredicateKey::Import, code_evaluator.clone());
     registry.insert(PredicateKey::Class, code_evaluator.clone());
     registry.insert(PredicateKey::Struct, code_evaluator.clone());
+    registry.insert(PredicateKey::Field, code_evaluator.clone());
     registry.insert(PredicateKey::Enum, code_evaluator.clone());
+    registry.insert(PredicateKey::EnumVariant, code_evaluator.clone());
     registry.insert(PredicateKey::Interface, code_evaluator.clone());
+    registry.insert(PredicateKey::InterfaceMethod, code_evaluator.clone());
     registry.insert(PredicateKey::Trait, code_evaluator.clone());
+    registry.insert(PredicateKey::TraitMethod, code_evaluator.clone());
     registry.insert(PredicateKey::Type, code_evaluator.clone());
     registry.insert(PredicateKey::Impl, code_evaluator.clone());
+    registry.insert(PredicateKey::Implements, code_evaluator.clone());
     registry.insert(PredicateKey::Macro, code_evaluator.clone());
     registry.insert(PredicateKey::Module, code_evaluator.clone());
+    registry.insert(PredicateKey::Namespace, code_evaluator.clone());
     registry.insert(PredicateKey::Object, code_evaluator.clone());
     registry.insert(PredicateKey::Protocol, code_evaluator.clone());
+    registry.insert(PredicateKey::Template, code_evaluator.clone());
+    registry.insert(PredicateKey::Service, code_evaluator.clone());
     registry.insert(PredicateKey::Comment, code_evaluator.clone());
+    registry.insert(PredicateKey::Doc, code_evaluator.clone());
     registry.insert(PredicateKey::Str, code_evaluator.clone());
+    registry.insert(PredicateKey::StrExact, code_evaluator.clone());
     registry.insert(PredicateKey::Call, code_evaluator.clone());
+    registry.insert(PredicateKey::DefnBody, code_evaluator.clone());
+    registry.insert(PredicateKey::Target, code_evaluator.clone());
+    registry.insert(PredicateKey::Stage, code_evaluator.clone());
+    registry.insert(PredicateKey::From, code_evaluator.clone());
+    registry.insert(PredicateKey::Block, code_evaluator.clone());
+    registry.insert(PredicateKey::Route, code_evaluator.clone());
+    registry.insert(PredicateKey::Property, code_evaluator.clone());
+    registry.insert(PredicateKey::Extends, code_evaluator.clone());
+    registry.insert(PredicateKey::Test, code_evaluator.clone());
+    registry.insert(PredicateKey::Decorator, code_evaluator.clone());
+    registry.insert(PredicateKey::Heading, code_evaluator.clone());
+    registry.insert(PredicateKey::Link, code_evaluator.clone());
     // Add React predicates
     registry.insert(PredicateKey::Component, code_evaluator.clone());
     registry.insert(PredicateKey::Element, code_evaluator.clone());
@@ -287,6 +342,44 @@ mod tests {
             .is_match());
     }
 
+    #[test]
+    fn test_code_aware_evaluator_rust_fields_and_variants() {
+        let rust_code = r#"
+            pub struct User {
+                name: String,
+            }
+            pub enum Status {
+                Pending,
+                Done(u8),
+            }
+        "#;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let file_path = temp_dir.path().join("model.rs");
+        let mut file = std::fs::File::create(&file_path).unwrap();
+        file.write_all(rust_code.as_bytes()).unwrap();
+
+        let evaluator = CodeAwareEvaluator::new(CodeAwareSettings::default());
+        let mut ctx =
+            FileContext::new(file_path.clone(), file_path.parent().unwrap().to_path_buf());
+        assert!(evaluator
+            .evaluate(&mut ctx, &PredicateKey::EnumVariant, "Pending")
+            .unwrap()
+            .is_match());
+        let mut ctx =
+            FileContext::new(file_path.clone(), file_path.parent().unwrap().to_path_buf());
+        assert!(!evaluator
+            .evaluate(&mut ctx, &PredicateKey::EnumVariant, "Status")
+            .unwrap()
+            .is_match());
+        let mut ctx =
+            FileContext::new(file_path.clone(), file_path.parent().unwrap().to_path_buf());
+        assert!(evaluator
+            .evaluate(&mut ctx, &PredicateKey::Field, "name")
+            .unwrap()
+            .is_match());
+    }
+
     #[test]
     fn test_code_aware_evaluator_not_found() {
         let rust_code = r#"
@@ -315,6 +408,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_code_aware_evaluator_str_exact_strips_quotes() {
+        let rust_code = r#"
+            const API_KEY: &str = "api_key";
+        "#;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let file_path = temp_dir.path().join("keys.rs");
+        let mut file = std::fs::File::create(&file_path).unwrap();
+        file.write_all(rust_code.as_bytes()).unwrap();
+
+        let evaluator = CodeAwareEvaluator::new(CodeAwareSettings::default());
+        let mut ctx =
+            FileContext::new(file_path.clone(), file_path.parent().unwrap().to_path_buf());
+        assert!(evaluator
+            .evaluate(&mut ctx, &PredicateKey::Str, "key")
+            .unwrap()
+            .is_match());
+        let mut ctx =
+            FileContext::new(file_path.clone(), file_path.parent().unwrap().to_path_buf());
+        assert!(
+            !evaluator
+                .evaluate(&mut ctx, &PredicateKey::StrExact, "key")
+                .unwrap()
+                .is_match(),
+            "str_exact should not match a substring of the literal"
+        );
+        let mut ctx =
+            FileContext::new(file_path.clone(), file_path.parent().unwrap().to_path_buf());
+        assert!(evaluator
+            .evaluate(&mut ctx, &PredicateKey::StrExact, "api_key")
+            .unwrap()
+            .is_match());
+    }
+
     #[test]
     fn test_code_aware_evaluator_python_suite() {
         let python_code = r#"
@@ -348,6 +476,21 @@ def process_data():
             .evaluate(&mut ctx, &PredicateKey::Class, "DataProcessor")
             .unwrap()
             .is_match());
+        let mut ctx =
+            FileContext::new(file_path.clone(), file_path.parent().unwrap().to_path_buf());
+        assert!(evaluator
+            .evaluate(&mut ctx, &PredicateKey::Field, "api_key")
+            .unwrap()
+            .is_match());
+        let mut ctx =
+            FileContext::new(file_path.clone(), file_path.parent().unwrap().to_path_buf());
+        assert!(
+            !evaluator
+                .evaluate(&mut ctx, &PredicateKey::Field, "proc")
+                .unwrap()
+                .is_match(),
+            "Plain local assignments are not fields"
+        );
 
         // --- Functions ---
         let mut ctx =
@@ -474,6 +617,7 @@ def process_data():
 
             interface User { id: number; }
             type ID = string | number;
+            enum Status { Pending, Done = "done" }
 
             class ApiClient {
                 // The URL for the API
@@ -508,6 +652,23 @@ def process_data():
             .evaluate(&mut ctx, &PredicateKey::Func, "fetchUser")
             .unwrap()
             .is_match());
+        for (key, value) in [
+            (PredicateKey::EnumVariant, "Pending"),
+            (PredicateKey::EnumVariant, "Done"),
+            (PredicateKey::Field, "url"),
+            (PredicateKey::Field, "id"),
+        ] {
+            let mut ctx =
+                FileContext::new(file_path.clone(), file_path.parent().unwrap().to_path_buf());
+            assert!(
+                evaluator
+                    .evaluate(&mut ctx, &key, value)
+                    .unwrap()
+                    .is_match(),
+                "Should find {}:{value}",
+                key.as_ref()
+            );
+        }
         let mut ctx =
             FileContext::new(file_path.clone(), file_path.parent().unwrap().to_path_buf());
         assert!(evaluator
@@ -585,6 +746,12 @@ def process_data():
             .is_match());
         let mut ctx =
             FileContext::new(file_path.clone(), file_path.parent().unwrap().to_path_buf());
+        assert!(evaluator
+            .evaluate(&mut ctx, &PredicateKey::Field, "ID")
+            .unwrap()
+            .is_match());
+        let mut ctx =
+            FileContext::new(file_path.clone(), file_path.parent().unwrap().to_path_buf());
         assert!(evaluator
             .evaluate(&mut ctx, &PredicateKey::Func, "Greet")
             .unwrap()
@@ -618,6 +785,10 @@ def process_data():
 
            // Represents a user
            public class User {
+               private String name;
+
+               enum Role { ADMIN, GUEST }
+
                public User() {
                    System.out.println("User created");
                }
@@ -647,6 +818,18 @@ def process_data():
             .is_match());
         let mut ctx =
             FileContext::new(file_path.clone(), file_path.parent().unwrap().to_path_buf());
+        assert!(evaluator
+            .evaluate(&mut ctx, &PredicateKey::Field, "name")
+            .unwrap()
+            .is_match());
+        let mut ctx =
+            FileContext::new(file_path.clone(), file_path.parent().unwrap().to_path_buf());
+        assert!(evaluator
+            .evaluate(&mut ctx, &PredicateKey::EnumVariant, "GUEST")
+            .unwrap()
+            .is_match());
+        let mut ctx =
+            FileContext::new(file_path.clone(), file_path.parent().unwrap().to_path_buf());
         assert!(evaluator
             .evaluate(&mut ctx, &PredicateKey::Call, "println")
             .unwrap()
@@ -3,8 +3,48 @@ use crate::evaluator::{FileContext, MatchResult};
 use crate::parser::PredicateKey;
 use anyhow::{anyhow, Result};
 use glob::{MatchOptions, Pattern};
+use regex::{Regex, RegexBuilder};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// Matches file names against a case-insensitive glob, or against a case-insensitive regex
+/// when the value is wrapped in slashes (`/^test_.*\.rs$/`). Regexes are compiled once per
+/// value and reused for every file in the search.
+#[derive(Default)]
+pub(super) struct NameEvaluator {
+    regexes: Mutex<HashMap<String, Arc<Regex>>>,
+}
+
+/// Returns the regex source for slash-wrapped `name:` values.
+pub(super) fn regex_source(value: &str) -> Option<&str> {
+    value
+        .strip_prefix('/')
+        .and_then(|rest| rest.strip_suffix('/'))
+        .filter(|source| !source.is_empty())
+}
+
+pub(super) fn compile_name_regex(source: &str) -> Result<Regex> {
+    Ok(RegexBuilder::new(source)
+        .case_insensitive(true)
+        .size_limit(10 * 1024 * 1024)
+        .build()?)
+}
+
+impl NameEvaluator {
+    fn regex(&self, source: &str) -> Result<Arc<Regex>> {
+        let mut regexes = self
+            .regexes
+            .lock()
+            .map_err(|_| anyhow!("name regex cache lock poisoned"))?;
+        if let Some(regex) = regexes.get(source) {
+            return Ok(Arc::clone(regex));
+        }
+        let regex = Arc::new(compile_name_regex(source)?);
+        regexes.insert(source.to_string(), Arc::clone(&regex));
+        Ok(regex)
+    }
+}
 
-pub(super) struct NameEvaluator;
 impl PredicateEvaluator for NameEvaluator {
     fn evaluate(
         &self,
@@ -21,6 +61,11 @@ impl PredicateEvaluator for NameEvaluator {
             .file_name()
             .and_then(|s| s.to_str())
             .unwrap_or("");
+        if let Some(source) = regex_source(value) {
+            return Ok(MatchResult::Boolean(
+                self.regex(source)?.is_match(file_name),
+            ));
+        }
         let options = MatchOptions {
             case_sensitive: false,
             ..Default::default()
@@ -44,7 +89,7 @@ mod tests {
         let mut context2 =
             FileContext::new(PathBuf::from("/home/user/main.rs"), PathBuf::from("/"));
 
-        let evaluator = NameEvaluator;
+        let evaluator = NameEvaluator::default();
         assert!(evaluator
             .evaluate(&mut context1, &PredicateKey::Name, "Cargo.toml")
             .unwrap()
@@ -69,11 +114,30 @@ mod tests {
             .is_match());
     }
 
+    #[test]
+    fn test_name_evaluator_regex_mode() {
+        let evaluator = NameEvaluator::default();
+        let pattern = r"/^(foo|bar)\.rs$/";
+        for (file, expected) in [("foo.rs", true), ("bar.rs", true), ("foobar.rs", false)] {
+            let mut context =
+                FileContext::new(PathBuf::from("/src").join(file), PathBuf::from("/"));
+            assert_eq!(
+                evaluator
+                    .evaluate(&mut context, &PredicateKey::Name, pattern)
+                    .unwrap()
+                    .is_match(),
+                expected,
+                "{file}"
+            );
+        }
+        assert_eq!(evaluator.regexes.lock().unwrap().len(), 1);
+    }
+
     #[test]
     fn test_name_evaluator_case_insensitive() {
         let mut context =
             FileContext::new(PathBuf::from("/home/user/MyFile.txt"), PathBuf::from("/"));
-        let evaluator = NameEvaluator;
+        let evaluator = NameEvaluator::default();
         assert!(evaluator
             .evaluate(&mut context, &PredicateKey::Name, "myfile.txt")
             .unwrap()
@@ -2,7 +2,7 @@ use super::PredicateEvaluator;
 use crate::evaluator::{FileContext, MatchResult};
 use crate::parser::PredicateKey;
 use anyhow::Result;
-use globset::Glob;
+use globset::GlobBuilder;
 use std::path::PathBuf;
 
 pub(super) struct PathEvaluator;
@@ -14,6 +14,7 @@ impl PredicateEvaluator for PathEvaluator {
         key: &PredicateKey,
         value: &str,
     ) -> Result<MatchResult> {
+        let value = &*crate::expand_tilde_str(value);
         if let PredicateKey::PathExact = key {
             let mut expected = PathBuf::from(value);
             if expected.is_relative() {
@@ -38,11 +39,15 @@ impl PredicateEvaluator for PathEvaluator {
         let absolute_path_str = context.resolved_path().to_string_lossy();
         let value_path = std::path::Path::new(value);
         let use_absolute = value_path.is_absolute();
+        let ignore_case = context.ignore_case_paths();
 
         if value.contains('*') || value.contains('?') || value.contains('[') || value.contains('{')
         {
             // Convert glob-style pattern to a regex
-            let glob = Glob::new(value)?.compile_matcher();
+            let glob = GlobBuilder::new(value)
+                .case_insensitive(ignore_case)
+                .build()?
+                .compile_matcher();
             let target = if use_absolute {
                 absolute_path_str.as_ref()
             } else {
@@ -56,7 +61,13 @@ impl PredicateEvaluator for PathEvaluator {
             } else {
                 path_str.as_ref()
             };
-            Ok(MatchResult::Boolean(target.contains(value)))
+            if ignore_case {
+                Ok(MatchResult::Boolean(
+                    target.to_lowercase().contains(&value.to_lowercase()),
+                ))
+            } else {
+                Ok(MatchResult::Boolean(target.contains(value)))
+            }
         }
     }
 }
@@ -129,6 +140,29 @@ mod tests {
             .is_match());
     }
 
+    #[test]
+    fn test_path_evaluator_ignore_case_paths() {
+        let mut context = FileContext::new(
+            PathBuf::from("/home/user/project/Docs/README.md"),
+            PathBuf::from("/"),
+        );
+        let evaluator = PathEvaluator;
+        for value in ["docs/readme", "**/docs/*.MD"] {
+            assert!(!evaluator
+                .evaluate(&mut context, &PredicateKey::Path, value)
+                .unwrap()
+                .is_match());
+        }
+
+        context.set_ignore_case_paths(true);
+        for value in ["docs/readme", "**/docs/*.MD"] {
+            assert!(evaluator
+                .evaluate(&mut context, &PredicateKey::Path, value)
+                .unwrap()
+                .is_match());
+        }
+    }
+
     #[test]
     fn test_empty_path_query() {
         let mut context = FileContext::new(
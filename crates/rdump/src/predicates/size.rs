@@ -66,6 +66,40 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_size_evaluator_ranges() -> Result<()> {
+        let dir = tempdir()?;
+        let file_path = dir.path().join("file_1kb");
+        File::create(&file_path)?.write_all(&[0; 1024])?;
+
+        let mut context = FileContext::new(file_path, PathBuf::from("/"));
+        let evaluator = SizeEvaluator;
+        let matches = |context: &mut FileContext, value: &str| -> Result<bool> {
+            Ok(evaluator
+                .evaluate(context, &PredicateKey::Size, value)?
+                .is_match())
+        };
+
+        // The lower bound is inclusive and the upper bound exclusive.
+        assert!(matches(&mut context, "1kb..2kb")?);
+        assert!(matches(&mut context, "1024..1025")?);
+        assert!(!matches(&mut context, "512..1kb")?);
+        assert!(!matches(&mut context, "1025..1mb")?);
+
+        // Either bound may be left open.
+        assert!(matches(&mut context, "1kb..")?);
+        assert!(!matches(&mut context, "2kb..")?);
+        assert!(matches(&mut context, "..1025")?);
+        assert!(!matches(&mut context, "..1kb")?);
+
+        // Bounds take no operator, and at least one is required.
+        assert!(matches(&mut context, ">1kb..2kb").is_err());
+        assert!(matches(&mut context, "..").is_err());
+        assert!(matches(&mut context, "1kb..2xb").is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_size_evaluator_empty_file() -> Result<()> {
         let dir = tempdir()?;
@@ -3,12 +3,13 @@ use rdump_contracts::{
     CapabilityMetadata, ContractError, ErrorCode, ErrorEnvelope, ErrorMode, ErrorRemediation,
     ExecutionProfile, FileIdentity as ContractFileIdentity, LanguageCapabilityMatrix, LimitValue,
     Limits, LineEndingMode, MatchCoordinateSemantics, MatchInfo, OutputMode, PathDisplayMode,
-    PathMetadata, PredicateCatalog, PredicateDescriptor, ProgressEvent,
+    PathMetadata, PredicateCatalog, PredicateDescriptor, ProgressEvent, ResultFileMetadata,
     ResultKind as ContractResultKind, SearchDiagnostic as ContractDiagnostic, SearchItem,
     SearchRequest, SearchResponse, SearchStats as ContractStats, SearchStatus,
     SemanticSkipReason as ContractSemanticSkipReason, Snippet, SnippetMode, StabilityTier,
     SurfaceStability, SCHEMA_VERSION,
 };
+use std::collections::HashMap;
 #[cfg(unix)]
 use std::path::PathBuf;
 
@@ -98,8 +99,15 @@ pub fn search_options_from_request(request: &SearchRequest) -> SearchOptions {
         root: PathBuf::from(request.root.as_deref().unwrap_or(".")),
         presets: request.presets.clone(),
         no_ignore: request.no_ignore,
+        respect_global_gitignore: request.respect_global_gitignore,
+        ignore_parents: !request.no_ignore_parent,
         hidden: request.hidden,
         max_depth: request.max_depth,
+        min_depth: request.min_depth,
+        // Request limits are enforced by the pager, which also reports truncation.
+        max_results: None,
+        offset: 0,
+        limit: None,
         sql_dialect: request.sql_dialect.map(Into::into),
         sql_strict: request.sql_strict,
         error_mode: request.error_mode,
@@ -116,6 +124,26 @@ pub fn search_options_from_request(request: &SearchRequest) -> SearchOptions {
         ignore_debug: request.ignore_debug,
         language_debug: request.language_debug,
         sql_trace: request.sql_trace,
+        files_without_match: request.files_without_match,
+        secret_patterns: request.secret_patterns.clone(),
+        line_range: request.line_range,
+        encoding: request.encoding,
+        deterministic: request.deterministic,
+        same_file_system: request.same_file_system,
+        threads: request.threads,
+        regex_flags: request.regex_flags,
+        ignore_case_paths: request.ignore_case_paths,
+        whole_word: request.whole_word,
+        ext_map: request
+            .ext_map
+            .iter()
+            .map(|(extension, language)| (extension.to_ascii_lowercase(), language.clone()))
+            .collect(),
+        context_lines: None,
+        show_enclosing: false,
+        track_predicates: false,
+        load_content: !request.skip_content,
+        progress: None,
     };
     apply_execution_profile(request, &mut options);
     options
@@ -141,6 +169,7 @@ pub struct SearchRequestPager {
     root: String,
     effective_query: String,
     line_endings: LineEndingMode,
+    ext_map: HashMap<String, String>,
     raw_iter: crate::engine::SearchRawIterator,
     pending: Option<PendingSearchItem>,
     current_offset: usize,
@@ -168,7 +197,10 @@ impl SearchRequestPager {
         let limits = resolve_limits(request.limits.clone());
         let context_lines = request.context_lines.unwrap_or(DEFAULT_CONTEXT_LINES);
         let root = request.root.clone().unwrap_or_else(|| ".".to_string());
-        let options = search_options_from_request(request);
+        let mut options = search_options_from_request(request);
+        if output == OutputMode::Paths {
+            options = options.for_path_output();
+        }
         let explanation = crate::explain_query_with_runtime(&runtime, &request.query, &options)?;
         let raw_iter = runtime.search_raw_iter(&request.query, &options, cancellation)?;
 
@@ -182,6 +214,7 @@ impl SearchRequestPager {
             root,
             effective_query: explanation.effective_query,
             line_endings: request.line_endings.unwrap_or(LineEndingMode::Preserve),
+            ext_map: options.ext_map.clone(),
             raw_iter,
             pending: None,
             current_offset: 0,
@@ -493,6 +526,15 @@ impl SearchRequestPager {
                     .iter()
                     .map(map_diagnostic)
                     .collect::<Vec<_>>();
+                let metadata = if self.request.with_metadata && self.output == OutputMode::Full {
+                    Some(result_file_metadata(
+                        self.runtime.backend().as_ref(),
+                        &result,
+                        &self.ext_map,
+                    )?)
+                } else {
+                    None
+                };
                 let (item, match_count) = build_item(
                     self.runtime.backend().as_ref(),
                     self.output,
@@ -503,6 +545,7 @@ impl SearchRequestPager {
                     self.request.path_display,
                     self.line_endings,
                     self.request.include_match_text,
+                    metadata,
                 )?;
                 Ok(PendingSearchItem {
                     approx_bytes: estimate_search_item_bytes(&item),
@@ -745,6 +788,7 @@ fn build_item(
     path_display: Option<PathDisplayMode>,
     line_endings: LineEndingMode,
     include_match_text: bool,
+    metadata: Option<ResultFileMetadata>,
 ) -> Result<(SearchItem, usize)> {
     let file = map_file_identity(result.file_identity());
     let path = render_contract_path(result.file_identity(), path_display);
@@ -835,6 +879,7 @@ fn build_item(
                     semantic_skip_reasons,
                     item_truncated: content_truncated
                         || result.match_count() > limits.max_matches_per_file,
+                    metadata,
                 },
                 match_count,
             ))
@@ -857,6 +902,31 @@ fn build_item(
     }
 }
 
+fn result_file_metadata(
+    backend: &dyn crate::backend::SearchBackend,
+    result: &SearchResult,
+    ext_map: &HashMap<String, String>,
+) -> Result<ResultFileMetadata> {
+    let path_metadata = match result.metadata.snapshot.as_ref() {
+        Some(snapshot) => snapshot.to_path_metadata(),
+        None => path_metadata(backend, &result.file_identity().resolved_path)?,
+    };
+    let language = crate::predicates::code_aware::profile_for_path(
+        &result.file_identity().resolved_path,
+        ext_map,
+    )
+    .and_then(|(key, _)| {
+        crate::predicates::code_aware::profiles::find_canonical_language_profile(&key)
+    })
+    .map(|entry| entry.id.to_string());
+    Ok(ResultFileMetadata {
+        size: path_metadata.size_bytes,
+        lines: result.content.lines().count(),
+        modified_unix_millis: path_metadata.modified_unix_millis,
+        language,
+    })
+}
+
 fn shape_matches(
     result: &SearchResult,
     limits: &ResolvedLimits,
@@ -992,6 +1062,7 @@ fn render_contract_path(
             .unwrap_or(&identity.display_path)
             .display()
             .to_string(),
+        PathDisplayMode::RelativeToCwd => identity.cwd_relative_path().display().to_string(),
     }
 }
 
@@ -1340,6 +1411,24 @@ pub fn predicate_catalog() -> PredicateCatalog {
             aliases: vec!["m".to_string()],
             deprecated_aliases: Vec::new(),
         },
+        PredicateDescriptor {
+            name: "defn_body".to_string(),
+            category: "semantic".to_string(),
+            aliases: vec!["func_containing".to_string()],
+            deprecated_aliases: Vec::new(),
+        },
+        PredicateDescriptor {
+            name: "extends".to_string(),
+            category: "semantic".to_string(),
+            aliases: vec!["superclass".to_string()],
+            deprecated_aliases: Vec::new(),
+        },
+        PredicateDescriptor {
+            name: "decorator".to_string(),
+            category: "semantic".to_string(),
+            aliases: vec!["annotation".to_string()],
+            deprecated_aliases: Vec::new(),
+        },
     ];
     for name in [
         "ext",
@@ -1363,18 +1452,37 @@ pub fn predicate_catalog() -> PredicateCatalog {
         "import",
         "class",
         "struct",
+        "field",
         "enum",
+        "enum_variant",
         "interface",
+        "interface_method",
         "trait",
+        "trait_method",
         "type",
         "impl",
+        "implements",
         "macro",
         "module",
+        "namespace",
         "object",
         "protocol",
+        "template",
+        "service",
         "comment",
+        "doc",
         "str",
+        "str_exact",
         "call",
+        "target",
+        "stage",
+        "from",
+        "block",
+        "route",
+        "property",
+        "test",
+        "heading",
+        "link",
         "component",
         "element",
         "hook",
@@ -1453,16 +1561,11 @@ pub fn language_capability_matrix() -> LanguageCapabilityMatrix {
                         "modified".to_string(),
                     ],
                     content: vec!["contains".to_string(), "matches".to_string()],
-                    semantic: {
-                        let mut semantic: Vec<String> = profile
-                            .profile
-                            .queries
-                            .keys()
-                            .map(|key| key.as_ref().to_string())
-                            .collect();
-                        semantic.sort();
-                        semantic
-                    },
+                    semantic: profile
+                        .semantic_predicates()
+                        .into_iter()
+                        .map(|key| key.as_ref().to_string())
+                        .collect(),
                 },
                 semantic_caveats: crate::predicates::code_aware::profiles::semantic_caveats_for_id(
                     profile.id,
@@ -1485,7 +1588,7 @@ fn output_mode_label(mode: OutputMode) -> &'static str {
     }
 }
 
-fn item_path(item: &SearchItem) -> &str {
+pub(crate) fn item_path(item: &SearchItem) -> &str {
     match item {
         SearchItem::Path { path, .. }
         | SearchItem::Summary { path, .. }
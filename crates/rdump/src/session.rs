@@ -0,0 +1,208 @@
+use anyhow::Result;
+use rdump_contracts::Encoding;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use tree_sitter::Tree;
+
+use crate::backend::SearchRuntime;
+use crate::{SearchOptions, SearchReport, SearchResult};
+
+/// Parsed syntax trees shared across searches, keyed by path, language profile and the
+/// encoding the content was transcoded with, since byte offsets in a tree only fit that text.
+///
+/// An entry is reused only while the file's modification time and size match the values
+/// recorded when it was parsed; otherwise the file is parsed again and the entry replaced.
+/// Files whose backend reports no modification time are never cached.
+///
+/// The cache holds at most [`TreeCache::capacity`] trees. Inserting one more evicts the tree
+/// that was least recently parsed or reused, so a long-lived session over a changing tree
+/// stays bounded.
+pub struct TreeCache {
+    entries: Mutex<HashMap<(PathBuf, String, Encoding), CachedTree>>,
+    capacity: usize,
+    clock: AtomicU64,
+    parses: AtomicUsize,
+}
+
+/// Number of trees [`TreeCache::new`] keeps before evicting.
+pub const DEFAULT_TREE_CACHE_CAPACITY: usize = 1024;
+
+struct CachedTree {
+    modified_unix_millis: i64,
+    size_bytes: u64,
+    last_used: u64,
+    tree: Tree,
+}
+
+impl Default for TreeCache {
+    fn default() -> Self {
+        Self::with_capacity(DEFAULT_TREE_CACHE_CAPACITY)
+    }
+}
+
+impl fmt::Debug for TreeCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TreeCache")
+            .field("entries", &self.len())
+            .field("capacity", &self.capacity)
+            .field("parses", &self.parse_count())
+            .finish()
+    }
+}
+
+impl TreeCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a cache that keeps at most `capacity` trees. A capacity of zero caches nothing.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+            capacity,
+            clock: AtomicU64::new(0),
+            parses: AtomicUsize::new(0),
+        }
+    }
+
+    /// Maximum number of trees kept at once.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of cached trees.
+    pub fn len(&self) -> usize {
+        self.entries.lock().expect("tree cache lock poisoned").len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Number of files parsed through this cache, i.e. lookups that missed.
+    pub fn parse_count(&self) -> usize {
+        self.parses.load(Ordering::SeqCst)
+    }
+
+    /// Drops every cached tree.
+    pub fn clear(&self) {
+        self.entries
+            .lock()
+            .expect("tree cache lock poisoned")
+            .clear();
+    }
+
+    pub(crate) fn get(
+        &self,
+        path: &Path,
+        language_key: &str,
+        encoding: Encoding,
+        modified_unix_millis: i64,
+        size_bytes: u64,
+    ) -> Option<Tree> {
+        let mut entries = self.entries.lock().expect("tree cache lock poisoned");
+        let cached = entries.get_mut(&(path.to_path_buf(), language_key.to_string(), encoding))?;
+        if cached.modified_unix_millis != modified_unix_millis || cached.size_bytes != size_bytes {
+            return None;
+        }
+        cached.last_used = self.clock.fetch_add(1, Ordering::SeqCst);
+        Some(cached.tree.clone())
+    }
+
+    pub(crate) fn insert(
+        &self,
+        path: &Path,
+        language_key: &str,
+        encoding: Encoding,
+        modified_unix_millis: i64,
+        size_bytes: u64,
+        tree: Tree,
+    ) {
+        self.parses.fetch_add(1, Ordering::SeqCst);
+        if self.capacity == 0 {
+            return;
+        }
+        let key = (path.to_path_buf(), language_key.to_string(), encoding);
+        let mut entries = self.entries.lock().expect("tree cache lock poisoned");
+        if !entries.contains_key(&key) && entries.len() >= self.capacity {
+            let oldest = entries
+                .iter()
+                .min_by_key(|(_, cached)| cached.last_used)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                entries.remove(&oldest);
+            }
+        }
+        entries.insert(
+            key,
+            CachedTree {
+                modified_unix_millis,
+                size_bytes,
+                last_used: self.clock.fetch_add(1, Ordering::SeqCst),
+                tree,
+            },
+        );
+    }
+}
+
+/// A long-lived search handle for processes that run many queries over the same tree,
+/// such as editor integrations or watch loops.
+///
+/// Syntax trees parsed by one search are reused by later searches until the file changes,
+/// so repeated semantic queries skip re-parsing unchanged files. File content is still
+/// read on every search.
+#[derive(Debug, Clone)]
+pub struct SearchSession {
+    runtime: SearchRuntime,
+    tree_cache: Arc<TreeCache>,
+}
+
+impl Default for SearchSession {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SearchSession {
+    /// Creates a session that searches the host filesystem.
+    pub fn new() -> Self {
+        Self::with_runtime(SearchRuntime::real_fs())
+    }
+
+    /// Creates a session on top of an existing runtime and its backend.
+    pub fn with_runtime(runtime: SearchRuntime) -> Self {
+        Self::with_runtime_and_tree_cache(runtime, Arc::new(TreeCache::new()))
+    }
+
+    /// Creates a session that keeps its syntax trees in `tree_cache`, for example one built
+    /// with [`TreeCache::with_capacity`].
+    pub fn with_runtime_and_tree_cache(runtime: SearchRuntime, tree_cache: Arc<TreeCache>) -> Self {
+        Self {
+            runtime: runtime.with_tree_cache(Arc::clone(&tree_cache)),
+            tree_cache,
+        }
+    }
+
+    /// Returns the runtime this session searches with.
+    pub fn runtime(&self) -> &SearchRuntime {
+        &self.runtime
+    }
+
+    /// Returns the syntax-tree cache shared by this session's searches.
+    pub fn tree_cache(&self) -> &Arc<TreeCache> {
+        &self.tree_cache
+    }
+
+    /// Collects all results into memory, reusing cached syntax trees.
+    pub fn search(&self, query: &str, options: &SearchOptions) -> Result<Vec<SearchResult>> {
+        self.runtime.search(query, options)
+    }
+
+    /// Collects results plus engine statistics, reusing cached syntax trees.
+    pub fn search_with_stats(&self, query: &str, options: &SearchOptions) -> Result<SearchReport> {
+        self.runtime.search_with_stats(query, options)
+    }
+}
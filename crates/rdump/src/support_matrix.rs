@@ -206,7 +206,7 @@ const REACT_SHARED_CASES: &[SupportMatrixCase] = &[
         language_scope: "react, jsx, tsx",
         query: "hook:useAuth",
         expected: &["const { user } = useAuth();"],
-        absent: &[],
+        absent: &["export default function useAuth()"],
         intentionally_language_specific: true,
     },
     SupportMatrixCase {
@@ -215,7 +215,7 @@ const REACT_SHARED_CASES: &[SupportMatrixCase] = &[
         language_scope: "react, jsx, tsx",
         query: "customhook:useAuth",
         expected: &["export default function useAuth()"],
-        absent: &[],
+        absent: &["const { user } = useAuth();"],
         intentionally_language_specific: true,
     },
     SupportMatrixCase {
@@ -0,0 +1,185 @@
+//! `rdump search --watch`: re-run a search whenever files under the root change.
+
+use anyhow::{Context, Result};
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use std::io::{self, IsTerminal, Write};
+use std::path::{Component, Path, PathBuf};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::time::{Duration, Instant};
+
+/// How long the tree must stay quiet after a change before the search re-runs.
+const QUIET_PERIOD: Duration = Duration::from_millis(200);
+
+/// How long to block waiting for the first change when nothing is pending.
+const IDLE_WAIT: Duration = Duration::from_secs(3600);
+
+/// Coalesces a burst of change events (an editor save, a `git checkout`) into one re-run.
+///
+/// Each recorded event restarts the quiet period; [`Debouncer::poll`] fires once the period
+/// has elapsed without further events.
+#[derive(Debug)]
+pub(crate) struct Debouncer {
+    quiet: Duration,
+    last_event: Option<Instant>,
+}
+
+impl Debouncer {
+    pub(crate) fn new(quiet: Duration) -> Self {
+        Self {
+            quiet,
+            last_event: None,
+        }
+    }
+
+    pub(crate) fn record(&mut self, at: Instant) {
+        self.last_event = Some(at);
+    }
+
+    /// Returns true, once per burst, when the quiet period has passed since the last event.
+    pub(crate) fn poll(&mut self, now: Instant) -> bool {
+        match self.last_event {
+            Some(last) if now.saturating_duration_since(last) >= self.quiet => {
+                self.last_event = None;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// How long to wait for the next event before polling again.
+    pub(crate) fn wait_time(&self, now: Instant) -> Duration {
+        match self.last_event {
+            Some(last) => self
+                .quiet
+                .saturating_sub(now.saturating_duration_since(last)),
+            None => IDLE_WAIT,
+        }
+    }
+}
+
+/// Runs `search` once, then again after every debounced change under `root` until the
+/// process is interrupted (Ctrl-C). Errors from a run are printed and watching continues.
+/// Changes to `ignored` paths (such as the `--output` file), anywhere under an ignored
+/// directory, and inside `.git` are skipped.
+pub(crate) fn watch(
+    root: &Path,
+    ignored: &[PathBuf],
+    mut search: impl FnMut() -> Result<()>,
+) -> Result<()> {
+    let ignored: Vec<PathBuf> = ignored
+        .iter()
+        .map(|path| {
+            path.components()
+                .filter(|component| *component != Component::CurDir)
+                .collect()
+        })
+        .collect();
+    let clear_screen = io::stdout().is_terminal();
+    let mut run = || {
+        if clear_screen {
+            print!("\x1b[2J\x1b[H");
+            let _ = io::stdout().flush();
+        }
+        if let Err(err) = search() {
+            eprintln!("{err}");
+        }
+    };
+
+    let (tx, rx) = mpsc::channel();
+    let mut watcher =
+        notify::recommended_watcher(tx).context("Failed to start the file watcher")?;
+    watcher
+        .watch(root, RecursiveMode::Recursive)
+        .with_context(|| format!("Failed to watch {}", root.display()))?;
+
+    run();
+    let mut debouncer = Debouncer::new(QUIET_PERIOD);
+    loop {
+        match rx.recv_timeout(debouncer.wait_time(Instant::now())) {
+            Ok(Ok(event)) => {
+                if is_relevant(&event, &ignored) {
+                    debouncer.record(Instant::now());
+                }
+            }
+            Ok(Err(err)) => eprintln!("Warning: file watcher error: {err}"),
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => return Ok(()),
+        }
+        if debouncer.poll(Instant::now()) {
+            run();
+        }
+    }
+}
+
+fn is_relevant(event: &Event, ignored: &[PathBuf]) -> bool {
+    !matches!(event.kind, EventKind::Access(_))
+        && event.paths.iter().any(|path| {
+            !path
+                .components()
+                .any(|component| component == Component::Normal(".git".as_ref()))
+                && !ignored
+                    .iter()
+                    .any(|ignored| path.ancestors().any(|ancestor| ancestor.ends_with(ignored)))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_debouncer_coalesces_a_burst_into_one_run() {
+        let start = Instant::now();
+        let quiet = Duration::from_millis(100);
+        let mut debouncer = Debouncer::new(quiet);
+        assert!(!debouncer.poll(start));
+        assert_eq!(debouncer.wait_time(start), IDLE_WAIT);
+
+        for offset in [0, 30, 60, 90] {
+            debouncer.record(start + Duration::from_millis(offset));
+        }
+        // Each event restarts the quiet period.
+        assert!(!debouncer.poll(start + Duration::from_millis(150)));
+        assert_eq!(
+            debouncer.wait_time(start + Duration::from_millis(150)),
+            Duration::from_millis(40)
+        );
+        assert!(debouncer.poll(start + Duration::from_millis(190)));
+        // The burst fires only once.
+        assert!(!debouncer.poll(start + Duration::from_millis(500)));
+
+        debouncer.record(start + Duration::from_millis(600));
+        assert!(debouncer.poll(start + Duration::from_millis(700)));
+    }
+
+    #[test]
+    fn test_summary_json_and_output_dir_writes_are_not_relevant() {
+        let event = |path: &str| {
+            Event::new(EventKind::Create(notify::event::CreateKind::File))
+                .add_path(PathBuf::from(path))
+        };
+        let ignored = [PathBuf::from("stats.json"), PathBuf::from("out/dumps")];
+        assert!(!is_relevant(&event("/repo/stats.json"), &ignored));
+        assert!(!is_relevant(&event("/repo/out/dumps/src/lib.rs"), &ignored));
+        assert!(is_relevant(&event("/repo/out/notes.txt"), &ignored));
+        assert!(is_relevant(&event("/repo/src/stats.rs"), &ignored));
+    }
+
+    #[test]
+    fn test_git_and_ignored_paths_are_not_relevant() {
+        let event = |path: &str| {
+            Event::new(EventKind::Modify(notify::event::ModifyKind::Any))
+                .add_path(PathBuf::from(path))
+        };
+        let ignored = [PathBuf::from("out.txt")];
+        assert!(is_relevant(&event("/repo/src/lib.rs"), &ignored));
+        assert!(!is_relevant(&event("/repo/.git/index"), &ignored));
+        assert!(!is_relevant(&event("/repo/out.txt"), &ignored));
+        assert!(is_relevant(&event("/repo/out.txt.bak"), &ignored));
+        assert!(!is_relevant(
+            &Event::new(EventKind::Access(notify::event::AccessKind::Any))
+                .add_path(PathBuf::from("/repo/src/lib.rs")),
+            &ignored
+        ));
+    }
+}
@@ -57,6 +57,7 @@ fn library_exports_accessible_from_crate_root() {
         end_column: 2,
         byte_range: 0..2,
         text: "fn".to_string(),
+        ..Default::default()
     };
     let sample_result = SearchResult {
         path: PathBuf::from("example.rs"),
@@ -81,6 +82,7 @@ fn library_exports_accessible_from_crate_root() {
         false,
         0,
         rdump::TimeFormat::Local,
+        rdump::Messages::default(),
     )
     .expect("backend-aware raw formatter reachable");
     let mut path_writer = Vec::new();
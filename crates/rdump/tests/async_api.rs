@@ -8,10 +8,12 @@ use rdump::backend::{
 };
 use rdump::{
     search_all_async, search_all_async_with_runtime, search_async, search_async_with_runtime,
-    search_async_with_runtime_and_progress, SearchOptions, SearchRuntime,
+    search_async_with_runtime_and_progress, search_stream, search_stream_with_runtime,
+    SearchOptions, SearchRuntime,
 };
 use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::sync::Mutex;
 use tempfile::tempdir;
@@ -20,6 +22,7 @@ use tempfile::tempdir;
 struct FakeBackend {
     root: PathBuf,
     files: BTreeMap<PathBuf, (Vec<u8>, BackendMetadata)>,
+    reads: Arc<AtomicUsize>,
 }
 
 impl FakeBackend {
@@ -40,7 +43,11 @@ impl FakeBackend {
                 (relative, (bytes, metadata))
             })
             .collect();
-        Self { root, files }
+        Self {
+            root,
+            files,
+            reads: Arc::default(),
+        }
     }
 
     fn relative_key(&self, path: &Path) -> Result<PathBuf> {
@@ -113,6 +120,7 @@ impl SearchBackend for FakeBackend {
     }
 
     fn read_bytes(&self, path: &Path) -> Result<Vec<u8>> {
+        self.reads.fetch_add(1, Ordering::SeqCst);
         let relative = self.relative_key(path)?;
         self.files
             .get(&relative)
@@ -437,3 +445,79 @@ async fn test_search_async_with_custom_runtime_emits_progress() {
         rdump::contracts::ProgressEvent::Finished { returned_files, .. } if *returned_files == 1
     )));
 }
+
+#[tokio::test]
+async fn test_search_stream_yields_results() {
+    let dir = tempdir().unwrap();
+    std::fs::write(dir.path().join("main.rs"), "fn main() {}").unwrap();
+    std::fs::write(dir.path().join("lib.rs"), "fn helper() {}").unwrap();
+
+    let results: Vec<_> = search_stream(
+        "func:main",
+        SearchOptions {
+            root: dir.path().to_path_buf(),
+            ..Default::default()
+        },
+    )
+    .collect()
+    .await;
+
+    assert_eq!(results.len(), 1);
+    assert!(results[0].as_ref().unwrap().path.ends_with("main.rs"));
+}
+
+#[tokio::test]
+async fn test_search_stream_propagates_setup_error() {
+    let dir = tempdir().unwrap();
+    let mut stream = search_stream(
+        "invalid((query",
+        SearchOptions {
+            root: dir.path().to_path_buf(),
+            ..Default::default()
+        },
+    );
+
+    assert!(stream.next().await.unwrap().is_err());
+    assert!(stream.next().await.is_none());
+}
+
+#[tokio::test]
+async fn test_search_stream_early_drop_stops_reading_files() {
+    const FILES: usize = 50;
+    let root = PathBuf::from("/virtual");
+    let backend = Arc::new(FakeBackend::new(
+        root.clone(),
+        (0..FILES).map(|i| {
+            (
+                PathBuf::from(format!("file{i:02}.txt")),
+                b"hello stream\n".to_vec(),
+            )
+        }),
+    ));
+    let reads = Arc::clone(&backend.reads);
+    let runtime = SearchRuntime::with_backend(backend);
+
+    let first_two: Vec<_> = search_stream_with_runtime(
+        runtime,
+        "contains:hello",
+        SearchOptions {
+            root,
+            ..Default::default()
+        },
+    )
+    .take(2)
+    .collect()
+    .await;
+    assert_eq!(first_two.len(), 2);
+    assert!(first_two.iter().all(|result| result.is_ok()));
+
+    // Give the blocking producer a moment to observe the dropped stream.
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    let reads_after_drop = reads.load(Ordering::SeqCst);
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    assert_eq!(reads.load(Ordering::SeqCst), reads_after_drop);
+    assert!(
+        reads_after_drop < FILES,
+        "stream read {reads_after_drop} times for {FILES} files"
+    );
+}
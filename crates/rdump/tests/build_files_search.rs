@@ -0,0 +1,59 @@
+use predicates::prelude::*;
+mod common;
+use common::setup_custom_project;
+
+const DOCKERFILE: &str = "# build stage\nFROM rust:1.80 AS builder\nRUN cargo build --release\n\nFROM debian:bookworm-slim\nCOPY --from=builder /app/target/release/app /usr/local/bin/app\n";
+
+const MAKEFILE: &str = "CARGO ?= cargo\n\n.PHONY: build test\n\nbuild:\n\t$(CARGO) build\n\ntest: build\n\t$(CARGO) test\n";
+
+#[test]
+fn test_target_predicate_makefile() {
+    let dir = setup_custom_project(&[("Makefile", MAKEFILE), ("notes.txt", "build:")]);
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .arg("search")
+        .arg("target:test")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Makefile"))
+        .stdout(predicate::str::contains("notes.txt").not());
+}
+
+#[test]
+fn test_def_predicate_makefile() {
+    let dir = setup_custom_project(&[("build/rules.mk", MAKEFILE)]);
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .arg("search")
+        .arg("def:CARGO")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("rules.mk"));
+}
+
+#[test]
+fn test_from_and_stage_predicates_dockerfile() {
+    let dir = setup_custom_project(&[("Dockerfile", DOCKERFILE), ("Dockerfile.dev", DOCKERFILE)]);
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .arg("search")
+        .arg("--format=paths")
+        .arg("from:rust & stage:builder")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Dockerfile\n"))
+        .stdout(predicate::str::contains("Dockerfile.dev"));
+}
+
+#[test]
+fn test_stage_predicate_does_not_match_copy_from() {
+    let dir =
+        setup_custom_project(&[("Dockerfile", "FROM alpine\nCOPY --from=builder /src /dst\n")]);
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .arg("search")
+        .arg("stage:builder")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+}
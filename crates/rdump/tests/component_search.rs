@@ -0,0 +1,132 @@
+use predicates::prelude::*;
+mod common;
+use common::setup_fixture;
+
+// =============================================================================
+// SCRIPT PREDICATES
+// =============================================================================
+
+#[test]
+fn test_func_predicate_inside_svelte_script() {
+    let dir = setup_fixture("component_project");
+    // `notes.html` and the markup of `Counter.svelte` only mention `increment` as text.
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "func:increment"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Counter.svelte"))
+        .stdout(predicate::str::contains("notes.html").not())
+        .stdout(predicate::str::contains("TodoList.vue").not());
+
+    let output = assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=matches", "func:increment"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("function increment()"), "{stdout}");
+    assert!(!stdout.contains("<Button"), "{stdout}");
+}
+
+#[test]
+fn test_func_predicate_inside_vue_script_setup() {
+    let dir = setup_fixture("component_project");
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "func:addTodo#1"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("TodoList.vue"))
+        .stdout(predicate::str::contains("Counter.svelte").not());
+}
+
+#[test]
+fn test_import_and_call_predicates_in_component_scripts() {
+    let dir = setup_fixture("component_project");
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "import:svelte"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Counter.svelte"))
+        .stdout(predicate::str::contains("TodoList.vue").not());
+
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "call:ref"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("TodoList.vue"))
+        .stdout(predicate::str::contains("Counter.svelte").not());
+}
+
+// =============================================================================
+// MARKUP PREDICATES
+// =============================================================================
+
+#[test]
+fn test_element_predicate_matches_template_markup() {
+    let dir = setup_fixture("component_project");
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "element:h1"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Counter.svelte"))
+        .stdout(predicate::str::contains("TodoList.vue").not());
+
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "element:ul"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("TodoList.vue"))
+        .stdout(predicate::str::contains("Counter.svelte").not());
+}
+
+#[test]
+fn test_component_predicate_matches_pascal_case_tags() {
+    let dir = setup_fixture("component_project");
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "component:TodoItem"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("TodoList.vue"))
+        .stdout(predicate::str::contains("Counter.svelte").not());
+
+    // Native elements are not components, even when a component shares their name.
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "component:button"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+}
+
+#[test]
+fn test_lang_describe_lists_markup_predicates_for_components() {
+    let output = assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .args(["lang", "describe", "vue", "--format", "json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let names: Vec<&str> = json["predicates"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|predicate| predicate["name"].as_str())
+        .collect();
+    assert!(names.contains(&"element"), "{names:?}");
+    assert!(names.contains(&"component"), "{names:?}");
+    assert!(names.contains(&"func"), "{names:?}");
+
+    // TypeScript itself has no markup.
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .args(["lang", "describe", "typescript"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("element").not());
+}
@@ -154,6 +154,49 @@ fn test_cpp_call_add() {
         .stdout(predicate::str::contains("add("));
 }
 
+// =============================================================================
+// NAMESPACE AND TEMPLATE PREDICATE TESTS
+// =============================================================================
+
+#[test]
+fn test_cpp_namespace_matches_nested_namespaces() {
+    let dir = setup_fixture("cpp_project");
+    let output = assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=matches", "namespace:detail"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("util.hpp"), "{stdout}");
+    assert!(!stdout.contains("main.cpp"), "{stdout}");
+    // Both `namespace detail` inside `demo` and `namespace demo::io::detail`.
+    assert_eq!(stdout.matches(" detail").count(), 2, "{stdout}");
+
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .arg("search")
+        .arg("namespace:demo")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("main.cpp"))
+        .stdout(predicate::str::contains("util.hpp"));
+}
+
+#[test]
+fn test_cpp_template_predicate() {
+    let dir = setup_fixture("cpp_project");
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .arg("search")
+        .arg("template:.")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "T clamp_to(T value, T low, T high)",
+        ))
+        .stdout(predicate::str::contains("main.cpp").not());
+}
+
 // =============================================================================
 // COMBINATION TESTS
 // =============================================================================
@@ -366,3 +366,95 @@ fn test_csharp_ext_filter() {
         .success()
         .stdout(predicate::str::contains(".rs").not());
 }
+
+// =============================================================================
+// RECORD, PROPERTY AND NAMESPACE TESTS
+// =============================================================================
+
+#[test]
+fn test_csharp_class_matches_record() {
+    let dir = setup_custom_project(&[(
+        "Person.cs",
+        r#"
+namespace App.Models;
+
+public record Person(string Name, int Age);
+"#,
+    )]);
+
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .arg("search")
+        .arg("class:Person")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Person.cs"));
+
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .arg("search")
+        .arg("property:Age")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Person.cs"));
+
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .arg("search")
+        .arg("namespace:App.Models")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Person.cs"));
+}
+
+#[test]
+fn test_csharp_property_matches_auto_property() {
+    let dir = setup_custom_project(&[(
+        "Model.cs",
+        r#"
+public class User {
+    private string _secret;
+    public string Email { get; private set; }
+    public string Slug => Email.ToLower();
+}
+"#,
+    )]);
+
+    for query in ["property:Email", "property:Slug"] {
+        assert_cmd::cargo::cargo_bin_cmd!("rdump")
+            .current_dir(dir.path())
+            .args(["search", query])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Model.cs"));
+    }
+
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "property:_secret"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+}
+
+#[test]
+fn test_csharp_top_level_statements() {
+    let dir = setup_custom_project(&[(
+        "Program.cs",
+        r#"
+using System;
+
+Console.WriteLine(Describe(3));
+
+static string Describe(int count) => $"{count} items";
+"#,
+    )]);
+
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .arg("search")
+        .arg("func:Describe & call:WriteLine")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Program.cs"));
+}
@@ -279,6 +279,36 @@ end
         .stdout(predicate::str::contains("macros.ex"));
 }
 
+#[test]
+fn test_elixir_module_predicate_matches_defmodule_only() {
+    let dir = setup_custom_project(&[
+        (
+            "accounts.ex",
+            r#"
+defmodule Accounts do
+  def list, do: []
+end
+"#,
+        ),
+        (
+            "client.ex",
+            r#"
+defmodule Client do
+  def fetch, do: Accounts.list()
+end
+"#,
+        ),
+    ]);
+
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "module:Accounts"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("accounts.ex"))
+        .stdout(predicate::str::contains("client.ex").not());
+}
+
 // =============================================================================
 // OUTPUT FORMAT TESTS
 // =============================================================================
@@ -2,4 +2,15 @@
 
 namespace demo {
 inline void do_nothing() {}
+
+namespace detail {
+template <typename T>
+T clamp_to(T value, T low, T high) {
+    return value < low ? low : (value > high ? high : value);
 }
+} // namespace detail
+} // namespace demo
+
+namespace demo::io::detail {
+inline int buffer_size() { return 4096; }
+} // namespace demo::io::detail
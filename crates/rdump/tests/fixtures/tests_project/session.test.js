@@ -0,0 +1,11 @@
+const { login } = require("./session");
+
+describe("session", () => {
+  test("login redirects home", () => {
+    expect(login("alice")).toBe(true);
+  });
+
+  it.only("logout clears the session", () => {
+    expect(login("")).toBe(false);
+  });
+});
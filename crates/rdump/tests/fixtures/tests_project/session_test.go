@@ -0,0 +1,17 @@
+package session
+
+import "testing"
+
+func loginHelper() string {
+	return "alice"
+}
+
+func Testify() {}
+
+func TestLogin(t *testing.T) {
+	if loginHelper() == "" {
+		t.Fatal("empty user")
+	}
+}
+
+func TestLogout(t *testing.T) {}
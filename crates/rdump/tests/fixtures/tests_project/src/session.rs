@@ -0,0 +1,23 @@
+pub fn login(user: &str) -> bool {
+    !user.is_empty()
+}
+
+pub fn login_helper() -> &'static str {
+    "alice"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn login_succeeds() {
+        assert!(login(login_helper()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn logout_without_session_panics() {
+        panic!("no session");
+    }
+}
@@ -0,0 +1,17 @@
+import unittest
+
+
+def login_helper():
+    return "alice"
+
+
+def test_login():
+    assert login_helper()
+
+
+class SessionTests(unittest.TestCase):
+    def setUp(self):
+        self.user = login_helper()
+
+    def test_logout(self):
+        self.assertTrue(self.user)
@@ -487,6 +487,78 @@ type Response struct {
         .stdout(predicate::str::contains("type Handler interface"));
 }
 
+#[test]
+fn test_go_interface_method_matches_only_interface_signatures() {
+    let dir = setup_custom_project(&[(
+        "service.go",
+        r#"package main
+
+type Handler interface {
+    Handle(req Request) Response
+}
+
+type Request struct{}
+type Response struct{}
+
+type Server struct{}
+
+func (s *Server) Handle(req Request) Response {
+    return Response{}
+}
+"#,
+    )]);
+
+    let output = assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=matches", "interface_method:Handle"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("4:5-4:11 Handle"), "{stdout}");
+    assert!(!stdout.contains("12:18-12:24 Handle"), "{stdout}");
+
+    // `func:` keeps matching the concrete method only.
+    let output = assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=matches", "func:Handle"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("12:18-12:24 Handle"), "{stdout}");
+    assert!(!stdout.contains("4:5-4:11 Handle"), "{stdout}");
+}
+
+#[test]
+fn test_go_struct_empty_body_modifier() {
+    let dir = setup_custom_project(&[(
+        "models.go",
+        r#"package main
+
+type Marker struct{}
+
+type Server struct {
+    addr string
+}
+"#,
+    )]);
+
+    let output = assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=matches", "struct:.{}"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("3:6-3:12 Marker"), "{stdout}");
+    assert!(!stdout.contains("Server"), "{stdout}");
+
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "struct:Server{}"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+}
+
 #[test]
 fn test_go_custom_multiple_structs() {
     let dir = setup_custom_project(&[(
@@ -690,6 +762,40 @@ type Extended struct {
         .stdout(predicate::str::contains("Extended"));
 }
 
+#[test]
+fn test_go_extends_matches_embedded_structs() {
+    let dir = setup_custom_project(&[(
+        "embedded.go",
+        r#"package main
+
+type Base struct {
+    ID int
+}
+
+type Extended struct {
+    Base
+    *sync.Mutex
+    Name string
+}
+"#,
+    )]);
+
+    for query in ["extends:Base", "extends:Mutex"] {
+        assert_cmd::cargo::cargo_bin_cmd!("rdump")
+            .current_dir(dir.path())
+            .args(["search", "--format=paths", query])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("embedded.go"));
+    }
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "extends:Name"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+}
+
 #[test]
 fn test_go_custom_type_alias() {
     let dir = setup_custom_project(&[(
@@ -818,3 +924,62 @@ fn test_go_ext_filter_excludes_others() {
         .stdout(predicate::str::contains(".py").not())
         .stdout(predicate::str::contains(".java").not());
 }
+
+#[test]
+fn test_go_call_qualified_receiver() {
+    let dir = setup_custom_project(&[
+        (
+            "qualified.go",
+            r#"package main
+
+import "fmt"
+
+func main() {
+	fmt.Println("hello")
+}
+"#,
+        ),
+        (
+            "bare.go",
+            r#"package main
+
+func Println(msg string) {}
+
+func run() {
+	Println("hello")
+	log.Println("hello")
+}
+"#,
+        ),
+    ]);
+
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "call:fmt.Println"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("qualified.go"))
+        .stdout(predicate::str::contains("bare.go").not());
+
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "call:Println"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("qualified.go"))
+        .stdout(predicate::str::contains("bare.go"));
+
+    let output = assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args([
+            "search",
+            "--format=matches",
+            "--no-headers",
+            "call:log.Println",
+        ])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout.lines().count(), 1, "{stdout}");
+    assert!(stdout.contains("bare.go:7:"), "{stdout}");
+}
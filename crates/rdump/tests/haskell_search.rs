@@ -255,6 +255,80 @@ primes n = [x | x <- [2..n], isPrime x]
         .stdout(predicate::str::contains("Comprehensions.hs"));
 }
 
+const TYPECLASS_SOURCE: &str = r#"
+module Shapes where
+
+class Monad m => Drawable m where
+    draw :: String -> m ()
+
+data Shape = Circle Double | Square Double
+
+instance Show Shape where
+    show (Circle r) = "circle " ++ show r
+    show (Square s) = "square " ++ show s
+
+instance Eq a => Eq (Tree a) where
+    (==) _ _ = True
+"#;
+
+#[test]
+fn test_haskell_class_predicate_matches_typeclass_declarations() {
+    let dir = setup_custom_project(&[("Shapes.hs", TYPECLASS_SOURCE)]);
+
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "class:Drawable"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Shapes.hs"));
+
+    // `Monad` is only a superclass constraint here, not a declared class.
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "class:Monad"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+}
+
+#[test]
+fn test_haskell_implements_predicate_matches_instance_class() {
+    let dir = setup_custom_project(&[("Shapes.hs", TYPECLASS_SOURCE)]);
+
+    let output = assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=matches", "implements:Show"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("instance Show Shape where"), "{stdout}");
+    assert!(!stdout.contains("instance Eq"), "{stdout}");
+
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "implements:Shape"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+}
+
+#[test]
+fn test_haskell_impl_predicate_matches_instance_type() {
+    let dir = setup_custom_project(&[("Shapes.hs", TYPECLASS_SOURCE)]);
+
+    let output = assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=matches", "impl:Tree"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.contains("instance Eq a => Eq (Tree a) where"),
+        "{stdout}"
+    );
+    assert!(!stdout.contains("instance Show"), "{stdout}");
+}
+
 // =============================================================================
 // OUTPUT FORMAT TESTS
 // =============================================================================
@@ -0,0 +1,93 @@
+use predicates::prelude::*;
+mod common;
+use common::setup_custom_project;
+
+const MAIN_TF: &str = r#"# Web tier
+resource "aws_instance" "web" {
+  ami           = "ami-123456"
+  instance_type = var.instance_type
+  tags = {
+    Name = upper("web")
+  }
+}
+
+resource "aws_s3_bucket" "assets" {
+  bucket = "assets"
+}
+
+module "vpc" {
+  source = "./modules/vpc"
+}
+"#;
+
+const VARIABLES_TF: &str = r#"variable "instance_type" {
+  type    = string
+  default = "t3.micro"
+}
+
+variable "region" {
+  default = "us-east-1"
+}
+"#;
+
+#[test]
+fn test_def_predicate_matches_resource_type_and_name() {
+    let dir = setup_custom_project(&[("main.tf", MAIN_TF), ("variables.tf", VARIABLES_TF)]);
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .arg("search")
+        .arg("def:aws_instance")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("main.tf"))
+        .stdout(predicate::str::contains("variables.tf").not());
+
+    let output = assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=matches", "def:web"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("2:"), "{stdout}");
+    // The `upper("web")` argument is an expression, not a block label.
+    assert_eq!(stdout.matches(" web").count(), 1, "{stdout}");
+}
+
+#[test]
+fn test_block_predicate_finds_variable_blocks() {
+    let dir = setup_custom_project(&[
+        ("main.tf", MAIN_TF),
+        ("variables.tf", VARIABLES_TF),
+        ("prod.tfvars", "region = \"eu-west-1\"\n"),
+    ]);
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .arg("search")
+        .arg("--format=paths")
+        .arg("block:variable & def:region")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("variables.tf"))
+        .stdout(predicate::str::contains("main.tf").not())
+        .stdout(predicate::str::contains("prod.tfvars").not());
+
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .arg("search")
+        .arg("block:module")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("module \"vpc\""));
+}
+
+#[test]
+fn test_comment_and_call_predicates_hcl() {
+    let dir = setup_custom_project(&[("main.tf", MAIN_TF)]);
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .arg("search")
+        .arg("comment:Web & call:upper")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("main.tf"));
+}
@@ -16,6 +16,7 @@ fn sample_report() -> SearchReport {
                 end_column: 7,
                 byte_range: 3..7,
                 text: "main".to_string(),
+                ..Default::default()
             }],
             content: "fn main() {\n    println!(\"hi\");\n}\n".to_string(),
             content_state: rdump::ContentState::Loaded,
@@ -38,12 +39,11 @@ fn highlight_cold_vs_warm_report_render() {
         &mut cold,
         &report,
         &Format::Hunks,
-        false,
-        false,
-        true,
-        0,
-        true,
-        rdump::TimeFormat::Local,
+        &formatter::ReportRenderOptions {
+            use_color: true,
+            show_suppressed_placeholders: true,
+            ..Default::default()
+        },
     )
     .unwrap();
     let cold_elapsed = cold_started.elapsed().as_millis();
@@ -54,12 +54,11 @@ fn highlight_cold_vs_warm_report_render() {
         &mut warm,
         &report,
         &Format::Hunks,
-        false,
-        false,
-        true,
-        0,
-        true,
-        rdump::TimeFormat::Local,
+        &formatter::ReportRenderOptions {
+            use_color: true,
+            show_suppressed_placeholders: true,
+            ..Default::default()
+        },
     )
     .unwrap();
     let warm_elapsed = warm_started.elapsed().as_millis();
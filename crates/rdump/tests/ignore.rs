@@ -99,3 +99,89 @@ fn test_rdumpignore_unignore_overrides_default_ignores() -> Result<(), Box<dyn s
 
     Ok(())
 }
+
+#[test]
+fn test_no_global_ignore_keeps_repo_gitignore() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = tempdir()?;
+    let root = dir.path();
+
+    StdCommand::new("git")
+        .arg("init")
+        .current_dir(root)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()?;
+
+    fs::File::create(root.join("excluded.log"))?.write_all(b"excluded log")?;
+    fs::File::create(root.join("ignored.log"))?.write_all(b"ignored log")?;
+    fs::File::create(root.join(".gitignore"))?.write_all(b"ignored.log")?;
+    fs::create_dir_all(root.join(".git/info"))?;
+    fs::write(root.join(".git/info/exclude"), "excluded.log\n")?;
+
+    let mut default_cmd = assert_cmd::cargo::cargo_bin_cmd!("rdump");
+    default_cmd.current_dir(root);
+    default_cmd.arg("search").arg("ext:log");
+    default_cmd
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("excluded.log").not())
+        .stdout(predicate::str::contains("ignored.log").not());
+
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("rdump");
+    cmd.current_dir(root);
+    cmd.arg("search").arg("--no-global-ignore").arg("ext:log");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("excluded.log"))
+        .stdout(predicate::str::contains("ignored.log").not());
+
+    Ok(())
+}
+
+#[test]
+fn test_ignore_parents_skips_repo_gitignore_from_subdirectory(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let dir = tempdir()?;
+    let root = dir.path();
+
+    StdCommand::new("git")
+        .arg("init")
+        .current_dir(root)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()?;
+
+    fs::File::create(root.join(".gitignore"))?.write_all(b"*.log")?;
+    let sub = root.join("service");
+    fs::create_dir(&sub)?;
+    fs::File::create(sub.join("debug.log"))?.write_all(b"debug log")?;
+    fs::File::create(sub.join("main.rs"))?.write_all(b"fn main() {}")?;
+
+    let paths = rdump::search_paths(
+        "ext:log | ext:rs",
+        rdump::SearchOptions {
+            root: sub.clone(),
+            ..Default::default()
+        },
+    )?;
+    assert!(paths.iter().any(|path| path.ends_with("main.rs")));
+    assert!(!paths.iter().any(|path| path.ends_with("debug.log")));
+
+    let paths = rdump::search_paths(
+        "ext:log | ext:rs",
+        rdump::SearchOptions::builder()
+            .root(&sub)
+            .ignore_parents(false)
+            .build(),
+    )?;
+    assert!(paths.iter().any(|path| path.ends_with("debug.log")));
+
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("rdump");
+    cmd.current_dir(&sub);
+    cmd.arg("search").arg("--no-ignore-parent").arg("ext:log");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("debug.log"));
+
+    Ok(())
+}
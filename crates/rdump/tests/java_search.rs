@@ -734,3 +734,71 @@ fn test_java_ext_filter() {
         .stdout(predicate::str::contains(".py").not())
         .stdout(predicate::str::contains(".go").not());
 }
+
+#[test]
+fn test_java_implements_predicate() {
+    let dir = setup_custom_project(&[(
+        "Version.java",
+        r#"package com.example;
+
+public class Version implements Comparable<Version>, java.io.Serializable {
+    public int compareTo(Version other) {
+        return 0;
+    }
+}
+
+class Job implements Runnable {
+    public void run() {}
+}
+"#,
+    )]);
+
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .arg("search")
+        .arg("implements:Runnable")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("class Job implements Runnable"));
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .arg("search")
+        .arg("implements:Comparable")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Version.java"));
+}
+
+#[test]
+fn test_java_interface_method_skips_default_methods_and_implementations() {
+    let dir = setup_custom_project(&[(
+        "Handler.java",
+        r#"interface Handler {
+    Response handle(Request req);
+    default void log() {}
+}
+
+class Server implements Handler {
+    public Response handle(Request req) {
+        return null;
+    }
+}
+"#,
+    )]);
+
+    let output = assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=matches", "interface_method:handle"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("2:14-2:20 handle"), "{stdout}");
+    assert!(!stdout.contains("7:"), "{stdout}");
+
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "interface_method:log"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+}
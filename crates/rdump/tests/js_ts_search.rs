@@ -423,3 +423,184 @@ fn test_ts_ext_filter() {
         .success()
         .stdout(predicate::str::contains(".js").not());
 }
+
+#[test]
+fn test_ts_implements_predicate() {
+    let dir = common::setup_custom_project(&[(
+        "version.ts",
+        r#"
+interface Comparable<T> {
+    compareTo(other: T): number;
+}
+
+export class Version implements Comparable<Version> {
+    compareTo(other: Version): number {
+        return 0;
+    }
+}
+
+export class Tag implements Named, Comparable<Tag> {
+    name = "tag";
+    compareTo(other: Tag): number {
+        return 0;
+    }
+}
+
+export class Plain {}
+"#,
+    )]);
+
+    let output = assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=matches", "implements:Comparable"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout.matches(" Comparable").count(), 2, "{stdout}");
+
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .arg("search")
+        .arg("implements:Plain")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("version.ts").not());
+}
+
+#[test]
+fn test_js_route_predicate_matches_express_calls() {
+    let dir = common::setup_custom_project(&[(
+        "server.js",
+        r#"const express = require("express");
+const app = express();
+const router = express.Router();
+
+app.get("/users", (req, res) => res.json([]));
+router.post('/users/:id', handler);
+app.use(`/api`, router);
+
+const cache = new Map();
+cache.get("users");
+"#,
+    )]);
+
+    let output = assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=matches", "route:/users"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains(r#""/users""#), "{stdout}");
+    assert!(stdout.contains("'/users/:id'"), "{stdout}");
+    assert!(!stdout.contains(r#""users""#), "{stdout}");
+
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=matches", "route:/api"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("`/api`"));
+}
+
+#[test]
+fn test_js_ext_map_treats_mjs_as_javascript() {
+    let dir = common::setup_custom_project(&[(
+        "module.mjs",
+        r#"export function loadConfig(path) {
+    return import(path);
+}
+"#,
+    )]);
+
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "func:loadConfig"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("module.mjs").not());
+
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args([
+            "search",
+            "--format=paths",
+            "--ext-map",
+            "mjs=js",
+            "func:loadConfig",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("module.mjs"));
+}
+
+#[test]
+fn test_js_ts_extends_matches_base_classes() {
+    let dir = common::setup_custom_project(&[
+        (
+            "widget.js",
+            "class Widget extends React.Component {\n  render() {}\n}\n",
+        ),
+        (
+            "errors.ts",
+            "class HttpError extends Error implements Serializable {\n  status = 500;\n}\n",
+        ),
+        (
+            "panel.tsx",
+            "class Panel extends Component<Props> {\n  render() { return null; }\n}\n",
+        ),
+    ]);
+
+    let output = assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "extends:Component"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("widget.js"), "{stdout}");
+    assert!(stdout.contains("panel.tsx"), "{stdout}");
+    assert!(!stdout.contains("errors.ts"), "{stdout}");
+
+    let output = assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "extends:Error"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("errors.ts"), "{stdout}");
+    assert!(!stdout.contains("widget.js"), "{stdout}");
+}
+
+#[test]
+fn test_ts_interface_method_matches_only_interface_signatures() {
+    let dir = common::setup_custom_project(&[(
+        "server.ts",
+        r#"interface Handler {
+  handle(req: Request): Response;
+}
+
+class Server implements Handler {
+  handle(req: Request): Response {
+    return {} as Response;
+  }
+}
+"#,
+    )]);
+
+    let output = assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=matches", "interface_method:handle"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("2:3-2:9 handle"), "{stdout}");
+    assert!(!stdout.contains("6:3-6:9 handle"), "{stdout}");
+
+    let output = assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=matches", "func:handle"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("6:3-6:9 handle"), "{stdout}");
+    assert!(!stdout.contains("2:3-2:9 handle"), "{stdout}");
+}
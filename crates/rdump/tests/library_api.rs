@@ -1,7 +1,7 @@
 use anyhow::Result;
 use once_cell::sync::Lazy;
 use rdump::search_iter;
-use rdump::{search, SearchOptions};
+use rdump::{search, ColorChoice, GroupByFlag, SearchArgs, SearchOptions, SearchSession};
 use std::env;
 use std::fs;
 use std::path::PathBuf;
@@ -159,7 +159,11 @@ fn test_search_nonexistent_root() {
         },
     );
 
-    assert!(result.is_err());
+    let message = result.unwrap_err().to_string();
+    assert!(
+        message.contains("root directory does not exist: /nonexistent/path/that/does/not/exist"),
+        "{message}"
+    );
 }
 
 #[test]
@@ -285,6 +289,34 @@ fn test_not_query() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_group_by_language_prints_a_section_per_language() -> Result<()> {
+    let dir = create_multi_lang_fixtures()?;
+    let output = dir.path().join("grouped.txt");
+
+    rdump::commands::search::run_search(SearchArgs {
+        query: Some("func:main".to_string()),
+        root: dir.path().to_path_buf(),
+        output: Some(output.clone()),
+        color: ColorChoice::Never,
+        group_by: GroupByFlag::Language,
+        ..Default::default()
+    })?;
+
+    let text = fs::read_to_string(output)?;
+    let javascript = text.find("=== JavaScript ===").expect("JavaScript section");
+    let python = text.find("=== Python ===").expect("Python section");
+    assert!(javascript < python, "sections are sorted by name:\n{text}");
+    let app = text.find("app.js").expect("app.js in output");
+    let script = text.find("script.py").expect("script.py in output");
+    assert!(
+        javascript < app && app < python && python < script,
+        "{text}"
+    );
+    assert!(!text.contains("=== Rust ==="), "{text}");
+    Ok(())
+}
+
 #[test]
 fn test_complex_compound_query() -> Result<()> {
     let dir = create_rust_fixtures()?;
@@ -409,6 +441,109 @@ fn test_match_multiline() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_impl_match_spans_the_whole_block() -> Result<()> {
+    let dir = tempdir()?;
+    fs::write(
+        dir.path().join("user.rs"),
+        r#"pub struct User;
+
+impl User {
+    pub fn new() -> Self {
+        User
+    }
+}
+
+impl std::fmt::Display for User {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "user")
+    }
+}
+
+impl<T> Wrapper<T> {}
+"#,
+    )?;
+    let options = || SearchOptions {
+        root: dir.path().to_path_buf(),
+        ..Default::default()
+    };
+
+    let results = search("impl:User", options())?;
+    assert_eq!(results.len(), 1);
+    let spans: Vec<_> = results[0]
+        .matches
+        .iter()
+        .map(|m| (m.start_line, m.end_line))
+        .collect();
+    assert_eq!(spans, vec![(3, 7), (9, 13)]);
+    assert!(results[0].matches[0].text.starts_with("impl User {"));
+    assert!(results[0].matches[0].text.ends_with('}'));
+
+    let results = search("impl:.", options())?;
+    assert_eq!(results[0].matches.len(), 3);
+    let results = search("impl:Wrapper", options())?;
+    assert_eq!(results[0].matches[0].start_line, 15);
+    Ok(())
+}
+
+#[test]
+fn test_context_lines_capture_surrounding_lines() -> Result<()> {
+    let dir = tempdir()?;
+    fs::write(
+        dir.path().join("notes.txt"),
+        "one\ntwo\nthree\nfour\nneedle\nsix\nseven\neight\nnine\n",
+    )?;
+    let options = |context_lines| SearchOptions {
+        root: dir.path().to_path_buf(),
+        context_lines,
+        ..Default::default()
+    };
+
+    let results = search("contains:needle", options(Some(2)))?;
+    let matched = &results[0].matches[0];
+    assert_eq!(matched.start_line, 5);
+    assert_eq!(matched.context_before, vec!["three", "four"]);
+    assert_eq!(matched.context_after, vec!["six", "seven"]);
+
+    let results = search("contains:one", options(Some(2)))?;
+    assert!(results[0].matches[0].context_before.is_empty());
+    assert_eq!(results[0].matches[0].context_after, vec!["two", "three"]);
+
+    let results = search("contains:needle", options(None))?;
+    assert!(results[0].matches[0].context_before.is_empty());
+    assert!(results[0].matches[0].context_after.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_track_predicates_records_the_source_predicate() -> Result<()> {
+    let dir = tempdir()?;
+    fs::write(
+        dir.path().join("lib.rs"),
+        "struct Config;\n\nfn load() {}\n",
+    )?;
+    let options = |track_predicates| SearchOptions {
+        root: dir.path().to_path_buf(),
+        track_predicates,
+        ..Default::default()
+    };
+
+    let results = search("func:load | struct:Config", options(true))?;
+    let predicates: Vec<_> = results[0]
+        .matches
+        .iter()
+        .map(|matched| matched.predicate.as_deref())
+        .collect();
+    assert_eq!(predicates, vec![Some("struct"), Some("func")]);
+
+    let results = search("func:load | struct:Config", options(false))?;
+    assert!(results[0]
+        .matches
+        .iter()
+        .all(|matched| matched.predicate.is_none()));
+    Ok(())
+}
+
 #[test]
 fn test_match_byte_range() -> Result<()> {
     let dir = tempdir()?;
@@ -639,6 +774,57 @@ fn test_max_depth_option() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_min_depth_option() -> Result<()> {
+    let dir = tempdir()?;
+    write_nested_file(&dir.path().to_path_buf(), "level1.rs", "fn l1() {}");
+    write_nested_file(&dir.path().to_path_buf(), "sub/sub/x.rs", "fn x() {}");
+
+    let results = search(
+        "ext:rs",
+        SearchOptions {
+            root: dir.path().to_path_buf(),
+            min_depth: Some(2),
+            ..Default::default()
+        },
+    )?;
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].path.file_name().unwrap(), "x.rs");
+    Ok(())
+}
+
+#[test]
+fn test_result_language_and_detected_dialect() -> Result<()> {
+    let dir = tempdir()?;
+    fs::write(dir.path().join("main.rs"), "// needle\nfn main() {}\n")?;
+    fs::write(dir.path().join("notes.unknownext"), "needle\n")?;
+    fs::write(
+        dir.path().join("report.sql"),
+        "-- needle\nCREATE FUNCTION f() RETURNS TABLE (id int) AS $$ SELECT 1 $$ LANGUAGE sql;\n",
+    )?;
+
+    let results = search(
+        "contains:needle",
+        SearchOptions::builder().root(dir.path()).build(),
+    )?;
+    let find = |name: &str| {
+        results
+            .iter()
+            .find(|result| result.path.file_name().unwrap() == name)
+            .unwrap()
+    };
+
+    assert_eq!(find("main.rs").language(), Some("Rust"));
+    assert_eq!(find("main.rs").detected_dialect(), None);
+    assert_eq!(find("notes.unknownext").language(), None);
+    assert_eq!(
+        find("report.sql").detected_dialect(),
+        Some(rdump::SqlDialect::Postgres)
+    );
+    Ok(())
+}
+
 #[test]
 fn test_hidden_files_option() -> Result<()> {
     let dir = tempdir()?;
@@ -766,6 +952,43 @@ fn test_multiple_presets() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_builder_preset_appends_each_preset() -> Result<()> {
+    let _lock = lock_env();
+    let dir = tempdir()?;
+    let config_root = dir.path().join("config");
+    let preset_dir = config_root.join("rdump");
+    fs::create_dir_all(&preset_dir)?;
+    fs::write(
+        preset_dir.join("config.toml"),
+        r#"
+            [presets]
+            rust = "ext:rs"
+            has_main = "contains:main"
+        "#,
+    )?;
+
+    let code_dir = dir.path().join("code");
+    fs::create_dir_all(&code_dir)?;
+    fs::write(code_dir.join("main.rs"), "fn main() {}")?;
+    fs::write(code_dir.join("lib.rs"), "pub fn lib() {}")?;
+    fs::write(code_dir.join("main.py"), "def main(): pass")?;
+
+    let _guard = EnvVarGuard::new("RDUMP_TEST_CONFIG_DIR", config_root.to_str().unwrap());
+    let results = search(
+        "",
+        SearchOptions::builder()
+            .root(&code_dir)
+            .preset("rust")
+            .preset("has_main")
+            .build(),
+    )?;
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].path.file_name().unwrap(), "main.rs");
+    Ok(())
+}
+
 #[test]
 fn test_custom_root() -> Result<()> {
     let dir = tempdir()?;
@@ -908,6 +1131,275 @@ fn test_symlinks_not_followed_by_default() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_custom_secret_patterns_replace_builtin_list() -> Result<()> {
+    let dir = tempdir()?;
+    fs::write(
+        dir.path().join("github.rs"),
+        "const TOKEN: &str = \"ghp_0123456789abcdefABCDEF\";\n",
+    )?;
+    fs::write(
+        dir.path().join("aws.rs"),
+        "const AWS_SECRET_ACCESS_KEY: &str = \"example\";\n",
+    )?;
+
+    let results = search(
+        "ext:rs",
+        SearchOptions {
+            root: dir.path().to_path_buf(),
+            secret_patterns: Some(vec!["ghp_[A-Za-z0-9]{20,}".to_string()]),
+            ..Default::default()
+        },
+    )?;
+    let state_for = |name: &str| {
+        results
+            .iter()
+            .find(|result| result.path.ends_with(name))
+            .map(|result| result.content_state.clone())
+            .expect("expected result")
+    };
+    assert_eq!(
+        state_for("github.rs"),
+        rdump::ContentState::Skipped {
+            reason: rdump::ContentSkipReason::SecretLike
+        }
+    );
+    assert_eq!(state_for("aws.rs"), rdump::ContentState::Loaded);
+    Ok(())
+}
+
+#[test]
+fn test_empty_secret_patterns_disable_detection() -> Result<()> {
+    let dir = tempdir()?;
+    fs::write(
+        dir.path().join("config.rs"),
+        "const AWS_SECRET_ACCESS_KEY: &str = \"example\";\n",
+    )?;
+
+    let results = search(
+        "ext:rs",
+        SearchOptions {
+            root: dir.path().to_path_buf(),
+            secret_patterns: Some(Vec::new()),
+            ..Default::default()
+        },
+    )?;
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].content_state, rdump::ContentState::Loaded);
+    assert!(results[0].content.contains("AWS_SECRET_ACCESS_KEY"));
+    Ok(())
+}
+
+#[test]
+fn test_line_range_restricts_content_and_semantic_matches() -> Result<()> {
+    let dir = tempdir()?;
+    fs::write(
+        dir.path().join("lib.rs"),
+        "// todo: one\nfn first() {}\n// todo: two\nfn second() {}\n// todo: three\n",
+    )?;
+    let options = |line_range| SearchOptions {
+        root: dir.path().to_path_buf(),
+        line_range,
+        ..Default::default()
+    };
+
+    let results = search("contains:todo", options(Some((2, 4))))?;
+    assert_eq!(results.len(), 1);
+    let lines: Vec<usize> = results[0].matches.iter().map(|m| m.start_line).collect();
+    assert_eq!(lines, vec![3]);
+
+    let results = search("func:.", options(Some((4, 5))))?;
+    assert_eq!(results.len(), 1);
+    let lines: Vec<usize> = results[0].matches.iter().map(|m| m.start_line).collect();
+    assert_eq!(lines, vec![4]);
+
+    let results = search("contains:todo | func:.", options(Some((100, 200))))?;
+    assert!(results.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_search_session_reuses_trees_until_files_change() -> Result<()> {
+    let dir = tempdir()?;
+    fs::write(dir.path().join("a.rs"), "fn alpha() {}\nfn beta() {}\n")?;
+    fs::write(dir.path().join("b.py"), "def gamma():\n    pass\n")?;
+    let options = SearchOptions {
+        root: dir.path().to_path_buf(),
+        deterministic: true,
+        ..Default::default()
+    };
+    let summarize = |results: Vec<rdump::SearchResult>| -> Vec<(PathBuf, Vec<usize>)> {
+        results
+            .into_iter()
+            .map(|result| {
+                let lines = result.matches.iter().map(|m| m.start_line).collect();
+                (result.path, lines)
+            })
+            .collect()
+    };
+
+    let session = SearchSession::new();
+    let first = summarize(session.search("func:.", &options)?);
+    let second = summarize(session.search("func:.", &options)?);
+    assert_eq!(first.len(), 2);
+    assert_eq!(first, second);
+    assert_eq!(session.tree_cache().parse_count(), 2);
+
+    fs::write(
+        dir.path().join("a.rs"),
+        "fn alpha() {}\nfn beta() {}\nfn delta() {}\n",
+    )?;
+    let third = summarize(session.search("func:.", &options)?);
+    assert_eq!(session.tree_cache().parse_count(), 3);
+    assert_eq!(third[0].1, vec![1, 2, 3]);
+    Ok(())
+}
+
+#[test]
+fn test_search_session_tree_cache_evicts_least_recently_used() -> Result<()> {
+    let dir = tempdir()?;
+    fs::write(dir.path().join("a.rs"), "fn alpha() {}\n")?;
+    fs::write(dir.path().join("b.rs"), "fn beta() {}\n")?;
+    let options = SearchOptions {
+        root: dir.path().to_path_buf(),
+        ..Default::default()
+    };
+
+    let session = SearchSession::with_runtime_and_tree_cache(
+        rdump::SearchRuntime::real_fs(),
+        std::sync::Arc::new(rdump::TreeCache::with_capacity(1)),
+    );
+    let cache = session.tree_cache();
+    assert_eq!(session.search("func:.", &options)?.len(), 2);
+    assert_eq!(cache.parse_count(), 2);
+    assert_eq!(cache.len(), 1);
+
+    // Once `a.rs` holds the only slot, searching it again reuses the tree...
+    session.search("name:a.rs & func:alpha", &options)?;
+    let parses = cache.parse_count();
+    session.search("name:a.rs & func:alpha", &options)?;
+    assert_eq!(cache.parse_count(), parses);
+
+    // ...while `b.rs` has been evicted and is parsed again, taking its place.
+    session.search("name:b.rs & func:beta", &options)?;
+    assert_eq!(cache.parse_count(), parses + 1);
+    assert_eq!(cache.len(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_show_enclosing_honors_ext_map_and_reuses_cached_trees() -> Result<()> {
+    let dir = tempdir()?;
+    fs::write(
+        dir.path().join("lexer.rs.in"),
+        "mod tokens {\n    const LIMIT: u32 = 1;\n}\n",
+    )?;
+    let options = SearchOptions {
+        root: dir.path().to_path_buf(),
+        show_enclosing: true,
+        ext_map: [("rs.in".to_string(), "rust".to_string())].into(),
+        ..Default::default()
+    };
+
+    let session = SearchSession::new();
+    for _ in 0..2 {
+        let results = session.search("contains:LIMIT", &options)?;
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].matches[0].enclosing.as_deref(),
+            Some("mod tokens")
+        );
+    }
+    assert_eq!(session.tree_cache().parse_count(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_search_session_reparses_when_the_encoding_changes() -> Result<()> {
+    let dir = tempdir()?;
+    // `é` in Latin-1 is a lone byte that lossy UTF-8 widens to U+FFFD, shifting later offsets.
+    fs::write(dir.path().join("lib.rs"), b"// caf\xe9\nfn alpha() {}\n")?;
+    let session = SearchSession::new();
+    let search_with = |encoding| {
+        session.search(
+            "func:alpha",
+            &SearchOptions {
+                root: dir.path().to_path_buf(),
+                encoding,
+                ..Default::default()
+            },
+        )
+    };
+
+    for encoding in [
+        rdump::contracts::Encoding::Auto,
+        rdump::contracts::Encoding::Latin1,
+    ] {
+        let results = search_with(encoding)?;
+        assert_eq!(results.len(), 1);
+        let matched = &results[0].matches[0];
+        assert_eq!(
+            &results[0].content[matched.byte_range.clone()],
+            matched.text.as_str()
+        );
+        assert!(matched.text.contains("alpha"), "{:?}", matched.text);
+    }
+    assert_eq!(session.tree_cache().parse_count(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_encoding_transcodes_utf16_fixture() -> Result<()> {
+    let dir = tempdir()?;
+    let text = "// légende\nfn greet() { println!(\"héllo\"); }\n";
+    let mut with_bom = vec![0xFF, 0xFE];
+    with_bom.extend(text.encode_utf16().flat_map(u16::to_le_bytes));
+    fs::write(dir.path().join("bom.rs"), with_bom)?;
+    let without_bom: Vec<u8> = text.encode_utf16().flat_map(u16::to_be_bytes).collect();
+    fs::write(dir.path().join("big_endian.rs"), without_bom)?;
+
+    let options = |query: &str, encoding| {
+        search(
+            query,
+            SearchOptions {
+                root: dir.path().to_path_buf(),
+                encoding,
+                ..Default::default()
+            },
+        )
+    };
+
+    let results = options(
+        "name:bom.rs & contains:héllo",
+        rdump::contracts::Encoding::Auto,
+    )?;
+    assert_eq!(results.len(), 1);
+    let matched = &results[0].matches[0];
+    assert_eq!(matched.start_line, 2);
+    assert_eq!(
+        &results[0].content[matched.byte_range.clone()],
+        matched.text.as_str()
+    );
+    assert!(matched.text.contains("héllo"));
+
+    let results = options("name:bom.rs & func:greet", rdump::contracts::Encoding::Auto)?;
+    assert_eq!(results.len(), 1);
+
+    let results = options(
+        "name:big_endian.rs & contains:légende",
+        rdump::contracts::Encoding::Auto,
+    )?;
+    assert!(results.is_empty());
+
+    let results = options(
+        "name:big_endian.rs & contains:légende",
+        rdump::contracts::Encoding::Utf16Be,
+    )?;
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].content, text);
+    Ok(())
+}
+
 #[test]
 fn test_binary_file_detection() -> Result<()> {
     let dir = tempdir()?;
@@ -937,6 +1429,69 @@ fn test_binary_file_detection() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_load_content_false_never_reads_binary_files() -> Result<()> {
+    let dir = tempdir()?;
+    create_binary_file(&dir.path().to_path_buf(), "blob.rs");
+    fs::write(dir.path().join("lib.rs"), "fn keep() {}\n")?;
+
+    let results = search(
+        "ext:rs",
+        SearchOptions {
+            root: dir.path().to_path_buf(),
+            load_content: false,
+            deterministic: true,
+            ..Default::default()
+        },
+    )?;
+
+    assert_eq!(results.len(), 2);
+    for result in &results {
+        assert_eq!(
+            result.content_state,
+            rdump::ContentState::Skipped {
+                reason: rdump::ContentSkipReason::NotRequested
+            }
+        );
+        assert!(result.content.is_empty());
+        assert!(result.diagnostics.is_empty(), "{:?}", result.diagnostics);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_progress_callback_reports_scanned_and_matched_files() -> Result<()> {
+    let dir = tempdir()?;
+    for index in 0..150 {
+        let body = if index % 3 == 0 {
+            "fn keep() {}\n"
+        } else {
+            "fn skip() {}\n"
+        };
+        fs::write(dir.path().join(format!("file_{index:03}.rs")), body)?;
+    }
+
+    let reports = std::sync::Arc::new(Mutex::new(Vec::new()));
+    let sink = std::sync::Arc::clone(&reports);
+    let options = SearchOptions::builder()
+        .root(dir.path())
+        .progress(move |progress| sink.lock().unwrap().push(progress))
+        .build();
+    let results = search("ext:rs & contains:keep", options)?;
+
+    let reports = reports.lock().unwrap();
+    assert_eq!(results.len(), 50);
+    assert_eq!(reports.len(), 150 / rdump::PROGRESS_INTERVAL + 1);
+    assert!(reports
+        .windows(2)
+        .all(|pair| pair[0].files_scanned < pair[1].files_scanned));
+    let last = reports.last().unwrap();
+    assert_eq!(last.files_scanned, 150);
+    assert_eq!(last.files_matched, results.len());
+    assert!(last.current_path.to_string_lossy().ends_with(".rs"));
+    Ok(())
+}
+
 #[test]
 fn test_results_can_be_accessed_after_tempdir_dropped() -> Result<()> {
     let dir = tempdir()?;
@@ -0,0 +1,77 @@
+use predicates::prelude::*;
+mod common;
+use common::setup_custom_project;
+
+const GUIDE_MD: &str = r#"# Project
+
+Read the [API docs](https://docs.rs/project "Docs") before upgrading.
+
+## Installation
+
+Run `cargo install project`.
+
+Public API
+----------
+
+See [the changelog][changelog] for breaking changes.
+
+[changelog]: https://example.com/CHANGELOG.md
+"#;
+
+const NOTES_MD: &str = r#"# Notes
+
+Installation details live in the guide, see [guide](./guide.md).
+"#;
+
+#[test]
+fn test_heading_predicate_matches_atx_and_setext_headings() {
+    let dir = setup_custom_project(&[("guide.md", GUIDE_MD), ("notes.md", NOTES_MD)]);
+    // `notes.md` only mentions "Installation" in a paragraph.
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .arg("search")
+        .arg("heading:Installation")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("guide.md"))
+        .stdout(predicate::str::contains("notes.md").not());
+
+    let output = assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=matches", "heading:API"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("Public API"), "{stdout}");
+    assert!(!stdout.contains("API docs"), "{stdout}");
+}
+
+#[test]
+fn test_link_predicate_matches_inline_and_reference_targets() {
+    let dir = setup_custom_project(&[("guide.md", GUIDE_MD), ("notes.md", NOTES_MD)]);
+    for query in ["link:docs.rs", "link:CHANGELOG"] {
+        assert_cmd::cargo::cargo_bin_cmd!("rdump")
+            .current_dir(dir.path())
+            .arg("search")
+            .arg(query)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("guide.md"))
+            .stdout(predicate::str::contains("notes.md").not());
+    }
+
+    // Link text is not a target.
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "link:upgrading"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "link:guide.md"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("notes.md"));
+}
@@ -250,6 +250,85 @@ end
         .stdout(predicate::str::contains("functors.ml"));
 }
 
+#[test]
+fn test_ocaml_module_predicate_matches_modules_functors_and_signatures() {
+    let dir = setup_custom_project(&[
+        (
+            "config.ml",
+            r#"
+module type SETTINGS = sig
+  val port : int
+end
+
+module Config = struct
+  let port = 8080
+end
+
+module Make (Base : SETTINGS) = struct
+  let describe () = string_of_int Base.port
+end
+
+module Server = Make (Config)
+
+let start () =
+  let banner = "listening" in
+  print_endline banner
+"#,
+        ),
+        (
+            "config.mli",
+            r#"
+module Config : sig
+  val port : int
+end
+"#,
+        ),
+    ]);
+
+    for query in [
+        "module:Config",
+        "module:Make",
+        "module:SETTINGS",
+        "module:Server",
+    ] {
+        assert_cmd::cargo::cargo_bin_cmd!("rdump")
+            .current_dir(dir.path())
+            .args(["search", "--format=paths", query])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("config.ml"));
+    }
+
+    // The .mli signature declares the module too.
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "module:Config & ext:mli"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("config.mli"));
+
+    // Functor parameters and applied module paths are not definitions.
+    for query in ["module:Base", "module:Conf"] {
+        assert_cmd::cargo::cargo_bin_cmd!("rdump")
+            .current_dir(dir.path())
+            .args(["search", "--format=paths", query])
+            .assert()
+            .success()
+            .stdout(predicate::str::is_empty());
+    }
+
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args([
+            "search",
+            "--format=paths",
+            "func:start & module:Config & ext:ml",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("config.ml"));
+}
+
 // =============================================================================
 // OUTPUT FORMAT TESTS
 // =============================================================================
@@ -47,3 +47,95 @@ fn test_path_globstar_crosses_directory_boundaries() {
         .stdout(predicate::str::contains("root_file.rs"))
         .stdout(predicate::str::contains("deep_file.rs"));
 }
+
+#[test]
+fn test_in_accepts_multiple_directories_globs_and_negation() {
+    let dir = tempdir().unwrap();
+    let root = dir.path();
+    for (path, content) in [
+        ("src/main.rs", "fn main() {}"),
+        ("lib/util.rs", "fn util() {}"),
+        ("tests/it.rs", "fn it() {}"),
+        ("packages/web/src/app.ts", "export {}"),
+        ("packages/api/src/server.ts", "export {}"),
+        ("packages/api/test/server.test.ts", "export {}"),
+    ] {
+        let file = root.join(path);
+        fs::create_dir_all(file.parent().unwrap()).unwrap();
+        fs::write(file, content).unwrap();
+    }
+
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("rdump");
+    cmd.current_dir(root);
+    cmd.args(["search", "--format=paths", "in:src,lib"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("src/main.rs"))
+        .stdout(predicate::str::contains("lib/util.rs"))
+        .stdout(predicate::str::contains("tests/it.rs").not())
+        .stdout(predicate::str::contains("app.ts").not());
+
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("rdump");
+    cmd.current_dir(root);
+    cmd.args(["search", "--format=paths", "ext:rs & !in:tests"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("src/main.rs"))
+        .stdout(predicate::str::contains("lib/util.rs"))
+        .stdout(predicate::str::contains("tests/it.rs").not());
+
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("rdump");
+    cmd.current_dir(root);
+    cmd.args(["search", "--format=paths", "in:packages/*/src"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("packages/web/src/app.ts"))
+        .stdout(predicate::str::contains("packages/api/src/server.ts"))
+        .stdout(predicate::str::contains("server.test.ts").not())
+        .stdout(predicate::str::contains("main.rs").not());
+}
+
+#[test]
+fn test_in_plain_directory_is_exact_while_globs_match_at_any_depth() {
+    let dir = tempdir().unwrap();
+    let root = dir.path();
+    for path in [
+        "src/main.rs",
+        "src/handlers/index.rs",
+        "src/api/handlers/users.rs",
+        "lib/handlers/auth.rs",
+    ] {
+        let file = root.join(path);
+        fs::create_dir_all(file.parent().unwrap()).unwrap();
+        fs::write(file, "fn handler() {}").unwrap();
+    }
+
+    // A plain directory only covers the files directly inside it.
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("rdump");
+    cmd.current_dir(root);
+    cmd.args(["search", "--format=paths", "in:src"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("src/main.rs"))
+        .stdout(predicate::str::contains("handlers").not());
+
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("rdump");
+    cmd.current_dir(root);
+    cmd.args(["search", "--format=paths", "in:**/handlers"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("src/handlers/index.rs"))
+        .stdout(predicate::str::contains("src/api/handlers/users.rs"))
+        .stdout(predicate::str::contains("lib/handlers/auth.rs"))
+        .stdout(predicate::str::contains("main.rs").not());
+
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("rdump");
+    cmd.current_dir(root);
+    cmd.args(["search", "--format=paths", "in:src/**/handlers"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("src/handlers/index.rs"))
+        .stdout(predicate::str::contains("src/api/handlers/users.rs"))
+        .stdout(predicate::str::contains("auth.rs").not())
+        .stdout(predicate::str::contains("main.rs").not());
+}
@@ -212,6 +212,46 @@ class Utils {
         .stdout(predicate::str::contains("utils.php"));
 }
 
+#[test]
+fn test_php_namespace_attribute_and_trait_predicates() {
+    let dir = setup_custom_project(&[(
+        "UserController.php",
+        r#"<?php
+namespace App\Controllers;
+
+trait Loggable {}
+
+#[Route('/users')]
+class UserController {
+    use Loggable;
+}
+"#,
+    )]);
+
+    for query in [
+        "namespace:Controllers & ext:php",
+        "decorator:Route & ext:php",
+        "annotation:Route & ext:php",
+        "trait:Loggable & ext:php",
+    ] {
+        assert_cmd::cargo::cargo_bin_cmd!("rdump")
+            .current_dir(dir.path())
+            .arg("search")
+            .arg(query)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("UserController.php"));
+    }
+
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .arg("search")
+        .arg("decorator:Deprecated & ext:php")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+}
+
 // =============================================================================
 // EDGE CASE TESTS
 // =============================================================================
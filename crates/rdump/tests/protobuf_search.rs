@@ -0,0 +1,121 @@
+use predicates::prelude::*;
+mod common;
+use common::setup_custom_project;
+
+const USER_PROTO: &str = r#"syntax = "proto3";
+
+package accounts.v1;
+
+import "google/protobuf/timestamp.proto";
+
+// A registered account.
+message User {
+  string id = 1;
+  string email = 2;
+  google.protobuf.Timestamp created_at = 3;
+}
+
+message GetUserRequest {
+  string id = 1;
+}
+
+enum Role {
+  ROLE_UNSPECIFIED = 0;
+  ROLE_ADMIN = 1;
+}
+
+service UserService {
+  rpc GetUser(GetUserRequest) returns (User);
+  rpc ListUsers(GetUserRequest) returns (stream User);
+}
+"#;
+
+const BILLING_PROTO: &str = r#"syntax = "proto3";
+
+package billing.v1;
+
+message Invoice {
+  string user_id = 1;
+}
+
+service BillingService {
+  rpc GetInvoice(Invoice) returns (Invoice);
+}
+"#;
+
+fn proto_project() -> tempfile::TempDir {
+    setup_custom_project(&[("user.proto", USER_PROTO), ("billing.proto", BILLING_PROTO)])
+}
+
+#[test]
+fn test_message_predicate_matches_message_declarations() {
+    let dir = proto_project();
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "message:User"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("user.proto"))
+        .stdout(predicate::str::contains("billing.proto").not());
+
+    let output = assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=matches", "struct:Invoice"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("billing.proto"), "{stdout}");
+    assert!(!stdout.contains("user.proto"), "{stdout}");
+
+    // `def:` covers messages, enums and services alike.
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "def:Role"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("user.proto"));
+}
+
+#[test]
+fn test_service_predicate_matches_grpc_services() {
+    let dir = proto_project();
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "service:BillingService"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("billing.proto"))
+        .stdout(predicate::str::contains("user.proto").not());
+
+    // A message is not a service.
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "service:User"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+}
+
+#[test]
+fn test_func_predicate_matches_rpc_methods() {
+    let dir = proto_project();
+    let output = assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=matches", "func:GetUser"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("24:7-24:14 GetUser"), "{stdout}");
+    assert!(!stdout.contains("billing.proto"), "{stdout}");
+
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args([
+            "search",
+            "--format=paths",
+            "service:UserService & func:ListUsers",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("user.proto"));
+}
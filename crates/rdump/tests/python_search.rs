@@ -671,6 +671,103 @@ docstring with special content
         .stdout(predicate::str::contains("docs.py"));
 }
 
+#[test]
+fn test_python_str_doc_matches_only_docstrings() {
+    let dir = setup_custom_project(&[
+        (
+            "docs.py",
+            r#"
+docstring = """
+This is a multiline
+docstring with special content
+"""
+"#,
+        ),
+        (
+            "service.py",
+            r#""""Service helpers with special content."""
+
+class Service:
+    """Runs the special content pipeline."""
+
+    def run(self):
+        """Start the special content job."""
+        return "special content"
+"#,
+        ),
+    ]);
+
+    // An assigned string is a plain string, not a docstring.
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .arg("search")
+        .arg("--format=paths")
+        .arg("str:doc:multiline")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+
+    let output = assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .arg("search")
+        .arg("--format=hunks")
+        .args(["--context", "0"])
+        .arg("str:doc:special")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Service helpers"), "{stdout}");
+    assert!(stdout.contains("Runs the special"), "{stdout}");
+    assert!(stdout.contains("Start the special"), "{stdout}");
+    assert!(!stdout.contains("return"), "{stdout}");
+    assert!(!stdout.contains("multiline"), "{stdout}");
+
+    // Plain `str:` still matches every string.
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .arg("search")
+        .arg("--format=paths")
+        .arg("str:multiline")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("docs.py"));
+}
+
+#[test]
+fn test_python_call_arity_suffix() {
+    let dir = setup_custom_project(&[
+        ("one.py", "print(\"hello\")\n"),
+        ("two.py", "print(\"hello\", \"world\")\n"),
+    ]);
+
+    for (query, expected, unexpected) in [
+        ("call:print#1", "one.py", "two.py"),
+        ("call:print#2", "two.py", "one.py"),
+    ] {
+        assert_cmd::cargo::cargo_bin_cmd!("rdump")
+            .current_dir(dir.path())
+            .arg("search")
+            .arg("--format=paths")
+            .arg(query)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(expected))
+            .stdout(predicate::str::contains(unexpected).not());
+    }
+
+    // Without a suffix both calls match.
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .arg("search")
+        .arg("--format=paths")
+        .arg("call:print")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("one.py"))
+        .stdout(predicate::str::contains("two.py"));
+}
+
 #[test]
 fn test_python_custom_f_string() {
     let dir = setup_custom_project(&[(
@@ -901,3 +998,131 @@ fn test_python_all_classes_count() {
     let file_count = stdout.lines().filter(|l| !l.is_empty()).count();
     assert!(file_count >= 2, "Expected at least 2 files with classes");
 }
+
+#[test]
+fn test_python_route_predicate_matches_flask_decorators() {
+    let dir = setup_custom_project(&[(
+        "app.py",
+        r#"from flask import Flask
+
+app = Flask(__name__)
+
+
+@app.route("/users")
+def list_users():
+    return lookup("/users/legacy")
+
+
+@app.post("/orders/<int:order_id>")
+def create_order(order_id):
+    return order_id
+
+
+@cache.get("users")
+def cached():
+    return None
+"#,
+    )]);
+
+    let output = assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=matches", "route:/users"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains(r#""/users""#), "{stdout}");
+    assert!(!stdout.contains("legacy"), "{stdout}");
+    assert!(!stdout.contains("orders"), "{stdout}");
+
+    let output = assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=matches", "route:."])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("/orders/<int:order_id>"), "{stdout}");
+    assert!(!stdout.contains(r#""users""#), "{stdout}");
+}
+
+#[test]
+fn test_python_extends_matches_base_classes() {
+    let dir = setup_custom_project(&[
+        (
+            "errors.py",
+            r#"import errors
+
+
+class NotFound(Exception):
+    pass
+
+
+class Conflict(errors.ApiError, metaclass=Registry):
+    pass
+"#,
+        ),
+        ("plain.py", "class Exception:\n    pass\n"),
+    ]);
+
+    let output = assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "superclass:Exception"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("errors.py"), "{stdout}");
+    assert!(!stdout.contains("plain.py"), "{stdout}");
+
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "extends:ApiError"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("errors.py"));
+}
+
+#[test]
+fn test_doc_predicate_matches_docstrings_but_not_hash_comments() {
+    let dir = setup_custom_project(&[
+        (
+            "client.py",
+            r#""""HTTP client helpers."""
+
+
+class Client:
+    """Deprecated: use AsyncClient."""
+
+    def fetch(self):
+        """Fetch one page."""
+        return "deprecated is only a string here"
+"#,
+        ),
+        (
+            "legacy.py",
+            "def retry():\n    # deprecated retry path\n    return None\n",
+        ),
+    ]);
+
+    let output = assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "doc:Deprecated"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("client.py"), "{stdout}");
+    assert!(!stdout.contains("legacy.py"), "{stdout}");
+
+    // Plain strings are not docstrings.
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "doc:'only a string'"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "doc:'Fetch one page'"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("client.py"));
+}
@@ -192,3 +192,102 @@ fn lang_matrix_json_reports_support_tiers() -> Result<(), Box<dyn std::error::Er
     }));
     Ok(())
 }
+
+#[test]
+fn config_defaults_apply_below_cli_flags() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = tempdir()?;
+    fs::create_dir(dir.path().join(".rdump"))?;
+    fs::write(
+        dir.path().join(".rdump/config.toml"),
+        "[defaults]\nhidden = true\n",
+    )?;
+    fs::write(dir.path().join(".env.local"), "needle\n")?;
+    fs::write(dir.path().join("visible.txt"), "needle\n")?;
+
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("rdump");
+    cmd.current_dir(dir.path())
+        .args(["search", "--find", "contains:needle"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(".env.local"))
+        .stdout(predicate::str::contains("visible.txt"));
+
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("rdump");
+    cmd.current_dir(dir.path())
+        .args(["search", "--find", "--no-hidden", "contains:needle"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(".env.local").not())
+        .stdout(predicate::str::contains("visible.txt"));
+
+    Ok(())
+}
+
+#[test]
+fn config_default_format_yields_to_an_explicit_format() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = tempdir()?;
+    fs::create_dir(dir.path().join(".rdump"))?;
+    fs::write(
+        dir.path().join(".rdump/config.toml"),
+        "[defaults]\nformat = \"paths\"\n",
+    )?;
+    fs::write(dir.path().join("notes.txt"), "needle\n")?;
+
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("rdump");
+    cmd.current_dir(dir.path())
+        .args(["search", "contains:needle"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("notes.txt"))
+        .stdout(predicate::str::contains("needle").not());
+
+    // `hunks` is also the built-in default, but naming it still beats the config.
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("rdump");
+    cmd.current_dir(dir.path())
+        .args(["search", "--format", "hunks", "contains:needle"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("needle"));
+
+    Ok(())
+}
+
+#[test]
+fn config_ext_map_maps_compound_extensions_to_a_language() -> Result<(), Box<dyn std::error::Error>>
+{
+    let dir = tempdir()?;
+    fs::write(dir.path().join("main.rs.in"), "fn main() {}\n")?;
+
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("rdump");
+    cmd.current_dir(dir.path())
+        .args(["search", "--format=paths", "func:main"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("main.rs.in").not());
+
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("rdump");
+    cmd.current_dir(dir.path()).args([
+        "search",
+        "--format=paths",
+        "--ext-map",
+        "rs.in=rust",
+        "func:main",
+    ]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("main.rs.in"));
+
+    fs::create_dir(dir.path().join(".rdump"))?;
+    fs::write(
+        dir.path().join(".rdump/config.toml"),
+        "[defaults.ext_map]\n\"rs.in\" = \"rust\"\n",
+    )?;
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("rdump");
+    cmd.current_dir(dir.path())
+        .args(["search", "--format=paths", "func:main"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("main.rs.in"));
+
+    Ok(())
+}
@@ -1,8 +1,99 @@
 mod common;
-use common::assert_public_support_matrix;
+use common::{assert_public_support_matrix, setup_custom_project};
 use rdump::support_matrix::react_shared_cases;
+use rdump::{search, SearchOptions};
 
 #[test]
 fn test_react_shared_behavior_matrix() {
     assert_public_support_matrix(react_shared_cases());
 }
+
+const HOOK_SITES_TSX: &str = r#"import React, { useState } from 'react';
+
+export function useState(initial: number) {
+  return [initial, () => {}];
+}
+
+export function useAuth() {
+  const [user] = React.useState(null);
+  return { user };
+}
+
+export function Profile() {
+  const [count] = useState(0);
+  const { user } = useAuth();
+  return <p>{count}{user}</p>;
+}
+"#;
+
+fn hook_site_lines(query: &str) -> Vec<usize> {
+    let dir = setup_custom_project(&[("src/hooks.tsx", HOOK_SITES_TSX)]);
+    let results = search(
+        query,
+        SearchOptions {
+            root: dir.path().to_path_buf(),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert_eq!(results.len(), 1, "expected one matching file for {query}");
+    results[0]
+        .matches
+        .iter()
+        .map(|matched| matched.start_line)
+        .collect()
+}
+
+#[test]
+fn test_hook_matches_call_sites_but_not_same_named_definition() {
+    let lines = hook_site_lines("hook:useState");
+    assert!(lines.contains(&8), "member call should match: {lines:?}");
+    assert!(lines.contains(&13), "bare call should match: {lines:?}");
+    assert!(
+        !lines.contains(&3),
+        "definition should not match: {lines:?}"
+    );
+}
+
+#[test]
+fn test_customhook_matches_definition_but_not_call_site() {
+    let lines = hook_site_lines("customhook:useAuth");
+    assert_eq!(lines, vec![7]);
+}
+
+const BUTTONS_TSX: &str = r#"export function Toolbar() {
+  return (
+    <div>
+      <Button variant="primary">Save</Button>
+      <Button variant="secondary">Cancel</Button>
+      <Button variant={"primary"} disabled>Retry</Button>
+    </div>
+  );
+}
+"#;
+
+fn prop_lines(query: &str) -> Vec<usize> {
+    let dir = setup_custom_project(&[("src/Toolbar.tsx", BUTTONS_TSX)]);
+    search(
+        query,
+        SearchOptions {
+            root: dir.path().to_path_buf(),
+            ..Default::default()
+        },
+    )
+    .unwrap()
+    .iter()
+    .flat_map(|result| result.matches.iter().map(|matched| matched.start_line))
+    .collect()
+}
+
+#[test]
+fn test_prop_value_matches_only_attributes_with_that_value() {
+    assert_eq!(prop_lines("prop:variant=primary"), vec![4, 6]);
+    assert_eq!(prop_lines("prop:variant=secondary"), vec![5]);
+    assert!(prop_lines("prop:variant=danger").is_empty());
+    // A bare attribute has no value to compare.
+    assert!(prop_lines("prop:disabled=true").is_empty());
+    // Name-only matching is unchanged.
+    assert_eq!(prop_lines("prop:variant"), vec![4, 5, 6]);
+}
@@ -215,6 +215,90 @@ end
         .stdout(predicate::str::contains("inheritance.rb"));
 }
 
+#[test]
+fn test_ruby_module_predicate_matches_module_declaration() {
+    let dir = setup_custom_project(&[
+        (
+            "admin.rb",
+            r#"
+module Admin
+  class Dashboard
+  end
+end
+"#,
+        ),
+        (
+            "admin_user.rb",
+            r#"
+class AdminUser
+  ADMIN = "Admin"
+end
+"#,
+        ),
+    ]);
+
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "module:Admin"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("admin.rb"))
+        .stdout(predicate::str::contains("admin_user.rb").not());
+}
+
+#[test]
+fn test_ruby_func_matches_instance_and_singleton_methods() {
+    let dir = setup_custom_project(&[
+        (
+            "record.rb",
+            r#"
+class Record
+  def save
+    true
+  end
+end
+"#,
+        ),
+        (
+            "store.rb",
+            r#"
+class Store
+  def self.save(record)
+    record
+  end
+end
+"#,
+        ),
+    ]);
+
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "func:save"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("record.rb"))
+        .stdout(predicate::str::contains("store.rb"));
+}
+
+#[test]
+fn test_ruby_str_matches_symbol_literals() {
+    let dir = setup_custom_project(&[(
+        "callbacks.rb",
+        r#"
+class Record
+  before_save :normalize_email
+end
+"#,
+    )]);
+
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=hunks", "str:normalize_email"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(":normalize_email"));
+}
+
 // =============================================================================
 // EDGE CASE TESTS
 // =============================================================================
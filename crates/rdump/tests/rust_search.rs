@@ -1,6 +1,6 @@
 use predicates::prelude::*;
 mod common;
-use common::setup_test_project;
+use common::{setup_custom_project, setup_rust_project, setup_test_project};
 
 #[test]
 fn test_def_finds_struct_in_correct_file() {
@@ -372,6 +372,35 @@ impl<T> Container<T> {
         .stdout(predicate::str::contains("generic.rs"));
 }
 
+#[test]
+fn test_rust_struct_empty_body_modifier() {
+    let dir = common::setup_custom_project(&[
+        ("unit.rs", "pub struct Marker;\n"),
+        ("braces.rs", "pub struct Marker {}\n"),
+        ("tuple.rs", "pub struct Marker();\n"),
+        ("fields.rs", "pub struct Marker {\n    id: u64,\n}\n"),
+    ]);
+
+    let output = assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "struct:Marker{}"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("unit.rs"), "{stdout}");
+    assert!(stdout.contains("braces.rs"), "{stdout}");
+    assert!(stdout.contains("tuple.rs"), "{stdout}");
+    assert!(!stdout.contains("fields.rs"), "{stdout}");
+
+    // Without the modifier every `Marker` still matches.
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "struct:Marker"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("fields.rs"));
+}
+
 #[test]
 fn test_rust_custom_async_function() {
     let dir = common::setup_custom_project(&[(
@@ -591,3 +620,248 @@ fn test_rust_ext_filter() {
         .success()
         .stdout(predicate::str::contains(".md").not());
 }
+
+#[test]
+fn test_defn_body_returns_enclosing_function() {
+    let dir = common::setup_custom_project(&[(
+        "src/workers.rs",
+        r#"fn start_workers() {
+    let handle = std::thread::spawn(|| work());
+    handle.join().unwrap();
+}
+
+fn work() {
+    println!("working");
+}
+"#,
+    )]);
+
+    for query in ["defn_body:spawn", "func_containing:spawn"] {
+        let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("rdump");
+        cmd.current_dir(dir.path());
+        cmd.arg("search").arg(query);
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::contains("fn start_workers() {"))
+            .stdout(predicate::str::contains("handle.join().unwrap();"))
+            .stdout(predicate::str::contains("fn work()").not());
+    }
+}
+
+#[test]
+fn test_containment_scopes_call_to_function_body() {
+    let dir = common::setup_custom_project(&[
+        (
+            "src/inside.rs",
+            "fn main() {\n    let value = parse().unwrap();\n}\n",
+        ),
+        (
+            "src/elsewhere.rs",
+            "fn main() {\n    helper();\n}\n\nfn helper() {\n    parse().unwrap();\n}\n",
+        ),
+    ]);
+
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("rdump");
+    cmd.current_dir(dir.path());
+    cmd.arg("search")
+        .arg("--format=paths")
+        .arg("func:main > call:unwrap");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("inside.rs"))
+        .stdout(predicate::str::contains("elsewhere.rs").not());
+
+    // File-level AND still matches both files.
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("rdump");
+    cmd.current_dir(dir.path());
+    cmd.arg("search")
+        .arg("--format=paths")
+        .arg("func:main & call:unwrap");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("inside.rs"))
+        .stdout(predicate::str::contains("elsewhere.rs"));
+}
+
+#[test]
+fn test_expand_to_scope_widens_content_hunk_to_function() {
+    let dir = common::setup_custom_project(&[(
+        "src/main.rs",
+        "use std::io;\n\nfn main() {\n    let a = 1;\n    let bar = a + 1;\n    println!(\"{bar}\");\n}\n\nfn other() {}\n",
+    )]);
+
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("rdump");
+    cmd.current_dir(dir.path());
+    cmd.args([
+        "search",
+        "--color=never",
+        "--expand-to-scope",
+        "contains:'let bar'",
+    ]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "fn main() {\n    let a = 1;\n    let bar = a + 1;\n    println!(\"{bar}\");\n}\n",
+        ))
+        .stdout(predicate::str::contains("use std::io").not())
+        .stdout(predicate::str::contains("fn other").not());
+
+    // Without the flag only the matching line is shown.
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("rdump");
+    cmd.current_dir(dir.path());
+    cmd.args(["search", "--color=never", "contains:'let bar'"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("let bar = a + 1;"))
+        .stdout(predicate::str::contains("fn main").not());
+}
+
+#[test]
+fn test_show_enclosing_labels_hunks_with_their_definition() {
+    let dir = common::setup_custom_project(&[(
+        "src/lexer.rs",
+        "struct Lexer;\n\nimpl Lexer {\n    fn advance(&mut self) {\n        let _ = helper();\n    }\n}\n\nfn tokenize() {\n    let _ = helper();\n}\n\nstatic HELPER: fn() = || { helper(); };\n",
+    )]);
+
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("rdump");
+    cmd.current_dir(dir.path());
+    cmd.args(["search", "--color=never", "--show-enclosing", "call:helper"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "[in fn advance]\n        let _ = helper();",
+        ))
+        .stdout(predicate::str::contains(
+            "[in fn tokenize]\n    let _ = helper();",
+        ))
+        // Closures have no name, and nothing named encloses this one.
+        .stdout(predicate::str::contains("[in ").count(2));
+
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("rdump");
+    cmd.current_dir(dir.path());
+    cmd.args(["search", "--color=never", "call:helper"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("[in ").not());
+}
+
+#[test]
+fn test_doc_predicate_matches_doc_comments_but_not_line_comments() {
+    let dir = setup_custom_project(&[
+        (
+            "src/api.rs",
+            "/// Deprecated: use `fetch_all` instead.\npub fn fetch() {}\n\npub fn fetch_all() {}\n",
+        ),
+        (
+            "src/internal.rs",
+            "pub fn retry() {\n    // deprecated retry path, remove soon\n    /* deprecated too */\n}\n",
+        ),
+    ]);
+
+    let output = assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "doc:Deprecated"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("api.rs"), "{stdout}");
+    assert!(!stdout.contains("internal.rs"), "{stdout}");
+
+    // Case-sensitive, like `comment:`.
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "doc:deprecated"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+
+    // `comment:` still sees every comment, doc comments included.
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "comment:deprecated"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("internal.rs"));
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "comment:Deprecated"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("api.rs"));
+}
+
+#[test]
+fn test_test_predicate_matches_attributed_functions() {
+    let dir = setup_custom_project(&[
+        (
+            "src/parser.rs",
+            r#"pub fn parses() -> bool {
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn parses() {
+        assert!(super::parses());
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn fetches() {}
+
+    fn helper() {}
+}
+"#,
+        ),
+        ("src/plain.rs", "fn parses() {}\n"),
+    ]);
+
+    let output = assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "test:parses"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("parser.rs"), "{stdout}");
+    assert!(!stdout.contains("plain.rs"), "{stdout}");
+
+    let output = assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=matches", "test:."])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("fn parses()"), "{stdout}");
+    assert!(stdout.contains("async fn fetches()"), "{stdout}");
+    assert!(!stdout.contains("helper"), "{stdout}");
+    assert!(!stdout.contains("pub fn parses"), "{stdout}");
+}
+
+#[test]
+fn test_aggregate_format_counts_hunks_per_predicate() {
+    let dir = setup_rust_project();
+
+    let output = assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args([
+            "search",
+            "--format=aggregate",
+            "func:. | struct:. | import:.",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let counts: Vec<(&str, usize)> = stdout
+        .lines()
+        .take_while(|line| !line.is_empty())
+        .map(|line| {
+            let (key, count) = line.split_once(": ").unwrap();
+            (key, count.parse().unwrap())
+        })
+        .collect();
+    let keys: Vec<&str> = counts.iter().map(|(key, _)| *key).collect();
+    assert_eq!(keys, ["func", "import", "struct"], "{stdout}");
+    assert!(counts.iter().all(|(_, count)| *count > 0), "{stdout}");
+}
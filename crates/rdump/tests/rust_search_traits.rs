@@ -27,3 +27,100 @@ fn test_impl_predicate() {
         .stdout(predicate::str::contains("src/traits.rs"))
         .stdout(predicate::str::contains("impl Summary for NewsArticle"));
 }
+
+#[test]
+fn test_implements_predicate_matches_trait_impls() {
+    let dir = common::setup_custom_project(&[(
+        "src/user.rs",
+        r#"use std::fmt;
+
+pub struct User;
+pub struct Admin;
+
+impl fmt::Display for User {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "user")
+    }
+}
+
+impl Display for Admin {}
+
+impl User {
+    pub fn display(&self) {}
+}
+"#,
+    )]);
+
+    let output = assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=matches", "implements:Display"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout.matches(" Display").count(), 2, "{stdout}");
+    assert!(!stdout.contains("display"), "{stdout}");
+
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .arg("search")
+        .arg("implements:User")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("user.rs").not());
+}
+
+#[test]
+fn test_trait_method_predicate_matches_only_trait_signatures() {
+    let dir = setup_test_project();
+    let output = assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=matches", "trait_method:summarize"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("2:8-2:17 summarize"), "{stdout}");
+    assert!(!stdout.contains("13:8-13:17 summarize"), "{stdout}");
+
+    // `func:` still sees both the signature and the impl's method.
+    let output = assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=matches", "func:summarize"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("2:8-2:17 summarize"), "{stdout}");
+    assert!(stdout.contains("13:8-13:17 summarize"), "{stdout}");
+}
+
+#[test]
+fn test_trait_method_predicate_skips_default_methods() {
+    let dir = common::setup_custom_project(&[(
+        "src/task.rs",
+        r#"pub trait Runnable {
+    fn run(&self);
+    fn describe(&self) -> String {
+        String::from("task")
+    }
+}
+
+pub struct Job;
+
+impl Runnable for Job {
+    fn run(&self) {}
+}
+"#,
+    )]);
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "trait_method:run"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("src/task.rs"));
+
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "trait_method:describe"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+}
@@ -242,6 +242,72 @@ object Implicits {
         .stdout(predicate::str::contains("implicits.scala"));
 }
 
+const DOMAIN_SCALA: &str = r#"
+package demo.domain
+
+case class User(id: Long, name: String)
+
+trait Repository {
+  def find(id: Long): Option[User]
+}
+
+object Bar {
+  type UserId = Long
+
+  given userOrdering: Ordering[User] = Ordering.by(_.id)
+}
+"#;
+
+#[test]
+fn test_scala_class_matches_case_class() {
+    let dir = setup_custom_project(&[("Domain.scala", DOMAIN_SCALA)]);
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "class:User"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Domain.scala"));
+
+    // An object is not a class.
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "class:Bar"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+}
+
+#[test]
+fn test_scala_object_trait_and_def_predicates() {
+    let dir = setup_custom_project(&[("Domain.scala", DOMAIN_SCALA)]);
+    for query in [
+        "object:Bar",
+        "def:Bar",
+        "trait:Repository",
+        "def:Repository",
+        "type:UserId",
+        "def:userOrdering",
+    ] {
+        assert_cmd::cargo::cargo_bin_cmd!("rdump")
+            .current_dir(dir.path())
+            .args(["search", "--format=paths", query])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Domain.scala"));
+    }
+}
+
+#[test]
+fn test_scala_func_matches_abstract_trait_methods() {
+    let dir = setup_custom_project(&[("Domain.scala", DOMAIN_SCALA)]);
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "trait:Repository & func:find"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Domain.scala"));
+}
+
 #[test]
 fn test_scala_custom_pattern_matching() {
     let dir = setup_custom_project(&[(
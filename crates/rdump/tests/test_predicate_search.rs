@@ -0,0 +1,67 @@
+use predicates::prelude::*;
+mod common;
+use common::setup_fixture;
+
+fn matches_output(query: &str) -> String {
+    let dir = setup_fixture("tests_project");
+    let output = assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=matches", query])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn test_test_predicate_lists_rust_attributed_functions() {
+    let stdout = matches_output("test:. & ext:rs");
+    assert!(stdout.contains("login_succeeds"), "{stdout}");
+    assert!(stdout.contains("logout_without_session_panics"), "{stdout}");
+    assert!(!stdout.contains("login_helper"), "{stdout}");
+}
+
+#[test]
+fn test_test_predicate_lists_go_test_functions() {
+    let stdout = matches_output("test:. & ext:go");
+    assert!(stdout.contains("TestLogin"), "{stdout}");
+    assert!(stdout.contains("TestLogout"), "{stdout}");
+    assert!(!stdout.contains("Testify"), "{stdout}");
+    assert!(!stdout.contains("loginHelper"), "{stdout}");
+}
+
+#[test]
+fn test_test_predicate_lists_python_and_jest_tests() {
+    let stdout = matches_output("test:. & ext:py");
+    assert!(stdout.contains("test_login"), "{stdout}");
+    assert!(stdout.contains("test_logout"), "{stdout}");
+    assert!(!stdout.contains("setUp"), "{stdout}");
+    assert!(!stdout.contains("login_helper"), "{stdout}");
+
+    let stdout = matches_output("test:. & ext:js");
+    assert!(stdout.contains("login redirects home"), "{stdout}");
+    assert!(stdout.contains("logout clears the session"), "{stdout}");
+    assert!(!stdout.contains("\"session\""), "{stdout}");
+}
+
+#[test]
+fn test_test_predicate_matches_names_by_substring() {
+    let dir = setup_fixture("tests_project");
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "test:login"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("session.rs"))
+        .stdout(predicate::str::contains("test_session.py"))
+        .stdout(predicate::str::contains("session.test.js"))
+        // Go test names are capitalized, so `login` does not match `TestLogin`.
+        .stdout(predicate::str::contains("session_test.go").not());
+
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "test:Login"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("session_test.go"));
+}
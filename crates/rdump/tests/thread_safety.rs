@@ -65,6 +65,7 @@ fn search_result_moves_between_threads() {
             end_column: 10,
             byte_range: 0..10,
             text: "fn main()".to_string(),
+            ..Default::default()
         }],
         content: "fn main() {}".to_string(),
         content_state: rdump::ContentState::Loaded,
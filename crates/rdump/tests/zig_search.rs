@@ -335,3 +335,50 @@ fn test_zig_ext_filter() {
         .success()
         .stdout(predicate::str::contains(".rs").not());
 }
+
+#[test]
+fn test_zig_test_blocks_and_comptime_functions() {
+    let dir = setup_custom_project(&[(
+        "parser.zig",
+        r#"const std = @import("std");
+
+pub fn parse(comptime T: type, input: []const u8) !T {
+    return std.fmt.parseInt(T, input, 10);
+}
+
+test "parse_works" {
+    try std.testing.expectEqual(@as(u8, 7), try parse(u8, "7"));
+}
+
+test "rejects garbage" {
+    try std.testing.expectError(error.InvalidCharacter, parse(u8, "x"));
+}
+"#,
+    )]);
+
+    for query in [
+        "test:parse_works",
+        "test:'rejects garbage'",
+        "test:garbage",
+        "test:.",
+    ] {
+        assert_cmd::cargo::cargo_bin_cmd!("rdump")
+            .current_dir(dir.path())
+            .args(["search", "--format=paths", query])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("parser.zig"));
+    }
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "test:tokenize"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+    assert_cmd::cargo::cargo_bin_cmd!("rdump")
+        .current_dir(dir.path())
+        .args(["search", "--format=paths", "func:parse"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("parser.zig"));
+}